@@ -14,13 +14,14 @@ use syn::parse_macro_input;
 /// * A strategy `struct` for each variant - with their names being the suggestion type's name
 ///   concatenated with the variant's name. These structs act as Bevy `Component`s which will be
 ///   added to the entity when the suggested variant is chosen, and can be used by action systems
-///   to enact the behaviors they represent.
+///   to enact the behaviors they represent. Each strategy `struct` has `VARIANT_NAME` and
+///   `VARIANT_INDEX` constants, and implements `YoetzStrategy`.
 ///
 /// * For internal usage only - an omni-query `struct`.
 ///
-/// This macro must decorate an `enum`, and each variant of the `enum` must be either a unit
-/// variant or a struct variant (tuple variants are not allowed). Each field of a struct variant
-/// must be annotated with a `#[yoetz(...)]` attribute that specifies its role:
+/// This macro must decorate an `enum`. Each field of a struct or tuple variant must be annotated
+/// with a `#[yoetz(...)]` attribute that specifies its role (the only exception is the field of a
+/// single-field tuple variant, which is an input field unless annotated otherwise):
 ///
 /// * Key fields (annotated with `#[yoetz(key)]`) can discern between different suggestions. If the
 ///   same variant is suggested but with a difference in the key fields, it will be considered as a
@@ -29,24 +30,177 @@ use syn::parse_macro_input;
 ///
 ///   Key fields **must** be [`Clone`] and [`PartialEq`], because they get into the key enum.
 ///
+///   Annotating a key field with `#[yoetz(key(hash))]` puts a `u64` hash of the field in the key
+///   enum instead of a clone of it. Such fields must be [`Hash`](std::hash::Hash) (but not
+///   necessarily [`Clone`] or [`PartialEq`]). Use this for large key fields (paths, strings) that
+///   are wasteful to clone every time a suggestion is made - as long as telling the suggestions
+///   apart by the hash is acceptable.
+///
 /// * Input fields (annotated with `#[yoetz(input)]`) always get updated from the suggestion, even
-///   if the suggestion itself (and therefore the components) do not change.
+///   if the suggestion itself (and therefore the components) do not change (unless the variant is
+///   annotated with `#[yoetz(static_inputs)]`).
+///
+///   Annotating an input field with `#[yoetz(input, skip_if_eq)]` makes it only get updated when
+///   its value differs from the value in the strategy `struct`, so that the component is not
+///   marked as changed (for `Changed<...>` filters) when the inputs stay the same. Such fields
+///   must be [`PartialEq`].
 ///
 /// * State fields (annotated with `#[yoetz(state)]`) only get initialized from the suggestion when
 ///   the suggestion itself changes. When it doesn't (the variant and the key fields remain the
 ///   same) the state fields from the suggestion are discarded, which means that the action systems
 ///   can use them to maintain their own state.
 ///
+/// * Path fields (annotated with `#[yoetz(path_to(<target>))]`) are state fields of type
+///   `YoetzPath`, into which a `YoetzPathfindPlugin` writes the path to the `Vec3` field named
+///   `<target>` (which usually is an input field). Only supported on struct variants, and only
+///   once per variant. The strategy `struct` of a variant with a path field implements
+///   `YoetzPathfindStrategy`.
+///
+/// `#[cfg(...)]` attributes on variants are copied to everything generated for them (the key
+/// `enum` variant, the strategy `struct` and all the generated `match` arms), so variants can be
+/// compiled out per feature or platform.
+///
+/// `#[non_exhaustive]` on the `enum` is copied to the key `enum` and the kind `enum` (see
+/// `key_helpers` below), and on variants to the key `enum`'s variants. Explicit discriminants (and
+/// the `enum`'s `#[repr(...)]`) are copied to the kind `enum`, so that casting a kind to an integer
+/// gives the discriminant of its variant.
+///
+/// The `enum` may have type and const generic parameters (but not lifetime parameters). Each
+/// generated type only gets the parameters its fields use, and a strategy `struct` only
+/// implements `YoetzStrategy` if it uses all of them.
+///
+/// Variants may be annotated with their own `#[yoetz(...)]` attribute:
+///
+/// - `#[yoetz(cost(<resource> = <amount>, ...))]` - the amounts of the entity's
+///   `YoetzBehaviorResources` the behavior costs. Suggestions of this variant will be ignored if
+///   the entity cannot afford them.
+///
+/// - `#[yoetz(consistency_bonus = <bonus>)]` - a consistency bonus for the behavior, overriding the
+///   advisor's `consistency_bonus`. Use this to make some behaviors stickier than others.
+///
+/// - `#[yoetz(tier = <tier>)]` - a priority tier for the behavior (the default tier is 0).
+///   Suggestions in a higher tier always beat suggestions in lower tiers, and scores (and the
+///   consistency bonus) only compete within the highest tier that has an eligible suggestion. Use
+///   this for reflexes (e.g. dodging a grenade) that must outrank any utility computation.
+///
+/// - `#[yoetz(min_duration = <seconds>)]` - the behavior stays active for at least this long
+///   before the advisor may switch away from it (unless it is interrupted or dropped). Use this to
+///   let short animations finish instead of thrashing between behaviors.
+///
+/// - `#[yoetz(max_duration = <seconds>)]` - the advisor drops the behavior after it has been active
+///   for this long, and chooses a new one.
+///
+/// - `#[yoetz(interruptible = false)]` - once the behavior is chosen, the advisor does not replace
+///   it until the action systems release it (with `YoetzAdvisor::release`), drop it or complete it.
+///   Use this for attack wind-ups and scripted sequences that must not be cut mid-way.
+///
+/// - `#[yoetz(interrupt_threshold = <score>)]` - like `interruptible = false`, but suggestions with
+///   a final score of at least this much can still replace the behavior.
+///
+/// - `#[yoetz(static_inputs)]` - the input fields are only written to the strategy `struct` when
+///   it is created (or replaced because the key changed), and not every time the behavior is
+///   suggested again. Use this for variants with heavy inputs (paths, large `Vec`s) that do not
+///   change while the behavior is active, to avoid needlessly moving them and triggering change
+///   detection.
+///
+/// - `#[yoetz(default(<name>: <type>, ...))]` - extra fields for the strategy `struct` that are
+///   not part of the variant, and are initialized with `Default::default()` whenever the strategy
+///   component is created. Use this for the bookkeeping of the action systems (timers,
+///   accumulators, etc.) so that the suggestion systems do not have to fill it in each
+///   suggestion. Only supported on struct and unit variants (the latter get a strategy `struct`
+///   with named fields).
+///
+/// - `#[yoetz(also_insert(<component>, ...))]` - companion components for the behavior, which
+///   are inserted (with `Default::default()`) together with the strategy `struct` when the
+///   behavior starts and removed together with it when the behavior ends. The components must
+///   implement [`Default`].
+///
+/// - `#[yoetz(precondition(<fact>, !<fact>, ...))]` and `#[yoetz(effect(<fact>, !<fact>, ...))]`
+///   - the facts that must hold (or, with `!`, must not hold) for a `YoetzPlanner` to plan the
+///   behavior, and the facts that hold (or don't) after it is performed.
+///
+/// - `#[yoetz(skip)]` - generate nothing for the variant: no key `enum` variant, no strategy
+///   `struct` and no entry in `VARIANT_NAMES` or the variant indices. Its fields do not need to be
+///   annotated. Use this for bookkeeping variants that are part of the `enum` but never become
+///   behaviors - their `YoetzSuggestion::is_skipped` returns `true`, and suggesting them does
+///   nothing.
+///
+/// - `#[yoetz(fallback)]` - on a unit variant, makes it the behavior the advisor falls back to
+///   when it does not receive any suggestion, if its `starvation_policy` is `Fallback`. Only one
+///   variant can be the fallback.
+///
+/// - `#[yoetz(strategy(...))]` - for customizing the strategy `struct` of the variant, on top of
+///   the `enum`'s `strategy_structs(...)`. Besides the settings of all generated types, it
+///   supports `name = "<name>"` for naming the strategy `struct` something other than the
+///   suggestion type's name concatenated with the variant's name.
+///
 /// The `enum` itself may be annotated with its own `#[yoetz(...)] attribute:
 ///
 /// - `#[yoetz(key_enum(...))]` - for customizing the generated key `enum`.
 ///
 /// - `#[yoetz(strategy_structs(...))]` - for customizing the generated strategy `struct`s.
 ///
+/// - `#[yoetz(reflect)]` - derive `Reflect` for the key `enum` and the strategy `struct`s, and have
+///   `YoetzPlugin` register them (the strategy `struct`s as reflected components). All the key
+///   fields and the strategy fields must be `Reflect` for this to work.
+///
+/// - `#[yoetz(single_component)]` - instead of inserting the strategy `struct` of the chosen
+///   variant as a component (and removing it when the behavior ends), insert a single component
+///   `enum` - with its name being the suggestion type's name concatenated with the "Active"
+///   suffix - that has a tuple variant wrapping the strategy `struct` of each variant. Switching
+///   between behaviors then replaces the component's value in place, without moving the entity
+///   between archetypes. Use this for agents that switch behaviors very frequently. The price is
+///   that action systems must query the whole `enum` and `match` on it (instead of querying their
+///   own strategy `struct`), that change detection applies to the whole `enum`, and that
+///   `also_insert` and the utilities that look for strategy components on the entity (like
+///   `YoetzPatrolPlugin` and nested advisors) are not supported. The `strategy_structs(...)`
+///   settings are also applied to the component `enum`.
+///
+/// - `#[yoetz(key_helpers)]` - generate helper methods for the key `enum`: an `is_<variant>()`
+///   method for each variant (with the variant's name in `snake_case`), an `as_<variant>()` method
+///   for each variant that has key fields (returning a reference to the key field, or a tuple of
+///   references if there are multiple key fields), and a `discriminant()` method that returns its
+///   kind. The kind `enum` - with its name being the suggestion type's name concatenated with the
+///   "Kind" suffix - is a fieldless `enum` with the same variants, for branching on the kind of a
+///   behavior without matching on its key fields.
+///
+/// - `#[yoetz(builders)]` - generate a builder for each struct variant, for constructing
+///   suggestions of variants with many fields without a noisy inline `enum` literal. The builder
+///   is created with an associated function of the suggestion `enum` named after the variant in
+///   `snake_case`, has a setter for each field, and its `build` method can only be called (at
+///   compile time) once all the fields are set:
+///
+///   ```no_run
+///   # use bevy::prelude::*;
+///   # use bevy_yoetz::prelude::*;
+///   #[derive(YoetzSuggestion)]
+///   #[yoetz(builders)]
+///   enum AiBehavior {
+///       Attack {
+///           #[yoetz(key)]
+///           target: Entity,
+///           #[yoetz(input)]
+///           position: Vec3,
+///       },
+///   }
+///   # fn foo(target: Entity, position: Vec3) {
+///   let suggestion = AiBehavior::attack().target(target).position(position).build();
+///   # }
+///   ```
+///
 /// Attributes that customize generated types support the following settings:
 ///
 /// - `#[yoetz(...(derive(...)))]` - for applying derive macros on the generated structs.
 ///
+/// - `#[yoetz(...(serde))]` - shorthand for `derive(serde::Serialize, serde::Deserialize)`, using
+///   the `serde` re-exported by this crate (so it requires the `serde` feature of this crate, but
+///   not a direct dependency on `serde`). Use `key_enum(serde)` to make the `YoetzAdvisorSnapshot`
+///   serializable for saving and restoring the active behaviors, or to replicate the active
+///   behaviors with the `yoetz_replicon` feature of this crate.
+///
+/// - `#[yoetz(...(attr(...)))]` - for applying attributes on the generated structs (e.g.
+///   `attr(component(storage = "SparseSet"))` on strategy `struct`s).
+///
 /// ```no_run
 /// # use bevy::prelude::*;
 /// # use bevy_yoetz::prelude::*;
@@ -60,7 +214,9 @@ use syn::parse_macro_input;
 /// enum AiBehavior {
 ///     // Unit variants are allowed.
 ///     DoNothing,
-///     // Struct variants are allowed. But not tuple variants.
+///     // Tuple variants are allowed. A single field is an input field by default.
+///     Wander(Vec3),
+///     // Struct variants are allowed.
 ///     Attack {
 ///         // This is a key field, because attacking a different target should be considered a
 ///         // different suggestion.
@@ -78,6 +234,14 @@ use syn::parse_macro_input;
 ///         #[yoetz(state)]
 ///         time_left_for_the_attack: Timer,
 ///     },
+///     // The suggestion systems only need to suggest `AiBehavior::Flee`, and the action systems
+///     // get a `FleeTask { time_fleeing: 0.0 }` component. Agents flee often, so the component
+///     // is stored in a sparse set which is faster to add and remove.
+///     #[yoetz(
+///         default(time_fleeing: f32),
+///         strategy(name = "FleeTask", attr(component(storage = "SparseSet"))),
+///     )]
+///     Flee,
 /// }
 /// ```
 #[proc_macro_derive(YoetzSuggestion, attributes(yoetz))]
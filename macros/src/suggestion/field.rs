@@ -12,6 +12,11 @@ pub enum FieldRole {
 #[derive(Default)]
 pub struct FieldConfig {
     pub role: Option<FieldRole>,
+    pub skip_if_eq: bool,
+    /// Store a hash of the key field in the key `enum` instead of a clone of it.
+    pub hash_key: bool,
+    /// With `#[yoetz(path_to(<target>))]`, the name of the field the path leads to.
+    pub path_to: Option<syn::Ident>,
 }
 
 impl ApplyMeta for FieldConfig {
@@ -30,15 +35,52 @@ impl ApplyMeta for FieldConfig {
                     });
                     Ok(())
                 }
+                AttrArg::Sub(_) if role == "key" => {
+                    if self.role.is_some() {
+                        return Err(Error::new_spanned(&expr, "field role given more than once"));
+                    }
+                    for mode in expr.sub_attr()?.args::<syn::Ident>()? {
+                        if mode != "hash" {
+                            return Err(Error::new_spanned(
+                                &mode,
+                                format!("Unknown key mode {:?}", mode.to_string()),
+                            ));
+                        }
+                        self.hash_key = true;
+                    }
+                    self.role = Some(FieldRole::Key);
+                    Ok(())
+                }
                 _ => Err(expr.incorrect_type()),
             },
+            "path_to" => {
+                if self.role.is_some() {
+                    return Err(Error::new_spanned(&expr, "field role given more than once"));
+                }
+                let mut targets = expr.sub_attr()?.args::<syn::Ident>()?.into_iter();
+                let (Some(target), None) = (targets.next(), targets.next()) else {
+                    return Err(Error::new_spanned(
+                        &expr,
+                        "`path_to` takes a single field name",
+                    ));
+                };
+                // The path is never updated from the suggestions - it belongs to the pathfinding.
+                self.role = Some(FieldRole::State);
+                self.path_to = Some(target);
+                Ok(())
+            }
+            "skip_if_eq" => {
+                expr.flag()?;
+                self.skip_if_eq = true;
+                Ok(())
+            }
             _ => Err(expr.unknown_name()),
         }
     }
 }
 
 impl FieldConfig {
-    pub fn new_for(field: &mut syn::Field) -> Result<Self, Error> {
+    pub fn new_for(field: &mut syn::Field, default_role: Option<FieldRole>) -> Result<Self, Error> {
         let mut result = Self::default();
         for attr in field.attrs.drain(..) {
             if attr.path().is_ident("yoetz") {
@@ -46,9 +88,18 @@ impl FieldConfig {
             }
         }
 
+        if result.role.is_none() {
+            result.role = default_role;
+        }
         if result.role.is_none() {
             return Err(Error::new_spanned(&field, "YoetzSuggestion variant fields must be `#[yoets(<role>)]`, where <role> is key, input or state"));
         }
+        if result.skip_if_eq && result.role != Some(FieldRole::Input) {
+            return Err(Error::new_spanned(
+                &field,
+                "`skip_if_eq` is only supported on input fields",
+            ));
+        }
 
         Ok(result)
     }
@@ -1,10 +1,11 @@
-use syn::Error;
+use syn::{parse_quote, Error};
 
 use crate::util::{ApplyMeta, AttrArg};
 
 #[derive(Default)]
 pub struct GeneratedTypeConfig {
     pub derive: Vec<syn::Path>,
+    pub attrs: Vec<syn::Meta>,
 }
 
 impl ApplyMeta for GeneratedTypeConfig {
@@ -14,6 +15,20 @@ impl ApplyMeta for GeneratedTypeConfig {
                 self.derive.extend(expr.sub_attr()?.args()?);
                 Ok(())
             }
+            "serde" => {
+                expr.flag()?;
+                self.derive
+                    .push(parse_quote!(bevy_yoetz::__serde::Serialize));
+                self.derive
+                    .push(parse_quote!(bevy_yoetz::__serde::Deserialize));
+                self.attrs
+                    .push(parse_quote!(serde(crate = "bevy_yoetz::__serde")));
+                Ok(())
+            }
+            "attr" => {
+                self.attrs.extend(expr.sub_attr()?.args()?);
+                Ok(())
+            }
             _ => Err(expr.unknown_name()),
         }
     }
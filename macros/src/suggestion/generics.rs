@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// The subset of `generics` that is needed by a generated type whose fields are `tokens`.
+///
+/// Generated types cannot just take all the generic parameters of the suggestion `enum`, because
+/// Rust does not allow unused parameters. The where predicates are kept only if all the
+/// parameters they mention are kept.
+pub fn generics_used_by(generics: &syn::Generics, tokens: TokenStream) -> syn::Generics {
+    let mut used = HashSet::new();
+    collect_idents(tokens, &mut used);
+    let all_params = param_idents(generics.params.iter());
+
+    let params = generics
+        .params
+        .iter()
+        .filter(|param| param_ident(param).is_some_and(|ident| used.contains(&ident)))
+        .cloned()
+        .collect();
+    let kept_params = param_idents(generics.params.iter())
+        .into_iter()
+        .filter(|ident| used.contains(ident))
+        .collect::<HashSet<_>>();
+
+    let where_clause = generics.where_clause.as_ref().map(|where_clause| {
+        let predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| {
+                let mut mentioned = HashSet::new();
+                collect_idents(predicate.to_token_stream(), &mut mentioned);
+                let mut mentioned_params = mentioned.intersection(&all_params).peekable();
+                mentioned_params.peek().is_some()
+                    && mentioned_params.all(|ident| kept_params.contains(ident))
+            })
+            .cloned()
+            .collect();
+        syn::WhereClause {
+            where_token: where_clause.where_token,
+            predicates,
+        }
+    });
+
+    syn::Generics {
+        lt_token: generics.lt_token,
+        params,
+        gt_token: generics.gt_token,
+        where_clause,
+    }
+}
+
+/// Add predicates to the where clause of `generics`.
+pub fn with_predicates(
+    generics: &syn::Generics,
+    predicates: impl IntoIterator<Item = syn::WherePredicate>,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+fn param_ident(param: &syn::GenericParam) -> Option<String> {
+    match param {
+        syn::GenericParam::Type(type_param) => Some(type_param.ident.to_string()),
+        syn::GenericParam::Const(const_param) => Some(const_param.ident.to_string()),
+        syn::GenericParam::Lifetime(_) => None,
+    }
+}
+
+fn param_idents<'a>(params: impl Iterator<Item = &'a syn::GenericParam>) -> HashSet<String> {
+    params.filter_map(param_ident).collect()
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut HashSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use proc_macro2::{Span, TokenStream};
+use quote::quote;
 use syn::Error;
 
 use self::suggestion_enum::SuggestionEnumData;
-use self::variant::SuggestionVariantData;
+use self::variant::{SuggestionVariantData, VariantConfig};
 
 mod field;
 mod generated_type;
+mod generics;
 mod suggestion_enum;
 mod variant;
 
@@ -16,21 +18,58 @@ pub fn impl_suggestion(ast: &syn::DeriveInput) -> Result<TokenStream, Error> {
             "YoetzSuggestion can only be derived from an enum",
         ));
     };
-    let enum_data = SuggestionEnumData::try_from(ast)?;
-    let variants_data = ast_enum
-        .variants
-        .iter()
+    let mut enum_data = SuggestionEnumData::try_from(ast)?;
+    let mut variants = Vec::new();
+    for variant in ast_enum.variants.iter() {
+        if VariantConfig::new_for(variant)?.skip {
+            enum_data.skipped_variants.push(variant.clone());
+        } else {
+            variants.push(variant);
+        }
+    }
+    let enum_data = enum_data;
+    let mut variants_data = variants
+        .into_iter()
         .map(|variant| SuggestionVariantData::new(&enum_data, variant))
         .collect::<Result<Vec<_>, _>>()?;
+    assign_variant_indices(&mut variants_data)?;
     let mut output = TokenStream::default();
 
     output.extend(enum_data.emit_key_enum_code(&variants_data)?);
+    if enum_data.key_helpers {
+        output.extend(enum_data.emit_key_enum_helpers(&variants_data)?);
+    }
+    output.extend(enum_data.emit_active_enum_code(&variants_data)?);
     output.extend(enum_data.emit_omni_query_code(&variants_data)?);
     output.extend(enum_data.emit_trait_impl(&variants_data)?);
 
     for variant in variants_data.iter() {
         output.extend(variant.emit_strategy_code()?);
+        if enum_data.builders {
+            output.extend(variant.emit_builder_code()?);
+        }
     }
 
     Ok(output)
 }
+
+/// Variants disabled by `#[cfg(...)]` do not get an index, so the indices of the variants after
+/// them depend on the configuration. The common case where no variant is conditional still gets
+/// plain literals.
+fn assign_variant_indices(variants: &mut [SuggestionVariantData]) -> Result<(), Error> {
+    let mut unconditional = 0usize;
+    let mut conditional = Vec::new();
+    for variant in variants.iter_mut() {
+        variant.index = if conditional.is_empty() {
+            quote!(#unconditional)
+        } else {
+            quote!(#unconditional #(+ (#conditional as usize))*)
+        };
+        if let Some(predicate) = variant.cfg_predicate()? {
+            conditional.push(predicate);
+        } else {
+            unconditional += 1;
+        }
+    }
+    Ok(())
+}
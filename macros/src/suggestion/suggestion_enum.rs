@@ -1,37 +1,69 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::Error;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Error};
 
-use crate::util::{ApplyMeta, AttrArg};
+use crate::util::{to_snake_case, ApplyMeta, AttrArg};
 
 use super::field::FieldRole;
 use super::generated_type::GeneratedTypeConfig;
+use super::generics::{generics_used_by, with_predicates};
 use super::variant::SuggestionVariantData;
 
 pub struct SuggestionEnumData {
     pub visibility: syn::Visibility,
     pub name: syn::Ident,
+    pub generics: syn::Generics,
     pub key_enum_name: syn::Ident,
+    pub kind_enum_name: syn::Ident,
     pub omni_query_name: syn::Ident,
+    pub active_enum_name: syn::Ident,
     pub key_enum_config: GeneratedTypeConfig,
     pub strategy_structs_config: GeneratedTypeConfig,
+    pub reflect: bool,
+    pub single_component: bool,
+    pub builders: bool,
+    pub key_helpers: bool,
+    pub non_exhaustive: bool,
+    pub repr_attrs: Vec<syn::Attribute>,
+    /// Variants annotated with `#[yoetz(skip)]`, which get nothing generated for them.
+    pub skipped_variants: Vec<syn::Variant>,
 }
 
 impl TryFrom<&syn::DeriveInput> for SuggestionEnumData {
     type Error = Error;
 
     fn try_from(ast: &syn::DeriveInput) -> Result<Self, Self::Error> {
+        if let Some(lifetime) = ast.generics.lifetimes().next() {
+            return Err(Error::new_spanned(
+                lifetime,
+                "YoetzSuggestion cannot have lifetime parameters",
+            ));
+        }
         let mut result = Self {
             visibility: ast.vis.clone(),
             name: ast.ident.clone(),
+            generics: ast.generics.clone(),
             key_enum_name: syn::Ident::new(&format!("{}Key", ast.ident), ast.ident.span()),
+            kind_enum_name: syn::Ident::new(&format!("{}Kind", ast.ident), ast.ident.span()),
             omni_query_name: syn::Ident::new(&format!("{}OmniQuery", ast.ident), ast.ident.span()),
+            active_enum_name: syn::Ident::new(&format!("{}Active", ast.ident), ast.ident.span()),
             key_enum_config: GeneratedTypeConfig::default(),
             strategy_structs_config: GeneratedTypeConfig::default(),
+            reflect: false,
+            single_component: false,
+            builders: false,
+            key_helpers: false,
+            non_exhaustive: false,
+            repr_attrs: Vec::new(),
+            skipped_variants: Vec::new(),
         };
         for attr in ast.attrs.iter() {
             if attr.path().is_ident("yoetz") {
                 result.apply_attr(attr)?;
+            } else if attr.path().is_ident("non_exhaustive") {
+                result.non_exhaustive = true;
+            } else if attr.path().is_ident("repr") {
+                result.repr_attrs.push(attr.clone());
             }
         }
         Ok(result)
@@ -45,12 +77,88 @@ impl ApplyMeta for SuggestionEnumData {
             "strategy_structs" => self
                 .strategy_structs_config
                 .apply_sub_attr(expr.sub_attr()?),
+            "reflect" => {
+                expr.flag()?;
+                self.reflect = true;
+                Ok(())
+            }
+            "single_component" => {
+                expr.flag()?;
+                self.single_component = true;
+                Ok(())
+            }
+            "builders" => {
+                expr.flag()?;
+                self.builders = true;
+                Ok(())
+            }
+            "key_helpers" => {
+                expr.flag()?;
+                self.key_helpers = true;
+                Ok(())
+            }
             _ => Err(expr.unknown_name()),
         }
     }
 }
 
 impl SuggestionEnumData {
+    /// The generics of the key `enum`, which only uses the types of the key fields.
+    fn key_enum_generics(&self, variants: &[SuggestionVariantData]) -> syn::Generics {
+        generics_used_by(
+            &self.generics,
+            variants
+                .iter()
+                .flat_map(|variant| variant.iter_key_fields())
+                .map(|field| field.ty.to_token_stream())
+                .collect(),
+        )
+    }
+
+    /// The generics of the omni-query, which only uses the types of the strategy `struct`s.
+    fn omni_query_generics(&self, variants: &[SuggestionVariantData]) -> syn::Generics {
+        let generics = generics_used_by(
+            &self.generics,
+            variants
+                .iter()
+                .flat_map(|variant| variant.strategy_fields())
+                .map(|field| field.ty.to_token_stream())
+                .collect(),
+        );
+        if generics.params.is_empty() {
+            return generics;
+        }
+        with_predicates(
+            &generics,
+            variants.iter().map(|variant| {
+                let strategy_type = variant.strategy_type();
+                parse_quote!(#strategy_type: bevy::ecs::component::Component)
+            }),
+        )
+    }
+
+    /// The generics for implementing things on the suggestion `enum` itself, with the predicates
+    /// required for the generated types to be usable.
+    fn impl_generics(&self, variants: &[SuggestionVariantData]) -> syn::Generics {
+        if self.generics.params.is_empty() {
+            return self.generics.clone();
+        }
+        let key_enum_name = &self.key_enum_name;
+        let (_, key_ty_generics, _) = self.key_enum_generics(variants).split_for_impl();
+        with_predicates(
+            &self.generics,
+            variants
+                .iter()
+                .map(|variant| {
+                    let strategy_type = variant.strategy_type();
+                    parse_quote!(#strategy_type: bevy::ecs::component::Component)
+                })
+                .chain([parse_quote!(
+                    #key_enum_name #key_ty_generics: 'static + Send + Sync + Clone + PartialEq
+                )]),
+        )
+    }
+
     pub fn emit_key_enum_code(
         &self,
         variants: &[SuggestionVariantData],
@@ -62,9 +170,190 @@ impl SuggestionEnumData {
             .map(|variant| variant.emit_key_enum_variant())
             .collect::<Result<Vec<_>, _>>()?;
         let extra_derives = &self.key_enum_config.derive;
+        let extra_attrs = &self.key_enum_config.attrs;
+        let reflect_derive = self.reflect.then(|| quote!(bevy::reflect::Reflect,));
+        let generics = self.key_enum_generics(variants);
+        let where_clause = &generics.where_clause;
+        let non_exhaustive = self.non_exhaustive.then(|| quote!(#[non_exhaustive]));
         Ok(quote! {
-            #[derive(Clone, PartialEq, #(#extra_derives),*)]
-            #visibility enum #key_enum_name {
+            #[derive(Clone, PartialEq, #reflect_derive #(#extra_derives),*)]
+            #(#[#extra_attrs])*
+            #non_exhaustive
+            #visibility enum #key_enum_name #generics #where_clause {
+                #(#variant_options,)*
+            }
+        })
+    }
+
+    /// `match` arms for the skipped variants of the suggestion `enum`.
+    fn skipped_variants_arms(&self, body: impl Fn(&syn::Ident) -> TokenStream) -> TokenStream {
+        let suggestion_enum_name = &self.name;
+        let arms = self.skipped_variants.iter().map(|variant| {
+            let variant_name = &variant.ident;
+            let cfg_attrs = variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"));
+            let fields_pattern = match variant.fields {
+                syn::Fields::Named(_) => quote!({ .. }),
+                syn::Fields::Unnamed(_) => quote!((..)),
+                syn::Fields::Unit => quote!(),
+            };
+            let body = body(variant_name);
+            quote! {
+                #(#cfg_attrs)*
+                #suggestion_enum_name::#variant_name #fields_pattern => #body,
+            }
+        });
+        quote!(#(#arms)*)
+    }
+
+    /// The kind `enum`, and the helper methods of the key `enum`.
+    pub fn emit_key_enum_helpers(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        let visibility = &self.visibility;
+        let key_enum_name = &self.key_enum_name;
+        let kind_enum_name = &self.kind_enum_name;
+
+        let mut kind_variants = TokenStream::default();
+        let mut discriminant_arms = TokenStream::default();
+        let mut methods = TokenStream::default();
+
+        for variant in variants {
+            let variant_name = &variant.name;
+            let cfg_attrs = variant.cfg_attrs();
+            let fields_pattern = variant.fields_wildcard_pattern();
+            let snake_name = to_snake_case(&variant_name.to_string());
+            let is_method = format_ident!("is_{}", snake_name);
+            let as_method = format_ident!("as_{}", snake_name);
+
+            let discriminant = variant
+                .discriminant
+                .as_ref()
+                .map(|discriminant| quote!(= #discriminant));
+            kind_variants.extend(quote!(#cfg_attrs #variant_name #discriminant,));
+            discriminant_arms.extend(quote! {
+                #cfg_attrs
+                #key_enum_name::#variant_name #fields_pattern => #kind_enum_name::#variant_name,
+            });
+
+            let is_doc = format!("Check if the key is of the `{variant_name}` variant.");
+            methods.extend(quote! {
+                #cfg_attrs
+                #[doc = #is_doc]
+                pub fn #is_method(&self) -> bool {
+                    matches!(self, #key_enum_name::#variant_name #fields_pattern)
+                }
+            });
+
+            let key_fields = variant.iter_key_fields().collect::<Vec<_>>();
+            if key_fields.is_empty() {
+                continue;
+            }
+            let bindings = (0..key_fields.len())
+                .map(|i| format_ident!("field{i}"))
+                .collect::<Vec<_>>();
+            let pattern = if matches!(variant.fields, syn::Fields::Named(_)) {
+                let names = key_fields.iter().map(|field| &field.ident);
+                quote!({ #(#names: #bindings),* })
+            } else {
+                quote!((#(#bindings),*))
+            };
+            let types = key_fields.iter().map(|field| &field.ty);
+            let (return_type, value) = if key_fields.len() == 1 {
+                (quote!(#(&#types)*), quote!(#(#bindings)*))
+            } else {
+                (quote!((#(&#types),*)), quote!((#(#bindings),*)))
+            };
+            let as_doc = format!(
+                "The key fields of the `{variant_name}` variant, or `None` if the key is of a \
+                 different variant."
+            );
+            methods.extend(quote! {
+                #cfg_attrs
+                #[doc = #as_doc]
+                pub fn #as_method(&self) -> Option<#return_type> {
+                    match self {
+                        #key_enum_name::#variant_name #pattern => Some(#value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+            });
+        }
+
+        let generics = self.key_enum_generics(variants);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let kind_doc = format!("The variants of [`{key_enum_name}`], without their fields.");
+        let repr_attrs = &self.repr_attrs;
+        let non_exhaustive = self.non_exhaustive.then(|| quote!(#[non_exhaustive]));
+        Ok(quote! {
+            #[doc = #kind_doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #[allow(dead_code)]
+            #(#repr_attrs)*
+            #non_exhaustive
+            #visibility enum #kind_enum_name {
+                #kind_variants
+            }
+
+            #[allow(dead_code)]
+            impl #impl_generics #key_enum_name #ty_generics #where_clause {
+                /// The variant of the key, without its fields.
+                pub fn discriminant(&self) -> #kind_enum_name {
+                    match self {
+                        #discriminant_arms
+                    }
+                }
+
+                #methods
+            }
+        })
+    }
+
+    fn active_enum_type(&self, variants: &[SuggestionVariantData]) -> TokenStream {
+        let active_enum_name = &self.active_enum_name;
+        let (_, ty_generics, _) = self.omni_query_generics(variants).split_for_impl();
+        quote!(#active_enum_name #ty_generics)
+    }
+
+    /// With `#[yoetz(single_component)]`, the strategy `struct`s are stored inside a single
+    /// component `enum` instead of being components by themselves.
+    pub fn emit_active_enum_code(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        if !self.single_component {
+            return Ok(quote!());
+        }
+        if let Some(variant) = variants
+            .iter()
+            .find(|variant| !variant.config.also_insert.is_empty())
+        {
+            return Err(Error::new_spanned(
+                &variant.name,
+                "`also_insert` is not supported with `single_component`",
+            ));
+        }
+        let visibility = &self.visibility;
+        let active_enum_name = &self.active_enum_name;
+        let generics = self.omni_query_generics(variants);
+        let where_clause = &generics.where_clause;
+        let variant_options = variants.iter().map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let variant_name = &variant.name;
+            let strategy_type = variant.strategy_type();
+            quote!(#cfg_attrs #variant_name(#strategy_type))
+        });
+        let extra_derives = &self.strategy_structs_config.derive;
+        let extra_attrs = &self.strategy_structs_config.attrs;
+        let reflect_derive = self.reflect.then(|| quote!(bevy::reflect::Reflect,));
+        Ok(quote! {
+            #[derive(bevy::ecs::component::Component, #reflect_derive #(#extra_derives),*)]
+            #(#[#extra_attrs])*
+            #visibility enum #active_enum_name #generics #where_clause {
                 #(#variant_options,)*
             }
         })
@@ -75,17 +364,32 @@ impl SuggestionEnumData {
         variants: &[SuggestionVariantData],
     ) -> Result<TokenStream, Error> {
         let omni_query_name = &self.omni_query_name;
+        let generics = self.omni_query_generics(variants);
+        let where_clause = &generics.where_clause;
+        if self.single_component {
+            let active_enum_name = &self.active_enum_name;
+            let (_, ty_generics, _) = generics.split_for_impl();
+            return Ok(quote! {
+                #[derive(bevy::ecs::query::QueryData)]
+                #[query_data(mutable)]
+                struct #omni_query_name #generics #where_clause {
+                    active: Option<&'static mut #active_enum_name #ty_generics>,
+                }
+            });
+        }
         let strategies = variants.iter().enumerate().map(|(i, variant)| {
             let strategy_field_name = syn::Ident::new(&format!("strategy{i}"), Span::call_site());
-            let component_type = &variant.strategy_name;
+            let component_type = variant.strategy_type();
+            let cfg_attrs = variant.cfg_attrs();
             quote!(
+                #cfg_attrs
                 #strategy_field_name: Option<&'static mut #component_type>
             )
         });
         Ok(quote! {
             #[derive(bevy::ecs::query::QueryData)]
             #[query_data(mutable)]
-            struct #omni_query_name {
+            struct #omni_query_name #generics #where_clause {
                 #(#strategies,)*
             }
         })
@@ -98,24 +402,82 @@ impl SuggestionEnumData {
         let Self {
             visibility: _,
             name: suggestion_enum_name,
+            generics: _,
             key_enum_name,
+            kind_enum_name: _,
             omni_query_name,
+            active_enum_name: _,
             key_enum_config: _,
             strategy_structs_config: _,
+            reflect: _,
+            single_component,
+            builders: _,
+            key_helpers: _,
+            non_exhaustive: _,
+            repr_attrs: _,
+            skipped_variants: _,
         } = self;
+        let variant_names = variants.iter().map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let name = variant.name.to_string();
+            quote!(#cfg_attrs #name)
+        });
         let key_method = self.emit_key_method(variants)?;
+        let is_skipped_method = self.emit_is_skipped_method();
+        let variant_index_method = self.emit_variant_index_method(variants)?;
         let remove_components_method = self.emit_remove_components_method(variants)?;
         let add_components_method = self.emit_add_components_method(variants)?;
-        let update_into_components_method = self.emit_update_into_components_method(variants)?;
+        let into_components_helper = self.emit_into_components_helper(variants)?;
+        let costs_method = self.emit_costs_method(variants)?;
+        let consistency_bonus_method = self.emit_consistency_bonus_method(variants)?;
+        let tier_method = self.emit_tier_method(variants)?;
+        let fallback_method = self.emit_fallback_method(variants)?;
+        let duration_methods = self.emit_duration_methods(variants)?;
+        let interrupt_threshold_method = self.emit_interrupt_threshold_method(variants)?;
+        let planning_methods = self.emit_planning_methods(variants)?;
+        let register_types_method = self.emit_register_types_method(variants)?;
+        let impl_generics = self.impl_generics(variants);
+        let (impl_generics, ty_generics, where_clause) = impl_generics.split_for_impl();
+        let (_, key_ty_generics, _) = self.key_enum_generics(variants).split_for_impl();
+        let (_, omni_query_ty_generics, _) = self.omni_query_generics(variants).split_for_impl();
         Ok(quote! {
-            impl YoetzSuggestion for #suggestion_enum_name {
-                type Key = #key_enum_name;
-                type OmniQuery = #omni_query_name;
+            #into_components_helper
+
+            impl #impl_generics YoetzSuggestion for #suggestion_enum_name #ty_generics #where_clause {
+                type Key = #key_enum_name #key_ty_generics;
+                type OmniQuery = #omni_query_name #omni_query_ty_generics;
+
+                const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names),*];
+                const SINGLE_COMPONENT: bool = #single_component;
 
                 #key_method
+                #is_skipped_method
+                #variant_index_method
                 #remove_components_method
                 #add_components_method
-                #update_into_components_method
+
+                fn update_into_components(
+                    self,
+                    components: &mut <Self::OmniQuery as bevy::ecs::query::WorldQuery>::Item<'_>,
+                ) -> Result<(), Self> {
+                    self.__yoetz_into_components(components, false)
+                }
+
+                fn replace_into_components(
+                    self,
+                    components: &mut <Self::OmniQuery as bevy::ecs::query::WorldQuery>::Item<'_>,
+                ) -> Result<(), Self> {
+                    self.__yoetz_into_components(components, true)
+                }
+
+                #costs_method
+                #consistency_bonus_method
+                #tier_method
+                #fallback_method
+                #duration_methods
+                #interrupt_threshold_method
+                #planning_methods
+                #register_types_method
             }
         })
     }
@@ -131,41 +493,62 @@ impl SuggestionEnumData {
 
         for variant in variants {
             let variant_name = &variant.name;
-            let (source_pattern, target_pattern) = match &variant.fields {
-                syn::Fields::Named(_) => {
-                    let get_fields = variant.iter_fields_with_configs().map(|(field, config)| {
-                        let field_name = &field.ident;
-                        if config.role.unwrap() == FieldRole::Key {
-                            quote!(#field_name)
-                        } else {
-                            quote!(#field_name: _)
-                        }
-                    });
-                    let set_fields = variant.iter_key_fields().map(|field| {
-                        let field_name = &field.ident;
-                        quote!(#field_name: #field_name.clone())
-                    });
-                    (
-                        quote!({
-                            #(
-                                #get_fields
-                            ),*
-                        }),
+            let key_bindings = variant
+                .iter_bindings_with_configs()
+                .filter(|(_, _, config)| config.role.unwrap() == FieldRole::Key)
+                .map(|(_, binding, config)| (binding, config.hash_key))
+                .collect::<Vec<_>>();
+            let key_values = key_bindings
+                .iter()
+                .map(|(binding, hash_key)| {
+                    if *hash_key {
                         quote!({
-                            #(
-                                #set_fields
-                            ),*
-                        }),
-                    )
+                            let mut hasher = ::std::hash::DefaultHasher::new();
+                            ::std::hash::Hash::hash(#binding, &mut hasher);
+                            ::std::hash::Hasher::finish(&hasher)
+                        })
+                    } else {
+                        quote!(#binding.clone())
+                    }
+                })
+                .collect::<Vec<_>>();
+            let key_bindings = key_bindings
+                .into_iter()
+                .map(|(binding, _)| binding)
+                .collect::<Vec<_>>();
+            let (source_pattern, target_pattern) = match &variant.fields {
+                syn::Fields::Named(_) => (
+                    quote!({ #(#key_bindings,)* .. }),
+                    quote!({ #(#key_bindings: #key_values),* }),
+                ),
+                syn::Fields::Unnamed(_) => {
+                    let bindings =
+                        variant
+                            .iter_bindings_with_configs()
+                            .map(|(_, binding, config)| {
+                                if config.role.unwrap() == FieldRole::Key {
+                                    quote!(#binding)
+                                } else {
+                                    quote!(_)
+                                }
+                            });
+                    (quote!((#(#bindings),*)), quote!((#(#key_values),*)))
                 }
-                syn::Fields::Unnamed(_) => panic!("currently unsupported"),
                 syn::Fields::Unit => (quote!(), quote!()),
             };
 
+            variants_code.extend(variant.cfg_attrs());
             variants_code.extend(quote! {
                 #suggestion_enum_name::#variant_name #source_pattern => #key_enum_name::#variant_name #target_pattern,
             });
         }
+        variants_code.extend(self.skipped_variants_arms(|variant_name| {
+            let message = format!(
+                "{}::{} is `#[yoetz(skip)]`, so it has no key",
+                suggestion_enum_name, variant_name,
+            );
+            quote!(unreachable!(#message))
+        }));
 
         Ok(quote! {
             fn key(&self) -> Self::Key {
@@ -176,7 +559,22 @@ impl SuggestionEnumData {
         })
     }
 
-    fn emit_remove_components_method(
+    fn emit_is_skipped_method(&self) -> TokenStream {
+        if self.skipped_variants.is_empty() {
+            return TokenStream::default();
+        }
+        let skipped_variants_arms = self.skipped_variants_arms(|_| quote!(true));
+        quote! {
+            fn is_skipped(&self) -> bool {
+                match self {
+                    #skipped_variants_arms
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    fn emit_variant_index_method(
         &self,
         variants: &[SuggestionVariantData],
     ) -> Result<TokenStream, Error> {
@@ -186,21 +584,16 @@ impl SuggestionEnumData {
 
         for variant in variants {
             let variant_name = &variant.name;
-            let fields_pattern = match variant.fields {
-                syn::Fields::Named(_) => quote!({ .. }),
-                syn::Fields::Unnamed(_) => quote!((..)),
-                syn::Fields::Unit => quote!(),
-            };
-            let strategy_name = &variant.strategy_name;
+            let fields_pattern = variant.fields_wildcard_pattern();
+            let index = &variant.index;
+            variants_code.extend(variant.cfg_attrs());
             variants_code.extend(quote! {
-                #key_enum_name::#variant_name #fields_pattern => {
-                    cmd.remove::<#strategy_name>();
-                }
+                #key_enum_name::#variant_name #fields_pattern => #index,
             })
         }
 
         Ok(quote! {
-            fn remove_components(key: &Self::Key, cmd: &mut bevy::ecs::system::EntityCommands) {
+            fn variant_index(key: &Self::Key) -> usize {
                 match key {
                     #variants_code
                 }
@@ -208,6 +601,41 @@ impl SuggestionEnumData {
         })
     }
 
+    fn emit_remove_components_method(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        if self.single_component {
+            let active_type = self.active_enum_type(variants);
+            return Ok(quote! {
+                fn remove_components(_key: &Self::Key, cmd: &mut bevy::ecs::system::EntityCommands) {
+                    cmd.remove::<#active_type>();
+                }
+            });
+        }
+
+        // A table indexed by the variant index instead of a `match`, so that the removal code does
+        // not repeat the patterns of all the variants.
+        let removers = variants.iter().map(|variant| {
+            let strategy_type = variant.strategy_type();
+            let also_insert = &variant.config.also_insert;
+            let cfg_attrs = variant.cfg_attrs();
+            quote! {
+                #cfg_attrs
+                |cmd| {
+                    cmd.remove::<(#strategy_type, #(#also_insert,)*)>();
+                }
+            }
+        });
+
+        Ok(quote! {
+            fn remove_components(key: &Self::Key, cmd: &mut bevy::ecs::system::EntityCommands) {
+                let removers: &[fn(&mut bevy::ecs::system::EntityCommands)] = &[#(#removers),*];
+                removers[Self::variant_index(key)](cmd);
+            }
+        })
+    }
+
     fn emit_add_components_method(
         &self,
         variants: &[SuggestionVariantData],
@@ -218,29 +646,24 @@ impl SuggestionEnumData {
 
         for variant in variants {
             let variant_name = &variant.name;
-            let strategy_name = &variant.strategy_name;
-            let fields = variant
-                .fields
-                .iter()
-                .map(|field| &field.ident)
-                .collect::<Vec<_>>();
+            let fields_pattern = variant.fields_binding_pattern();
+            let strategy_constructor = variant.strategy_constructor();
+            let also_insert = &variant.config.also_insert;
+            let component = if self.single_component {
+                let active_enum_name = &self.active_enum_name;
+                quote!(#active_enum_name::#variant_name(#strategy_constructor))
+            } else {
+                strategy_constructor
+            };
 
-            variants_code.extend(match &variant.fields {
-                syn::Fields::Named(_) => quote! {
-                    #suggestion_enum_name::#variant_name { #(#fields),* } => {
-                        cmd.insert(#strategy_name {
-                            #(#fields),*
-                        });
-                    }
-                },
-                syn::Fields::Unnamed(_) => panic!("currently unsupported"),
-                syn::Fields::Unit => quote! {
-                    #suggestion_enum_name::#variant_name => {
-                        cmd.insert(#strategy_name);
-                    }
-                },
+            variants_code.extend(variant.cfg_attrs());
+            variants_code.extend(quote! {
+                #suggestion_enum_name::#variant_name #fields_pattern => {
+                    cmd.insert((#component, #(<#also_insert as Default>::default(),)*));
+                }
             });
         }
+        variants_code.extend(self.skipped_variants_arms(|_| quote!(unreachable!())));
 
         Ok(quote! {
             fn add_components(self, cmd: &mut bevy::ecs::system::EntityCommands) {
@@ -251,7 +674,9 @@ impl SuggestionEnumData {
         })
     }
 
-    fn emit_update_into_components_method(
+    /// Both `update_into_components` and `replace_into_components` delegate to a single
+    /// generated method, so that the patterns of all the variants are only generated once.
+    fn emit_into_components_helper(
         &self,
         variants: &[SuggestionVariantData],
     ) -> Result<TokenStream, Error> {
@@ -262,40 +687,107 @@ impl SuggestionEnumData {
         for (i, variant) in variants.iter().enumerate() {
             let strategy_field_name = syn::Ident::new(&format!("strategy{i}"), Span::call_site());
             let variant_name = &variant.name;
-            // let strategy_name = &variant.strategy_name;
-
-            let fields_pattern;
-            let update_statements;
-            match &variant.fields {
-                syn::Fields::Named(named) => {
-                    let all_fields = named.named.iter().map(|field| &field.ident);
-                    fields_pattern = quote!({ #(#all_fields),* });
-
-                    update_statements = variant
-                        .iter_fields_with_configs()
-                        .filter_map(|(field, config)| {
-                            if config.role.unwrap() == FieldRole::Input {
-                                let field_name = &field.ident;
-                                Some(quote! {
-                                    strategy_component.#field_name = #field_name;
-                                })
-                            } else {
-                                None
+            let strategy_constructor = variant.strategy_constructor();
+
+            let fields_pattern = variant.fields_binding_pattern();
+            let update_statements = variant
+                .iter_bindings_with_configs()
+                .filter_map(|(member, binding, config)| {
+                    if config.role.unwrap() != FieldRole::Input || variant.config.static_inputs {
+                        None
+                    } else if config.skip_if_eq {
+                        // Only dereference the component mutably if the value changed, so that
+                        // it does not get marked as changed.
+                        Some(quote! {
+                            if strategy_component.#member != #binding {
+                                strategy_component.#member = #binding;
                             }
                         })
-                        .collect::<Vec<_>>();
-                }
-                syn::Fields::Unnamed(_) => panic!("currently unsupported"),
-                syn::Fields::Unit => {
-                    fields_pattern = quote!();
-                    update_statements = Vec::default();
+                    } else {
+                        Some(quote! {
+                            strategy_component.#member = #binding;
+                        })
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            variants_code.extend(variant.cfg_attrs());
+            if self.single_component {
+                // The active component is replaced whatever variant it holds, but only updated if
+                // it holds the suggestion's variant. The active component is shared by all the
+                // variants, so it is only dereferenced mutably (and marked as changed) once one of
+                // the input fields needs to be written.
+                let active_enum_name = &self.active_enum_name;
+                let mut always_write = false;
+                let mut comparisons = Vec::new();
+                let mut assignments = Vec::new();
+                for (member, binding, config) in variant.iter_bindings_with_configs() {
+                    if config.role.unwrap() != FieldRole::Input || variant.config.static_inputs {
+                        continue;
+                    }
+                    if config.skip_if_eq {
+                        comparisons.push(quote!(strategy_component.#member != #binding));
+                    } else {
+                        always_write = true;
+                    }
+                    assignments.push(quote!(strategy_component.#member = #binding;));
                 }
+                let (strategy_binding, needs_write) = if always_write {
+                    (quote!(_), quote!(true))
+                } else if comparisons.is_empty() {
+                    (quote!(_), quote!(false))
+                } else {
+                    (quote!(strategy_component), quote!(#(#comparisons)||*))
+                };
+                let unmatched = quote!(#suggestion_enum_name::#variant_name #fields_pattern);
+                let (needs_write_binding, write) = if assignments.is_empty() {
+                    (quote!(_), quote!())
+                } else {
+                    let write = quote! {
+                        if needs_write {
+                            match &mut **active {
+                                #active_enum_name::#variant_name(strategy_component) => {
+                                    #( #assignments )*
+                                }
+                                #[allow(unreachable_patterns)]
+                                _ => {}
+                            }
+                        }
+                    };
+                    (quote!(needs_write), write)
+                };
+                variants_code.extend(quote! {
+                    #suggestion_enum_name::#variant_name #fields_pattern => {
+                        match components.active.as_mut() {
+                            Some(active) if replace => {
+                                **active = #active_enum_name::#variant_name(#strategy_constructor);
+                                Ok(())
+                            }
+                            Some(active) => {
+                                let #needs_write_binding = match &**active {
+                                    #active_enum_name::#variant_name(#strategy_binding) => {
+                                        #needs_write
+                                    }
+                                    #[allow(unreachable_patterns)]
+                                    _ => return Err(#unmatched),
+                                };
+                                #write
+                                Ok(())
+                            }
+                            None => Err(#unmatched),
+                        }
+                    }
+                });
+                continue;
             }
-
             variants_code.extend(quote! {
                 #suggestion_enum_name::#variant_name #fields_pattern => {
                     if let Some(strategy_component) = components.#strategy_field_name.as_mut() {
-                        #( #update_statements )*
+                        if replace {
+                            **strategy_component = #strategy_constructor;
+                        } else {
+                            #( #update_statements )*
+                        }
                         Ok(())
                     } else {
                         Err(#suggestion_enum_name::#variant_name #fields_pattern)
@@ -303,16 +795,275 @@ impl SuggestionEnumData {
                 }
             })
         }
+        variants_code.extend(self.skipped_variants_arms(|_| quote!(unreachable!())));
 
+        let impl_generics = self.impl_generics(variants);
+        let (impl_generics, ty_generics, where_clause) = impl_generics.split_for_impl();
         Ok(quote! {
-            fn update_into_components(
-                self,
-                components: &mut <Self::OmniQuery as bevy::ecs::query::WorldQuery>::Item<'_>,
-            ) -> Result<(), Self> {
+            impl #impl_generics #suggestion_enum_name #ty_generics #where_clause {
+                #[doc(hidden)]
+                fn __yoetz_into_components(
+                    self,
+                    components: &mut <<Self as YoetzSuggestion>::OmniQuery as bevy::ecs::query::WorldQuery>::Item<'_>,
+                    replace: bool,
+                ) -> Result<(), Self> {
+                    match self {
+                        #variants_code
+                    }
+                }
+            }
+        })
+    }
+
+    fn emit_costs_method(&self, variants: &[SuggestionVariantData]) -> Result<TokenStream, Error> {
+        if variants
+            .iter()
+            .all(|variant| variant.config.costs.is_empty())
+        {
+            return Ok(quote!());
+        }
+
+        let suggestion_enum_name = &self.name;
+
+        let mut variants_code = TokenStream::default();
+
+        for variant in variants {
+            let variant_name = &variant.name;
+            let fields_pattern = variant.fields_wildcard_pattern();
+            let costs = variant
+                .config
+                .costs
+                .iter()
+                .map(|(name, amount)| quote!((#name, (#amount) as f32)));
+            variants_code.extend(variant.cfg_attrs());
+            variants_code.extend(quote! {
+                #suggestion_enum_name::#variant_name #fields_pattern => &[#(#costs),*],
+            })
+        }
+        variants_code.extend(self.skipped_variants_arms(|_| quote!(&[])));
+
+        Ok(quote! {
+            fn costs(&self) -> &'static [(&'static str, f32)] {
                 match self {
                     #variants_code
                 }
             }
         })
     }
+
+    /// A method that looks up an optional per-variant value in a table indexed by the variant
+    /// index. Nothing is emitted if no variant has the value, so that the trait's default method
+    /// is used.
+    fn emit_variant_table_method(
+        variants: &[SuggestionVariantData],
+        method_name: &str,
+        value_type: TokenStream,
+        value: impl Fn(&SuggestionVariantData) -> Option<TokenStream>,
+    ) -> TokenStream {
+        let values = variants
+            .iter()
+            .map(|variant| (variant.cfg_attrs(), value(variant)))
+            .collect::<Vec<_>>();
+        if values.iter().all(|(_, value)| value.is_none()) {
+            return quote!();
+        }
+
+        let method_name = syn::Ident::new(method_name, Span::call_site());
+        let values = values.into_iter().map(|(cfg_attrs, value)| {
+            if let Some(value) = value {
+                quote!(#cfg_attrs Some(#value))
+            } else {
+                quote!(#cfg_attrs None)
+            }
+        });
+
+        quote! {
+            fn #method_name(key: &Self::Key) -> Option<#value_type> {
+                let values: &[Option<#value_type>] = &[#(#values),*];
+                values[Self::variant_index(key)]
+            }
+        }
+    }
+
+    fn emit_consistency_bonus_method(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        Ok(Self::emit_variant_table_method(
+            variants,
+            "consistency_bonus",
+            quote!(f32),
+            |variant| {
+                let bonus = variant.config.consistency_bonus.as_ref()?;
+                Some(quote!((#bonus) as f32))
+            },
+        ))
+    }
+
+    fn emit_tier_method(&self, variants: &[SuggestionVariantData]) -> Result<TokenStream, Error> {
+        Ok(Self::emit_variant_table_method(
+            variants,
+            "tier",
+            quote!(i32),
+            |variant| {
+                let tier = variant.config.tier.as_ref()?;
+                Some(quote!((#tier) as i32))
+            },
+        ))
+    }
+
+    fn emit_fallback_method(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        let mut fallbacks = variants
+            .iter()
+            .filter_map(|variant| Some((variant, variant.config.fallback.as_ref()?)));
+        let Some((variant, _)) = fallbacks.next() else {
+            return Ok(TokenStream::default());
+        };
+        if let Some((_, fallback)) = fallbacks.next() {
+            return Err(Error::new_spanned(
+                fallback,
+                "only one variant can be the `fallback`",
+            ));
+        }
+        let cfg_attrs = variant.cfg_attrs();
+        let variant_name = &variant.name;
+        Ok(quote! {
+            #cfg_attrs
+            fn fallback() -> Option<Self> {
+                Some(Self::#variant_name)
+            }
+        })
+    }
+
+    /// `interrupt_threshold` implies the variant is not interruptible, and without it a variant
+    /// that is not interruptible can only be replaced after it is released.
+    fn emit_interrupt_threshold_method(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        Ok(Self::emit_variant_table_method(
+            variants,
+            "interrupt_threshold",
+            quote!(f32),
+            |variant| {
+                if let Some(threshold) = &variant.config.interrupt_threshold {
+                    Some(quote!((#threshold) as f32))
+                } else if variant.config.interruptible.as_ref()?.value {
+                    None
+                } else {
+                    Some(quote!(f32::INFINITY))
+                }
+            },
+        ))
+    }
+
+    /// The durations are given in seconds.
+    fn emit_duration_methods(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        let to_duration = |duration: &Option<syn::Expr>| {
+            let duration = duration.as_ref()?;
+            Some(quote!(std::time::Duration::from_secs_f32((#duration) as f32)))
+        };
+        let min_duration_method = Self::emit_variant_table_method(
+            variants,
+            "min_duration",
+            quote!(std::time::Duration),
+            |variant| to_duration(&variant.config.min_duration),
+        );
+        let max_duration_method = Self::emit_variant_table_method(
+            variants,
+            "max_duration",
+            quote!(std::time::Duration),
+            |variant| to_duration(&variant.config.max_duration),
+        );
+        Ok(quote! {
+            #min_duration_method
+            #max_duration_method
+        })
+    }
+
+    fn emit_planning_methods(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        let facts_method = |method_name: &str, facts: Vec<&[(String, bool)]>| {
+            if facts.iter().all(|facts| facts.is_empty()) {
+                return quote!();
+            }
+            let method_name = syn::Ident::new(method_name, Span::call_site());
+            let values = variants.iter().zip(facts).map(|(variant, facts)| {
+                let cfg_attrs = variant.cfg_attrs();
+                let facts = facts.iter().map(|(name, value)| quote!((#name, #value)));
+                quote!(#cfg_attrs &[#(#facts),*])
+            });
+            quote! {
+                fn #method_name(key: &Self::Key) -> &'static [(&'static str, bool)] {
+                    let values: &[&'static [(&'static str, bool)]] = &[#(#values),*];
+                    values[Self::variant_index(key)]
+                }
+            }
+        };
+        let preconditions_method = facts_method(
+            "preconditions",
+            variants
+                .iter()
+                .map(|variant| variant.config.preconditions.as_slice())
+                .collect(),
+        );
+        let effects_method = facts_method(
+            "effects",
+            variants
+                .iter()
+                .map(|variant| variant.config.effects.as_slice())
+                .collect(),
+        );
+        Ok(quote! {
+            #preconditions_method
+            #effects_method
+        })
+    }
+
+    fn emit_register_types_method(
+        &self,
+        variants: &[SuggestionVariantData],
+    ) -> Result<TokenStream, Error> {
+        if !self.reflect {
+            return Ok(quote!());
+        }
+
+        let key_enum_name = &self.key_enum_name;
+        let (_, key_ty_generics, _) = self.key_enum_generics(variants).split_for_impl();
+        let strategies = variants.iter().map(|variant| {
+            let cfg_attrs = variant.cfg_attrs();
+            let strategy_type = variant.strategy_type();
+            quote! {
+                #cfg_attrs
+                {
+                    app.register_type::<#strategy_type>();
+                    app.register_type_data::<#strategy_type, bevy::ecs::reflect::ReflectComponent>();
+                }
+            }
+        });
+
+        let active = self.single_component.then(|| {
+            let active_type = self.active_enum_type(variants);
+            quote! {
+                app.register_type::<#active_type>();
+                app.register_type_data::<#active_type, bevy::ecs::reflect::ReflectComponent>();
+            }
+        });
+
+        Ok(quote! {
+            fn register_types(app: &mut bevy::app::App) {
+                app.register_type::<#key_enum_name #key_ty_generics>();
+                #(#strategies)*
+                #active
+            }
+        })
+    }
 }
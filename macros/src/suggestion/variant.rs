@@ -1,75 +1,651 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{parse_quote, Error};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Error, Token};
+
+use crate::util::{to_snake_case, ApplyMeta, AttrArg, KeyValue};
 
 use super::field::{FieldConfig, FieldRole};
+use super::generated_type::GeneratedTypeConfig;
+use super::generics::{generics_used_by, with_predicates};
 use super::suggestion_enum::SuggestionEnumData;
 
+#[derive(Default)]
+pub struct VariantConfig {
+    pub costs: Vec<(String, syn::Expr)>,
+    pub consistency_bonus: Option<syn::Expr>,
+    pub tier: Option<syn::Expr>,
+    pub min_duration: Option<syn::Expr>,
+    pub max_duration: Option<syn::Expr>,
+    pub interruptible: Option<syn::LitBool>,
+    pub interrupt_threshold: Option<syn::Expr>,
+    pub default_fields: Vec<syn::Field>,
+    pub strategy: VariantStrategyConfig,
+    pub also_insert: Vec<syn::Type>,
+    pub preconditions: Vec<(String, bool)>,
+    pub effects: Vec<(String, bool)>,
+    pub static_inputs: bool,
+    pub skip: bool,
+    pub fallback: Option<syn::Ident>,
+}
+
+impl ApplyMeta for VariantConfig {
+    fn apply_meta(&mut self, expr: AttrArg) -> Result<(), Error> {
+        match expr.name().to_string().as_str() {
+            "cost" => {
+                for cost in expr.sub_attr()?.args::<KeyValue>()? {
+                    let name = cost.name.to_string();
+                    self.costs.push((name, cost.parse_value()?));
+                }
+                Ok(())
+            }
+            "consistency_bonus" => {
+                self.consistency_bonus = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "tier" => {
+                self.tier = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "min_duration" => {
+                self.min_duration = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "max_duration" => {
+                self.max_duration = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "interruptible" => {
+                self.interruptible = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "interrupt_threshold" => {
+                self.interrupt_threshold = Some(expr.key_value()?.parse_value()?);
+                Ok(())
+            }
+            "default" => {
+                let fields = (|input: ParseStream| {
+                    Punctuated::<syn::Field, Token![,]>::parse_terminated_with(
+                        input,
+                        syn::Field::parse_named,
+                    )
+                })
+                .parse2(expr.sub_attr()?.args)?;
+                self.default_fields.extend(fields);
+                Ok(())
+            }
+            "strategy" => self.strategy.apply_sub_attr(expr.sub_attr()?),
+            "also_insert" => {
+                self.also_insert.extend(expr.sub_attr()?.args()?);
+                Ok(())
+            }
+            "precondition" => {
+                self.preconditions.extend(parse_planning_facts(expr)?);
+                Ok(())
+            }
+            "effect" => {
+                self.effects.extend(parse_planning_facts(expr)?);
+                Ok(())
+            }
+            "static_inputs" => {
+                expr.flag()?;
+                self.static_inputs = true;
+                Ok(())
+            }
+            "skip" => {
+                expr.flag()?;
+                self.skip = true;
+                Ok(())
+            }
+            "fallback" => {
+                self.fallback = Some(expr.flag()?);
+                Ok(())
+            }
+            _ => Err(expr.unknown_name()),
+        }
+    }
+}
+
+/// Parse the facts of `precondition(...)` or `effect(...)` - each fact is a name that may be
+/// negated with `!`.
+fn parse_planning_facts(expr: AttrArg) -> Result<Vec<(String, bool)>, Error> {
+    expr.sub_attr()?
+        .args::<AttrArg>()?
+        .into_iter()
+        .map(|fact| match fact {
+            AttrArg::Flag(name) => Ok((name.to_string(), true)),
+            AttrArg::Not { name, .. } => Ok((name.to_string(), false)),
+            _ => Err(fact.incorrect_type()),
+        })
+        .collect()
+}
+
+impl VariantConfig {
+    pub fn new_for(variant: &syn::Variant) -> Result<Self, Error> {
+        let mut result = Self::default();
+        for attr in variant.attrs.iter() {
+            if attr.path().is_ident("yoetz") {
+                result.apply_attr(attr)?;
+            }
+        }
+        if let (true, Some(fallback)) = (result.skip, result.fallback.as_ref()) {
+            return Err(Error::new_spanned(
+                fallback,
+                "a `skip` variant cannot be the `fallback`",
+            ));
+        }
+        Ok(result)
+    }
+}
+
+/// Customization of the strategy `struct` of a single variant, on top of the `enum`'s
+/// `strategy_structs(...)`.
+#[derive(Default)]
+pub struct VariantStrategyConfig {
+    pub name: Option<syn::Ident>,
+    pub generated_type: GeneratedTypeConfig,
+}
+
+impl ApplyMeta for VariantStrategyConfig {
+    fn apply_meta(&mut self, expr: AttrArg) -> Result<(), Error> {
+        match expr.name().to_string().as_str() {
+            "name" => {
+                let name: syn::LitStr = expr.key_value()?.parse_value()?;
+                self.name = Some(name.parse()?);
+                Ok(())
+            }
+            _ => self.generated_type.apply_meta(expr),
+        }
+    }
+}
+
 pub struct SuggestionVariantData<'a> {
     pub parent: &'a SuggestionEnumData,
+    /// An expression for the variant's index, which takes into account preceding variants that
+    /// are disabled by `#[cfg(...)]`. Filled in once all the variants are known.
+    pub index: TokenStream,
     pub name: syn::Ident,
     pub strategy_name: syn::Ident,
     pub fields: syn::Fields,
     pub fields_config: Vec<FieldConfig>,
+    pub config: VariantConfig,
+    pub cfg_attrs: Vec<syn::Attribute>,
+    pub non_exhaustive: bool,
+    pub discriminant: Option<syn::Expr>,
 }
 
 impl<'a> SuggestionVariantData<'a> {
     pub fn new(parent: &'a SuggestionEnumData, variant: &syn::Variant) -> Result<Self, Error> {
         let mut fields = variant.fields.clone();
+        // The single field of a tuple variant is an input unless specified otherwise.
+        let default_role = match &fields {
+            syn::Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => Some(FieldRole::Input),
+            _ => None,
+        };
         let fields_config = fields
             .iter_mut()
-            .map(FieldConfig::new_for)
+            .map(|field| FieldConfig::new_for(field, default_role))
             .collect::<Result<Vec<_>, _>>()?;
+        let config = VariantConfig::new_for(variant)?;
+        let mut path_fields = fields
+            .iter()
+            .zip(&fields_config)
+            .filter_map(|(field, config)| Some((field, config.path_to.as_ref()?)));
+        if let Some((field, target)) = path_fields.next() {
+            if let Some((field, _)) = path_fields.next() {
+                return Err(Error::new_spanned(
+                    field,
+                    "only one field of a variant can be `path_to`",
+                ));
+            }
+            if field.ident.is_none() {
+                return Err(Error::new_spanned(
+                    field,
+                    "`path_to` is only supported on struct variants",
+                ));
+            }
+            if !fields
+                .iter()
+                .any(|field| field.ident.as_ref() == Some(target))
+            {
+                return Err(Error::new_spanned(
+                    target,
+                    format!("variant has no field named {:?}", target.to_string()),
+                ));
+            }
+        }
+        if let Some(fallback) = config.fallback.as_ref() {
+            if !matches!(fields, syn::Fields::Unit) {
+                return Err(Error::new_spanned(
+                    fallback,
+                    "`fallback` is only supported on unit variants",
+                ));
+            }
+        }
+        if !config.default_fields.is_empty() && matches!(fields, syn::Fields::Unnamed(_)) {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "default fields are only supported on struct and unit variants",
+            ));
+        }
+        let strategy_name = config.strategy.name.clone().unwrap_or_else(|| {
+            syn::Ident::new(
+                &format!("{}{}", parent.name, variant.ident,),
+                variant.ident.span(),
+            )
+        });
         Ok(Self {
             parent,
+            index: TokenStream::default(),
             name: variant.ident.clone(),
-            strategy_name: syn::Ident::new(
-                &format!("{}{}", parent.name, variant.ident,),
-                variant.ident.span(),
-            ),
+            strategy_name,
             fields,
             fields_config,
+            config,
+            cfg_attrs: variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+                .cloned()
+                .collect(),
+            non_exhaustive: variant
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("non_exhaustive")),
+            discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr.clone()),
         })
     }
 
-    fn semicolon_if_needed(&self) -> Option<syn::token::Semi> {
-        if matches!(self.fields, syn::Fields::Named(..)) {
-            None
-        } else {
-            Some(Default::default())
+    /// The variant's `#[cfg(...)]` attributes, for putting on everything generated for it.
+    pub fn cfg_attrs(&self) -> TokenStream {
+        let cfg_attrs = &self.cfg_attrs;
+        quote!(#(#cfg_attrs)*)
+    }
+
+    /// The fields of the strategy `struct` - the fields of the variant followed by its default
+    /// fields.
+    pub fn strategy_fields(&self) -> syn::Fields {
+        if self.config.default_fields.is_empty() {
+            return self.fields.clone();
+        }
+        let mut fields = match &self.fields {
+            syn::Fields::Named(named) => named.clone(),
+            _ => syn::FieldsNamed {
+                brace_token: Default::default(),
+                named: Punctuated::new(),
+            },
+        };
+        fields
+            .named
+            .extend(self.config.default_fields.iter().cloned());
+        syn::Fields::Named(fields)
+    }
+
+    /// The generics of the strategy `struct`, which only uses the types of its fields.
+    pub fn strategy_generics(&self) -> syn::Generics {
+        generics_used_by(
+            &self.parent.generics,
+            self.strategy_fields()
+                .iter()
+                .map(|field| field.ty.to_token_stream())
+                .collect(),
+        )
+    }
+
+    /// The strategy `struct` with its generic parameters, for using it as a type.
+    pub fn strategy_type(&self) -> TokenStream {
+        let strategy_name = &self.strategy_name;
+        let generics = self.strategy_generics();
+        let (_, ty_generics, _) = generics.split_for_impl();
+        quote!(#strategy_name #ty_generics)
+    }
+
+    /// An expression that evaluates to `true` if the variant is not disabled by its `#[cfg(...)]`
+    /// attributes, or `None` if it has no such attributes.
+    pub fn cfg_predicate(&self) -> Result<Option<TokenStream>, Error> {
+        if self.cfg_attrs.is_empty() {
+            return Ok(None);
+        }
+        let predicates = self
+            .cfg_attrs
+            .iter()
+            .map(|attr| Ok(attr.meta.require_list()?.tokens.clone()))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Some(quote!(cfg!(all(#(#predicates),*)))))
+    }
+
+    pub fn fields_wildcard_pattern(&self) -> TokenStream {
+        match self.fields {
+            syn::Fields::Named(_) => quote!({ .. }),
+            syn::Fields::Unnamed(_) => quote!((..)),
+            syn::Fields::Unit => quote!(),
+        }
+    }
+
+    /// A pattern that binds all the fields (see
+    /// [`iter_bindings_with_configs`](Self::iter_bindings_with_configs)). Since the strategy
+    /// struct has the same fields as the variant, this can also be used for constructing it.
+    pub fn fields_binding_pattern(&self) -> TokenStream {
+        let bindings = self
+            .iter_bindings_with_configs()
+            .map(|(_, binding, _)| binding);
+        match self.fields {
+            syn::Fields::Named(_) => quote!({ #(#bindings),* }),
+            syn::Fields::Unnamed(_) => quote!((#(#bindings),*)),
+            syn::Fields::Unit => quote!(),
         }
     }
 
+    /// An expression that constructs the strategy `struct` from the bindings of
+    /// [`fields_binding_pattern`](Self::fields_binding_pattern), with the default fields
+    /// initialized to `Default::default()`.
+    pub fn strategy_constructor(&self) -> TokenStream {
+        let strategy_name = &self.strategy_name;
+        if self.config.default_fields.is_empty() {
+            let fields_pattern = self.fields_binding_pattern();
+            return quote!(#strategy_name #fields_pattern);
+        }
+        let bindings = self
+            .iter_bindings_with_configs()
+            .map(|(_, binding, _)| binding);
+        let default_fields = self.config.default_fields.iter().map(|field| &field.ident);
+        quote!(#strategy_name {
+            #(#bindings,)*
+            #(#default_fields: Default::default(),)*
+        })
+    }
+
     pub fn emit_strategy_code(&self) -> Result<TokenStream, Error> {
         let strategy_name = &self.strategy_name;
-        let mut fields = self.fields.clone();
-        for (field, config) in fields.iter_mut().zip(self.fields_config.iter()) {
+        let mut fields = self.strategy_fields();
+        for field in fields.iter_mut() {
             field.vis = self.parent.visibility.clone();
+        }
+        for (field, config) in fields.iter_mut().zip(self.fields_config.iter()) {
             if config.role.unwrap() == FieldRole::Key {
                 field.attrs.push(parse_quote!(#[allow(dead_code)]))
             }
         }
         let visibility = &self.parent.visibility;
-        let semicolon = self.semicolon_if_needed();
-        let extra_derives = &self.parent.strategy_structs_config.derive;
-        Ok(quote! {
-            #[derive(bevy::ecs::component::Component, #(#extra_derives),*)]
-            #visibility struct #strategy_name #fields #semicolon
-        })
+        let semicolon = (!matches!(fields, syn::Fields::Named(..))).then(<Token![;]>::default);
+        let extra_derives = self
+            .parent
+            .strategy_structs_config
+            .derive
+            .iter()
+            .chain(&self.config.strategy.generated_type.derive);
+        let extra_attrs = self
+            .parent
+            .strategy_structs_config
+            .attrs
+            .iter()
+            .chain(&self.config.strategy.generated_type.attrs);
+        let reflect_derive = self.parent.reflect.then(|| quote!(bevy::reflect::Reflect,));
+        let suggestion_enum_name = &self.parent.name;
+        let variant_name = self.name.to_string();
+        let variant_index = &self.index;
+        let cfg_attrs = self.cfg_attrs();
+        let generics = self.strategy_generics();
+        let where_clause = &generics.where_clause;
+        let struct_definition = if matches!(fields, syn::Fields::Named(..)) {
+            quote!(#visibility struct #strategy_name #generics #where_clause #fields)
+        } else {
+            quote!(#visibility struct #strategy_name #generics #fields #where_clause #semicolon)
+        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let mut output = quote! {
+            #cfg_attrs
+            #[derive(bevy::ecs::component::Component, #reflect_derive #(#extra_derives),*)]
+            #(#[#extra_attrs])*
+            #struct_definition
+
+            #cfg_attrs
+            #[allow(dead_code)]
+            impl #impl_generics #strategy_name #ty_generics #where_clause {
+                /// The name of the variant this strategy was generated from.
+                pub const VARIANT_NAME: &'static str = #variant_name;
+                /// The index of the variant this strategy was generated from.
+                pub const VARIANT_INDEX: usize = #variant_index;
+            }
+        };
+
+        // The strategy can only name its suggestion `enum` if it has all of its generic
+        // parameters.
+        if generics.params.len() == self.parent.generics.params.len() {
+            let (_, suggestion_ty_generics, _) = self.parent.generics.split_for_impl();
+            let generics = if generics.params.is_empty() {
+                generics.clone()
+            } else {
+                with_predicates(
+                    &generics,
+                    [parse_quote!(#suggestion_enum_name #suggestion_ty_generics: YoetzSuggestion)],
+                )
+            };
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            output.extend(quote! {
+                #cfg_attrs
+                impl #impl_generics YoetzStrategy for #strategy_name #ty_generics #where_clause {
+                    type Suggestion = #suggestion_enum_name #suggestion_ty_generics;
+                    const VARIANT_NAME: &'static str = #variant_name;
+                    const VARIANT_INDEX: usize = #variant_index;
+                }
+            });
+            if let Some((field, target)) = self
+                .iter_fields_with_configs()
+                .find_map(|(field, config)| Some((field, config.path_to.as_ref()?)))
+            {
+                let path = &field.ident;
+                output.extend(quote! {
+                    #cfg_attrs
+                    impl #impl_generics YoetzPathfindStrategy for #strategy_name #ty_generics #where_clause {
+                        fn path_target(&self) -> bevy::math::Vec3 {
+                            self.#target
+                        }
+
+                        fn path(&self) -> &YoetzPath {
+                            &self.#path
+                        }
+
+                        fn path_mut(&mut self) -> &mut YoetzPath {
+                            &mut self.#path
+                        }
+                    }
+                });
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// With `#[yoetz(builders)]`, a builder for constructing the variant field by field. Each field
+    /// has a type parameter on the builder - `()` until the field is set, so that `build` can only
+    /// be called once all the fields are set. Only struct variants get builders.
+    pub fn emit_builder_code(&self) -> Result<TokenStream, Error> {
+        let syn::Fields::Named(fields) = &self.fields else {
+            return Ok(quote!());
+        };
+        if fields.named.is_empty() {
+            return Ok(quote!());
+        }
+        let visibility = &self.parent.visibility;
+        let suggestion_enum_name = &self.parent.name;
+        let variant_name = &self.name;
+        let builder_name = format_ident!("{}{}Builder", suggestion_enum_name, variant_name);
+        let constructor_name = to_snake_case(&variant_name.to_string());
+        let constructor_name = if syn::parse_str::<syn::Ident>(&constructor_name).is_ok() {
+            syn::Ident::new(&constructor_name, variant_name.span())
+        } else {
+            // The name is a keyword (e.g. `Move`)
+            syn::Ident::new_raw(&constructor_name, variant_name.span())
+        };
+        let cfg_attrs = self.cfg_attrs();
+
+        let field_names = fields
+            .named
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap())
+            .collect::<Vec<_>>();
+        let field_types = fields
+            .named
+            .iter()
+            .map(|field| &field.ty)
+            .collect::<Vec<_>>();
+        let markers = (0..field_names.len())
+            .map(|i| format_ident!("__YoetzField{i}"))
+            .collect::<Vec<_>>();
+
+        let generics = &self.parent.generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let generic_args = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+                syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+                syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            })
+            .collect::<Vec<_>>();
+        let with_markers = |markers: &[&syn::Ident]| {
+            let mut generics = generics.clone();
+            generics.params.extend(
+                markers
+                    .iter()
+                    .map(|marker| -> syn::GenericParam { parse_quote!(#marker) }),
+            );
+            generics
+        };
+
+        let builder_generics = with_markers(&markers.iter().collect::<Vec<_>>());
+        let builder_where_clause = &builder_generics.where_clause;
+        let unset = field_names.iter().map(|_| quote!(()));
+        let constructor_doc = format!(
+            "Start building a [`{suggestion_enum_name}::{variant_name}`] suggestion. All the \
+             fields must be set before calling `build`."
+        );
+        let builder_doc = format!(
+            "A builder for [`{suggestion_enum_name}::{variant_name}`] suggestions. Created with \
+             [`{suggestion_enum_name}::{constructor_name}`]."
+        );
+        let mut output = quote! {
+            #cfg_attrs
+            #[doc = #builder_doc]
+            #[must_use]
+            #visibility struct #builder_name #builder_generics #builder_where_clause {
+                #(#field_names: #markers,)*
+                __yoetz_phantom: ::std::marker::PhantomData<fn() -> #suggestion_enum_name #ty_generics>,
+            }
+
+            #cfg_attrs
+            #[allow(dead_code)]
+            impl #impl_generics #suggestion_enum_name #ty_generics #where_clause {
+                #[doc = #constructor_doc]
+                #visibility fn #constructor_name() -> #builder_name<#(#generic_args,)* #(#unset),*> {
+                    #builder_name {
+                        #(#field_names: (),)*
+                        __yoetz_phantom: ::std::marker::PhantomData,
+                    }
+                }
+            }
+        };
+
+        for (i, (field_name, field_type)) in field_names.iter().zip(&field_types).enumerate() {
+            let other_markers = markers
+                .iter()
+                .enumerate()
+                .filter_map(|(j, marker)| (i != j).then_some(marker))
+                .collect::<Vec<_>>();
+            let setter_generics = with_markers(&other_markers);
+            let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+            let state_with = |value: TokenStream| {
+                markers.iter().enumerate().map(move |(j, marker)| {
+                    if i == j {
+                        value.clone()
+                    } else {
+                        marker.to_token_stream()
+                    }
+                })
+            };
+            let before = state_with(quote!(()));
+            let after = state_with(field_type.to_token_stream());
+            let other_fields = field_names
+                .iter()
+                .enumerate()
+                .filter_map(|(j, name)| (i != j).then_some(name));
+            let setter_doc = format!("Set the `{field_name}` field.");
+            output.extend(quote! {
+                #cfg_attrs
+                #[allow(dead_code)]
+                impl #setter_impl_generics #builder_name<#(#generic_args,)* #(#before),*> #where_clause {
+                    #[doc = #setter_doc]
+                    #visibility fn #field_name(
+                        self,
+                        #field_name: #field_type,
+                    ) -> #builder_name<#(#generic_args,)* #(#after),*> {
+                        #builder_name {
+                            #field_name,
+                            #(#other_fields: self.#other_fields,)*
+                            __yoetz_phantom: ::std::marker::PhantomData,
+                        }
+                    }
+                }
+            });
+        }
+
+        let build_doc = format!("Build the [`{suggestion_enum_name}::{variant_name}`] suggestion.");
+        output.extend(quote! {
+            #cfg_attrs
+            #[allow(dead_code)]
+            impl #impl_generics #builder_name<#(#generic_args,)* #(#field_types),*> #where_clause {
+                #[doc = #build_doc]
+                #visibility fn build(self) -> #suggestion_enum_name #ty_generics {
+                    #suggestion_enum_name::#variant_name {
+                        #(#field_names: self.#field_names,)*
+                    }
+                }
+            }
+        });
+
+        Ok(output)
     }
 
     pub fn iter_fields_with_configs(&self) -> impl Iterator<Item = (&syn::Field, &FieldConfig)> {
         self.fields.iter().zip(&self.fields_config)
     }
 
-    pub fn iter_key_fields(&self) -> impl Iterator<Item = &syn::Field> {
+    /// Iterate over the fields as members (for accessing them in the strategy struct), the names
+    /// to bind them to in patterns and their configs.
+    pub fn iter_bindings_with_configs(
+        &self,
+    ) -> impl Iterator<Item = (syn::Member, syn::Ident, &FieldConfig)> {
         self.iter_fields_with_configs()
-            .filter_map(|(field, config)| {
-                if config.role.unwrap() == FieldRole::Key {
-                    Some(field)
+            .enumerate()
+            .map(|(index, (field, config))| {
+                if let Some(ident) = field.ident.as_ref() {
+                    (syn::Member::Named(ident.clone()), ident.clone(), config)
                 } else {
-                    None
+                    (
+                        syn::Member::Unnamed(index.into()),
+                        format_ident!("field{index}"),
+                        config,
+                    )
+                }
+            })
+    }
+
+    /// Iterate over the key fields as they appear in the key `enum` (with the hash type for fields
+    /// that are keyed by their hash).
+    pub fn iter_key_fields(&self) -> impl Iterator<Item = syn::Field> + '_ {
+        self.iter_fields_with_configs()
+            .filter_map(|(field, config)| {
+                if config.role.unwrap() != FieldRole::Key {
+                    return None;
+                }
+                let mut field = field.clone();
+                if config.hash_key {
+                    field.ty = parse_quote!(u64);
                 }
+                Some(field)
             })
     }
 
@@ -78,18 +654,19 @@ impl<'a> SuggestionVariantData<'a> {
         let fields = match &self.fields {
             syn::Fields::Named(named) => syn::Fields::Named(syn::FieldsNamed {
                 brace_token: named.brace_token,
-                named: self.iter_key_fields().cloned().collect(),
+                named: self.iter_key_fields().collect(),
+            }),
+            syn::Fields::Unnamed(unnamed) => syn::Fields::Unnamed(syn::FieldsUnnamed {
+                paren_token: unnamed.paren_token,
+                unnamed: self.iter_key_fields().collect(),
             }),
-            syn::Fields::Unnamed(unnamed) => {
-                return Err(Error::new_spanned(
-                    unnamed,
-                    "tuple variants are currently unsupported for YoetzSuggestion, \
-                    and are resuseved for future features",
-                ));
-            }
             syn::Fields::Unit => syn::Fields::Unit,
         };
+        let cfg_attrs = self.cfg_attrs();
+        let non_exhaustive = self.non_exhaustive.then(|| quote!(#[non_exhaustive]));
         Ok(quote! {
+            #cfg_attrs
+            #non_exhaustive
             #name #fields
         })
     }
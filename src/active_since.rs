@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// When the active behavior of a [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) started.
+///
+/// Add this component next to the advisor to have it updated whenever the active behavior
+/// changes. Action systems (and suggestion systems) can then check how long the behavior has been
+/// running without querying the advisor, and without each strategy `struct` keeping its own timer:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Charge,
+/// # }
+/// fn charge(
+///     query: Query<(&AiBehaviorCharge, &YoetzActiveSince<AiBehavior>)>,
+///     time: Res<Time>,
+/// ) {
+///     for (_charge, active_since) in query.iter() {
+///         let speed_up = active_since.elapsed(&time).as_secs_f32().min(2.0);
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// The times are measured with the [`Time`] of the schedule the advisor thinks in.
+#[derive(Component)]
+pub struct YoetzActiveSince<S: YoetzSuggestion> {
+    key: Option<S::Key>,
+    since: Duration,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzActiveSince<S> {
+    fn default() -> Self {
+        Self {
+            key: None,
+            since: Duration::ZERO,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzActiveSince<S> {
+    /// The [`Key`](YoetzSuggestion::Key) of the active behavior.
+    pub fn key(&self) -> Option<&S::Key> {
+        self.key.as_ref()
+    }
+
+    /// The elapsed time when the active behavior started.
+    pub fn since(&self) -> Duration {
+        self.since
+    }
+
+    /// How long the active behavior has been running. Zero if there is no active behavior.
+    pub fn elapsed<T: Default>(&self, time: &Time<T>) -> Duration {
+        if self.key.is_some() {
+            time.elapsed().saturating_sub(self.since)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    pub(crate) fn set(&mut self, key: Option<&S::Key>, since: Duration) {
+        self.key = key.cloned();
+        self.since = since;
+    }
+}
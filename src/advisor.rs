@@ -1,6 +1,35 @@
-use bevy::ecs::query::{QueryData, WorldQuery};
-use bevy::ecs::system::EntityCommands;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::ecs::query::{QueryData, QueryItem, WorldQuery};
+use bevy::ecs::system::{EntityCommands, ParallelCommands};
 use bevy::prelude::*;
+use bevy::utils::Parallel;
+
+use crate::active_since::YoetzActiveSince;
+use crate::arbitration::{YoetzArbitration, YoetzRng};
+use crate::behavior_resources::YoetzBehaviorResources;
+use crate::census::YoetzBehaviorCensus;
+use crate::config::{
+    YoetzAggregation, YoetzConfig, YoetzConsistencyBonusMode, YoetzStarvationPolicy, YoetzTieBreak,
+};
+use crate::consideration::{YoetzConsideration, YoetzConsiderationMode, YoetzConsiderations};
+use crate::diagnostics::{YoetzDiagnostics, YoetzSuggestionDiagnostics};
+use crate::distribution::YoetzScoreDistribution;
+use crate::interaction_lock::YoetzInteractionLock;
+use crate::key_pattern::YoetzKeyPattern;
+use crate::lifecycle::{YoetzBehaviorEnded, YoetzBehaviorStarted, YoetzStarved, YoetzTransition};
+use crate::modifiers::{YoetzGlobalScoreModifiers, YoetzScoreModifiers};
+use crate::oscillation::{YoetzOscillationDamping, YoetzOscillationDetected};
+use crate::pause::YoetzPaused;
+use crate::score::YoetzScore;
+use crate::score_history::YoetzScoreHistory;
+use crate::sequence::YoetzSequence;
+use crate::switch_rate::{YoetzSwitchGroup, YoetzSwitchRateLimit};
+use crate::think_rate::YoetzThinkRate;
+use crate::transition_gate::YoetzTransitionGate;
+use crate::transitions::YoetzTransitions;
 
 #[doc(inline)]
 pub use bevy_yoetz_macros::YoetzSuggestion;
@@ -8,7 +37,9 @@ pub use bevy_yoetz_macros::YoetzSuggestion;
 /// An action suggestion for the AI agent to consider.
 ///
 /// Avoid implementing this trait manually - prefer using the
-/// [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro.
+/// [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro, or
+/// [`YoetzSimpleSuggestion`](crate::simple::YoetzSimpleSuggestion) for behaviors that are only
+/// known at runtime.
 ///
 /// `enum`s that implement this trait are mainly used as the generic parameter for [`YoetzAdvisor`]
 /// and as the data passed to it. A [`YoetzPlugin`](crate::YoetzPlugin) parametrized on them should
@@ -26,9 +57,32 @@ pub trait YoetzSuggestion: 'static + Sized + Send + Sync {
     /// macro is unsightly and there it never a reason to use it manually.
     type OmniQuery: QueryData;
 
+    /// The names of the `enum`'s variants, in the order they were declared.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Whether all the variants share a single behavior component, so that
+    /// [`replace_into_components`](Self::replace_into_components) can also switch between
+    /// variants.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro sets this for
+    /// `enum`s annotated with `#[yoetz(single_component)]`.
+    const SINGLE_COMPONENT: bool = false;
+
     /// Generate a [`Key`](Self::Key) that identifies the suggestion.
+    ///
+    /// Must not be called for [skipped](Self::is_skipped) suggestions, which have no key.
     fn key(&self) -> Self::Key;
 
+    /// Whether the suggestion is of a variant that is excluded from the behaviors (with
+    /// `#[yoetz(skip)]` - see the [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive
+    /// macro). Suggesting, forcing or offering such a suggestion does nothing.
+    fn is_skipped(&self) -> bool {
+        false
+    }
+
+    /// The index, in [`VARIANT_NAMES`](Self::VARIANT_NAMES), of the variant the key belongs to.
+    fn variant_index(key: &Self::Key) -> usize;
+
     /// Remove the behavior components that were created by a suggestion with the specified key.
     fn remove_components(key: &Self::Key, cmd: &mut EntityCommands);
 
@@ -48,27 +102,424 @@ pub trait YoetzSuggestion: 'static + Sized + Send + Sync {
         self,
         components: &mut <Self::OmniQuery as WorldQuery>::Item<'_>,
     ) -> Result<(), Self>;
+
+    /// Replace the existing behavior component of the suggestion's variant with one created from
+    /// the suggestion - including the key and state fields.
+    ///
+    /// This is used when the same variant is chosen with a different key (or, with
+    /// [`SINGLE_COMPONENT`](Self::SINGLE_COMPONENT), when any other behavior is chosen), to avoid
+    /// removing and re-inserting the component. If the entity does not have a component of that
+    /// variant, the suggestion is returned as an error.
+    fn replace_into_components(
+        self,
+        _components: &mut <Self::OmniQuery as WorldQuery>::Item<'_>,
+    ) -> Result<(), Self> {
+        Err(self)
+    }
+
+    /// The amounts of [`YoetzBehaviorResources`] the suggested behavior costs.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(cost(...))]` attributes on the variants.
+    fn costs(&self) -> &'static [(&'static str, f32)] {
+        &[]
+    }
+
+    /// A consistency bonus for the variant of the key, which overrides the advisor's
+    /// [`consistency_bonus`](YoetzAdvisor::consistency_bonus).
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(consistency_bonus = ...)]` attributes on the variants.
+    fn consistency_bonus(_key: &Self::Key) -> Option<f32> {
+        None
+    }
+
+    /// The priority tier of the variant of the key. Suggestions in a higher tier always beat
+    /// suggestions in lower tiers, regardless of their scores. `None` is tier 0.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(tier = ...)]` attributes on the variants.
+    fn tier(_key: &Self::Key) -> Option<i32> {
+        None
+    }
+
+    /// The behavior an advisor falls back to when it did not receive any suggestion, if its
+    /// [`starvation_policy`](YoetzAdvisor::starvation_policy) is
+    /// [`Fallback`](YoetzStarvationPolicy::Fallback).
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// for the unit variant annotated with `#[yoetz(fallback)]`.
+    fn fallback() -> Option<Self> {
+        None
+    }
+
+    /// The minimal time the behavior of the key stays active before the advisor may switch away
+    /// from it.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(min_duration = ...)]` attributes on the variants.
+    fn min_duration(_key: &Self::Key) -> Option<Duration> {
+        None
+    }
+
+    /// The maximal time the behavior of the key stays active before the advisor drops it.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(max_duration = ...)]` attributes on the variants.
+    fn max_duration(_key: &Self::Key) -> Option<Duration> {
+        None
+    }
+
+    /// The score a suggestion needs for replacing the behavior of the key before the action
+    /// systems [`release`](YoetzAdvisor::release) it, or `None` if the behavior can be replaced
+    /// normally.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(interruptible = false)]` and `#[yoetz(interrupt_threshold = ...)]` attributes
+    /// on the variants.
+    fn interrupt_threshold(_key: &Self::Key) -> Option<f32> {
+        None
+    }
+
+    /// The facts that must be `true` or `false` for a
+    /// [`YoetzPlanner`](crate::planner::YoetzPlanner) to plan the behavior of the key.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(precondition(...))]` attributes on the variants.
+    fn preconditions(_key: &Self::Key) -> &'static [(&'static str, bool)] {
+        &[]
+    }
+
+    /// The facts that become `true` or `false` after the behavior of the key is performed, for
+    /// planning with a [`YoetzPlanner`](crate::planner::YoetzPlanner).
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// from `#[yoetz(effect(...))]` attributes on the variants.
+    fn effects(_key: &Self::Key) -> &'static [(&'static str, bool)] {
+        &[]
+    }
+
+    /// Register the key `enum` and the strategy `struct`s in the type registry.
+    ///
+    /// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro generates this
+    /// when the `enum` is annotated with `#[yoetz(reflect)]`, and
+    /// [`YoetzPlugin`](crate::YoetzPlugin) calls it.
+    fn register_types(_app: &mut App) {}
+}
+
+/// A behavior component generated for a variant of a [`YoetzSuggestion`].
+///
+/// Avoid implementing this trait manually - it is implemented for the strategy `struct`s generated
+/// by the [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro.
+pub trait YoetzStrategy: Component {
+    /// The suggestion `enum` this strategy was generated from.
+    type Suggestion: YoetzSuggestion;
+
+    /// The name of the variant this strategy was generated from.
+    const VARIANT_NAME: &'static str;
+
+    /// The index, in [`YoetzSuggestion::VARIANT_NAMES`], of the variant this strategy was
+    /// generated from.
+    const VARIANT_INDEX: usize;
+
+    /// The name of the variant this strategy was generated from.
+    fn variant_name(&self) -> &'static str {
+        Self::VARIANT_NAME
+    }
+
+    /// The index, in [`YoetzSuggestion::VARIANT_NAMES`], of the variant this strategy was
+    /// generated from.
+    fn variant_index(&self) -> usize {
+        Self::VARIANT_INDEX
+    }
 }
 
 /// Controls an entity's AI by listening to [`YoetzSuggestion`]s and updating the entity's behavior
 /// components.
+///
+/// Settings that are `None` are taken from the [`YoetzConfig`] resource.
+///
+/// The scores are `f32`s, unless another [`YoetzScore`] type is specified as `N`.
+///
+/// When the advisor is removed from an entity, the components of its active behavior are removed
+/// as well (by an [`OnRemove`] observer the [`YoetzPlugin`](crate::YoetzPlugin) adds).
 #[derive(Component)]
-pub struct YoetzAdvisor<S: YoetzSuggestion> {
+pub struct YoetzAdvisor<S: YoetzSuggestion, N: YoetzScore = f32> {
     /// Added to score of any suggestion that matches the currently active behavior. This can be
     /// used to reduce the "flickering" when multiple suggestions are flocking around the same
     /// score.
-    pub consistency_bonus: f32,
+    pub consistency_bonus: Option<f32>,
+    /// How the [`consistency_bonus`](Self::consistency_bonus) turns into the bonus that is added
+    /// to the score - e.g. as a fraction of the score, or growing the longer the behavior is
+    /// active.
+    pub consistency_bonus_mode: Option<YoetzConsistencyBonusMode>,
+    /// Suggestions with (aggregated) scores lower than this are ignored.
+    pub score_threshold: Option<f32>,
+    /// How to choose between suggestions with the exact same score.
+    pub tie_break: Option<YoetzTieBreak>,
+    /// How to combine the scores of multiple suggestions with the same key.
+    pub aggregation: Option<YoetzAggregation>,
+    /// For this long after the advisor starts thinking, its consistency bonus ramps up linearly
+    /// from zero to its full value.
+    pub warmup: Option<Duration>,
+    /// A suggestion must win this many consecutive decisions before it replaces the active
+    /// behavior.
+    pub switch_after_wins: Option<u32>,
+    /// What the advisor does when it does not receive any suggestion.
+    pub starvation_policy: Option<YoetzStarvationPolicy>,
+    /// The advisor is starving - and its [`starvation_policy`](Self::starvation_policy) is
+    /// applied - once it did not receive any suggestion for this many consecutive thinks.
+    pub starvation_frames: Option<u32>,
+    /// Round the final scores to multiples of this resolution before comparing them (see
+    /// [`YoetzConfig::score_resolution`]).
+    pub score_resolution: Option<f32>,
+    /// How to choose the winner among the scored suggestions.
+    pub arbitration: YoetzArbitration<S>,
+    rng: Option<YoetzRng>,
+    template: Option<(String, Option<u64>)>,
     active_key: Option<S::Key>,
-    top_suggestion: Option<(f32, S)>,
+    rethink_requested: bool,
+    interrupted: bool,
+    keep_bonus: f32,
+    drop_requested: bool,
+    starved_thinks: u32,
+    challenger: Option<(S::Key, u32)>,
+    active_score: Option<N>,
+    active_costs: Vec<(&'static str, f32)>,
+    active_since: Duration,
+    first_think: Option<Duration>,
+    last_think: Duration,
+    settings: YoetzAdvisorSettings,
+    candidates: Vec<YoetzCandidate<S, N>>,
+    timed_suggestions: Vec<YoetzTimedSuggestion<S, N>>,
+    considerations: Vec<YoetzConsiderations<S>>,
+    retained_suggestions: usize,
+    gated: bool,
+    /// Score penalties of failed variants: `(variant_index, penalty, expires_at)`.
+    failure_penalties: Vec<(usize, f32, Duration)>,
+    active_cooldown: Option<Duration>,
+    /// Decaying score penalties of ended behaviors: `(key, penalty, started_at, duration)`.
+    cooldowns: Vec<(S::Key, N, Duration, Duration)>,
+    chosen_suggestion: Option<S::Key>,
+    suggestion_buffer: Option<YoetzSuggestionBuffer<S, N>>,
+    min_durations: Vec<(YoetzKeyPattern<S>, Duration)>,
+    max_durations: Vec<(YoetzKeyPattern<S>, Duration)>,
+    restored_time_in_behavior: Option<Duration>,
+    released: bool,
+    last_decision_margin: Option<N>,
+    active_source: Option<&'static str>,
+    /// A behavior to make active the next time the advisor thinks, and how long to lock it for.
+    forced: Option<(S, Option<Duration>)>,
+    /// Until when suggestions are ignored because of a forced behavior.
+    forced_until: Option<Duration>,
+    /// The keys of the steps of the sequence the active behavior is a step of, and the index of
+    /// the current step.
+    active_sequence: Option<(Vec<S::Key>, usize)>,
+    /// The sequences suggested since the last time the advisor thought, with the index of the
+    /// step each of them suggested.
+    suggested_sequences: Vec<(Vec<S::Key>, usize)>,
+    pending_active: Option<Option<S>>,
+    /// The patterns of the keys whose suggestions are ignored, and until when (`None` means until
+    /// they are cleared).
+    inhibitions: Vec<(YoetzKeyPattern<S>, Option<Duration>)>,
 }
 
-impl<S: YoetzSuggestion> YoetzAdvisor<S> {
+/// The suggestions of the last suggestion round, for when the suggestions are made in a different
+/// schedule than the one the advisor thinks in (see
+/// [`YoetzPlugin::suggest_in`](crate::YoetzPlugin::suggest_in)).
+struct YoetzSuggestionBuffer<S: YoetzSuggestion, N: YoetzScore> {
+    clone: fn(&S) -> S,
+    candidates: Vec<YoetzCandidate<S, N>>,
+    new_round: bool,
+}
+
+/// A suggestion made with [`YoetzAdvisor::suggest_for`], which is re-suggested every time the
+/// advisor thinks until it expires.
+struct YoetzTimedSuggestion<S: YoetzSuggestion, N: YoetzScore> {
+    key: S::Key,
+    score: N,
+    expires_at: Duration,
+    suggestion: S,
+    clone: fn(&S) -> S,
+}
+
+struct YoetzCandidate<S: YoetzSuggestion, N: YoetzScore> {
+    key: S::Key,
+    score: N,
+    total: N,
+    count: usize,
+    /// Set when the [`YoetzScoreModifiers`] are applied. `None` means the candidate is disabled.
+    modified_score: Option<Option<N>>,
+    extra_costs: Vec<(&'static str, f32)>,
+    cooldown: Option<Duration>,
+    /// The label of the highest scored suggestion (see [`YoetzAdvisor::suggest_labeled`]).
+    source: Option<&'static str>,
+    suggestion: S,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzCandidate<S, N> {
+    fn clone_with(&self, clone: fn(&S) -> S) -> Self {
+        Self {
+            key: self.key.clone(),
+            score: self.score,
+            total: self.total,
+            count: self.count,
+            modified_score: None,
+            extra_costs: self.extra_costs.clone(),
+            cooldown: self.cooldown,
+            source: self.source,
+            suggestion: clone(&self.suggestion),
+        }
+    }
+
+    fn aggregated_score(&self, aggregation: YoetzAggregation) -> N {
+        match aggregation {
+            YoetzAggregation::Max => self.score,
+            YoetzAggregation::Sum => self.total,
+            YoetzAggregation::Average => self.total.average(self.count),
+        }
+    }
+
+    fn costs(&self) -> impl '_ + Iterator<Item = &(&'static str, f32)> {
+        self.suggestion
+            .costs()
+            .iter()
+            .chain(self.extra_costs.iter())
+    }
+
+    /// The aggregated score after applying the [`YoetzScoreModifiers`], or `None` if they
+    /// disabled the candidate.
+    fn base_score(&self, aggregation: YoetzAggregation) -> Option<N> {
+        self.modified_score
+            .unwrap_or_else(|| Some(self.aggregated_score(aggregation)))
+    }
+}
+
+/// The settings of an advisor after filling in the ones it does not override from the
+/// [`YoetzConfig`].
+#[derive(Clone, Copy)]
+pub(crate) struct YoetzAdvisorSettings {
+    pub consistency_bonus: f32,
+    pub consistency_bonus_mode: YoetzConsistencyBonusMode,
+    pub score_threshold: f32,
+    pub tie_break: YoetzTieBreak,
+    pub aggregation: YoetzAggregation,
+    pub warmup: Duration,
+    pub switch_after_wins: u32,
+    pub starvation_policy: YoetzStarvationPolicy,
+    pub starvation_frames: u32,
+    pub score_resolution: Option<f32>,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzAdvisor<S, N> {
     /// Create a new advisor with the specified [`consistency_bonus`](Self::consistency_bonus).
     pub fn new(consistency_bonus: f32) -> Self {
         Self {
-            consistency_bonus,
+            consistency_bonus: Some(consistency_bonus),
+            ..Self::from_config()
+        }
+    }
+
+    /// Create a new advisor that takes all its settings from the [`YoetzConfig`].
+    pub fn from_config() -> Self {
+        let config = YoetzConfig::<S>::default();
+        Self {
+            consistency_bonus: None,
+            consistency_bonus_mode: None,
+            score_threshold: None,
+            tie_break: None,
+            aggregation: None,
+            warmup: None,
+            switch_after_wins: None,
+            starvation_policy: None,
+            starvation_frames: None,
+            score_resolution: None,
+            arbitration: YoetzArbitration::HighestScore,
+            rng: None,
+            template: None,
             active_key: None,
-            top_suggestion: None,
+            rethink_requested: false,
+            interrupted: false,
+            keep_bonus: 0.0,
+            drop_requested: false,
+            starved_thinks: 0,
+            challenger: None,
+            active_score: None,
+            active_costs: Vec::new(),
+            active_since: Duration::ZERO,
+            first_think: None,
+            last_think: Duration::ZERO,
+            settings: YoetzAdvisorSettings {
+                consistency_bonus: config.consistency_bonus,
+                consistency_bonus_mode: config.consistency_bonus_mode,
+                score_threshold: config.score_threshold,
+                tie_break: config.tie_break,
+                aggregation: config.aggregation,
+                warmup: config.warmup,
+                switch_after_wins: config.switch_after_wins,
+                starvation_policy: config.starvation_policy,
+                starvation_frames: config.starvation_frames,
+                score_resolution: config.score_resolution,
+            },
+            candidates: Vec::new(),
+            timed_suggestions: Vec::new(),
+            considerations: Vec::new(),
+            retained_suggestions: 1,
+            gated: false,
+            failure_penalties: Vec::new(),
+            active_cooldown: None,
+            cooldowns: Vec::new(),
+            chosen_suggestion: None,
+            suggestion_buffer: None,
+            min_durations: Vec::new(),
+            max_durations: Vec::new(),
+            restored_time_in_behavior: None,
+            released: false,
+            last_decision_margin: None,
+            active_source: None,
+            forced: None,
+            forced_until: None,
+            active_sequence: None,
+            suggested_sequences: Vec::new(),
+            pending_active: None,
+            inhibitions: Vec::new(),
+        }
+    }
+
+    /// Create a new advisor that takes its settings from the named
+    /// [`YoetzAdvisorTemplate`](crate::templates::YoetzAdvisorTemplate) in the
+    /// [`YoetzTemplates`](crate::templates::YoetzTemplates) resource.
+    ///
+    /// The template is applied right before the advisor first thinks - and again whenever the
+    /// template is [propagated](crate::templates::YoetzTemplates::propagate). Settings that the template does not
+    /// override are taken from the [`YoetzConfig`].
+    pub fn from_template(name: impl Into<String>) -> Self {
+        Self {
+            template: Some((name.into(), None)),
+            ..Self::from_config()
+        }
+    }
+
+    /// Make [`suggestions`](Self::suggestions) list the top `n` suggestions instead of only the
+    /// top one.
+    pub fn with_retained_suggestions(mut self, n: usize) -> Self {
+        self.retained_suggestions = n;
+        self
+    }
+
+    /// The name of the template the advisor was created from.
+    pub fn template_name(&self) -> Option<&str> {
+        self.template.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    pub(crate) fn template(&self) -> Option<(&str, Option<u64>)> {
+        let (name, applied_generation) = self.template.as_ref()?;
+        Some((name, *applied_generation))
+    }
+
+    pub(crate) fn set_applied_template_generation(&mut self, generation: u64) {
+        if let Some((_, applied_generation)) = self.template.as_mut() {
+            *applied_generation = Some(generation);
         }
     }
 
@@ -80,58 +531,1781 @@ impl<S: YoetzSuggestion> YoetzAdvisor<S> {
         &self.active_key
     }
 
+    /// The score (without the consistency bonus) the active behavior had when the advisor last
+    /// thought.
+    pub fn active_score(&self) -> Option<N> {
+        self.active_score
+    }
+
+    /// How much the final score (including the consistency bonus) of the winner of the last
+    /// decision was higher than that of the runner-up, or `None` if there was no runner-up.
+    ///
+    /// A small margin means the decision was a close call. The margin may be negative if the
+    /// winner was not the highest scored suggestion - e.g. when the active behavior was kept
+    /// because of its [minimal duration](Self::set_min_duration) or when the
+    /// [`arbitration`](Self::arbitration) is randomized.
+    pub fn last_decision_margin(&self) -> Option<N> {
+        self.last_decision_margin
+    }
+
+    /// The label of the suggestion that made the active behavior win the last decision, or `None`
+    /// if that suggestion was not [labeled](Self::suggest_labeled).
+    pub fn active_source(&self) -> Option<&'static str> {
+        self.active_source
+    }
+
+    /// The [`YoetzBehaviorResources`] costs of the currently active behavior, as of when it was
+    /// chosen.
+    pub fn active_costs(&self) -> &[(&'static str, f32)] {
+        &self.active_costs
+    }
+
     /// Suggest a behavior for the AI to consider.
     ///
     /// A suggestion should be sent every frame as long as it is valid - once it stops being sent
     /// it will immediately be replaced by another suggestion.
-    pub fn suggest(&mut self, score: f32, suggestion: S) {
-        if let Some((current_score, _)) = self.top_suggestion.as_ref() {
-            let bonus = if self
-                .active_key
-                .as_ref()
-                .map(|key| *key == suggestion.key())
-                .unwrap_or(false)
-            {
-                self.consistency_bonus
+    pub fn suggest(&mut self, score: N, suggestion: S) {
+        self.suggest_impl(score, suggestion, Vec::new());
+    }
+
+    /// Suggest a behavior, labeled with its source.
+    ///
+    /// The label of the winning suggestion is available from
+    /// [`active_source`](Self::active_source) (and the labels of all the suggestions from the
+    /// [`YoetzDiagnostics`]), for finding out which suggestion system drove a decision. When a
+    /// behavior is suggested multiple times, the label of the highest scored suggestion is used.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)] enum AiBehavior { Flee }
+    /// fn suggest_fleeing_from_fire(mut query: Query<&mut YoetzAdvisor<AiBehavior>>) {
+    ///     for mut advisor in query.iter_mut() {
+    ///         advisor.suggest_labeled(10.0, "fire", AiBehavior::Flee);
+    ///     }
+    /// }
+    /// ```
+    pub fn suggest_labeled(&mut self, score: N, label: &'static str, suggestion: S) {
+        if let Some(candidate) = self.suggest_impl(score, suggestion, Vec::new()) {
+            if candidate.score == score {
+                candidate.source = Some(label);
+            }
+        }
+    }
+
+    /// Suggest multiple behaviors at once.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)]
+    /// # enum AiBehavior {
+    /// #     Attack {
+    /// #         #[yoetz(key)]
+    /// #         target: Entity,
+    /// #     },
+    /// # }
+    /// # fn expensive_line_of_sight_check(_target: Entity) -> bool { true }
+    /// fn suggest_attacks(
+    ///     mut query: Query<&mut YoetzAdvisor<AiBehavior>>,
+    ///     targets_query: Query<(Entity, &GlobalTransform)>,
+    /// ) {
+    ///     for mut advisor in query.iter_mut() {
+    ///         let mut targets = targets_query
+    ///             .iter()
+    ///             .map(|(target, transform)| (10.0 - transform.translation().length(), target))
+    ///             .collect::<Vec<_>>();
+    ///         targets.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    ///         let suggestions = targets
+    ///             .into_iter()
+    ///             // Targets are sorted by score, so once one cannot win neither can the rest.
+    ///             .take_while(|(score, _)| advisor.could_win(*score))
+    ///             .filter(|(_, target)| expensive_line_of_sight_check(*target))
+    ///             .map(|(score, target)| (score, AiBehavior::Attack { target }))
+    ///             .collect::<Vec<_>>();
+    ///         advisor.suggest_many(suggestions);
+    ///     }
+    /// }
+    /// ```
+    pub fn suggest_many(&mut self, suggestions: impl IntoIterator<Item = (N, S)>) {
+        for (score, suggestion) in suggestions {
+            self.suggest(score, suggestion);
+        }
+    }
+
+    /// The key and the final score (including the consistency bonus and the penalties) of the
+    /// suggestion that would win if the advisor were to think now.
+    ///
+    /// Score modifiers, resources and other restrictions that are only applied when the advisor
+    /// thinks are not taken into account.
+    pub fn current_best(&self) -> Option<(&S::Key, N)> {
+        self.choose_candidate()
+            .map(|(index, score)| (&self.candidates[index].key, score))
+    }
+
+    /// The final score of the suggestion that would win if the advisor were to think now (see
+    /// [`current_best`](Self::current_best)).
+    pub fn current_best_score(&self) -> Option<N> {
+        self.current_best().map(|(_, score)| score)
+    }
+
+    /// Check if a new suggestion with this score could beat the suggestions made so far.
+    ///
+    /// Suggestion systems can use this to skip expensive checks for suggestions that cannot win
+    /// anyway. This is only a heuristic - it assumes the new suggestion is not for the active
+    /// behavior (which would get the consistency bonus) and ignores the restrictions that are only
+    /// applied when the advisor thinks.
+    pub fn could_win(&self, score: N) -> bool {
+        if score < N::from_f32(self.settings.score_threshold) {
+            return false;
+        }
+        self.current_best_score()
+            .is_none_or(|best_score| best_score <= score)
+    }
+
+    /// Suggest the current step of a [`YoetzSequence`].
+    ///
+    /// The first step is suggested, unless the active behavior is a step of the same sequence
+    /// (with the same keys) - in which case it's the current step of the sequence. Once the last
+    /// step [completes](Self::complete), the sequence starts over.
+    pub fn suggest_sequence(&mut self, score: N, sequence: YoetzSequence<S>) {
+        if sequence.steps.iter().any(S::is_skipped) {
+            return;
+        }
+        let keys = sequence.steps.iter().map(S::key).collect::<Vec<_>>();
+        let step = match &self.active_sequence {
+            Some((active_keys, step)) if *active_keys == keys => *step,
+            _ => 0,
+        };
+        let Some(suggestion) = sequence.steps.into_iter().nth(step) else {
+            return;
+        };
+        self.suggest(score, suggestion);
+        self.suggested_sequences.push((keys, step));
+    }
+
+    /// The index of the current step of the sequence the active behavior is a step of, and the
+    /// number of steps in that sequence.
+    pub fn sequence_progress(&self) -> Option<(usize, usize)> {
+        let (keys, step) = self.active_sequence.as_ref()?;
+        Some((*step, keys.len()))
+    }
+
+    /// Keep track of the sequence the active behavior is a step of.
+    fn track_sequence(&mut self) {
+        let suggested_sequences = std::mem::take(&mut self.suggested_sequences);
+        let Some(active_key) = self.active_key.as_ref() else {
+            self.active_sequence = None;
+            return;
+        };
+        if let Some((keys, step)) = self.active_sequence.as_ref() {
+            // Once a step completes the sequence points at the next step, but the completed step
+            // stays active until the advisor switches (which may take a while, e.g. if it is
+            // paused).
+            if keys[step.saturating_sub(1)..=*step].contains(active_key) {
+                return;
+            }
+        }
+        self.active_sequence = suggested_sequences
+            .into_iter()
+            .find(|(keys, step)| keys[*step] == *active_key);
+    }
+
+    /// Suggest a behavior that costs [`YoetzBehaviorResources`], in addition to the costs the
+    /// suggestion itself declares (see [`YoetzSuggestion::costs`]).
+    ///
+    /// If the entity cannot afford the costs, the suggestion is ignored.
+    pub fn suggest_with_costs(
+        &mut self,
+        score: N,
+        suggestion: S,
+        costs: impl IntoIterator<Item = (&'static str, f32)>,
+    ) {
+        self.suggest_impl(score, suggestion, costs.into_iter().collect());
+    }
+
+    /// Suggest a behavior that stays valid for `ttl`, even if it is not suggested again.
+    ///
+    /// Use this for suggestion systems that run less frequently than the advisor thinks (or only
+    /// on events). Until it expires, the suggestion is re-suggested every time the advisor thinks.
+    /// The TTL is measured from the last time the advisor thought, and suggesting the same key
+    /// again with `suggest_for` replaces the previous timed suggestion.
+    ///
+    /// The arguments are ordered `(score, ttl, suggestion)` so that the suggestion comes last,
+    /// like in all the other suggestion methods. This is the only TTL/expiry API - there is no
+    /// `(score, suggestion, ttl)` variant.
+    pub fn suggest_for(&mut self, score: N, ttl: Duration, suggestion: S)
+    where
+        S: Clone,
+    {
+        if suggestion.is_skipped() {
+            return;
+        }
+        let key = suggestion.key();
+        let timed_suggestion = YoetzTimedSuggestion {
+            key,
+            score,
+            expires_at: self.last_think + ttl,
+            suggestion,
+            clone: S::clone,
+        };
+        if let Some(existing) = self
+            .timed_suggestions
+            .iter_mut()
+            .find(|existing| existing.key == timed_suggestion.key)
+        {
+            *existing = timed_suggestion;
+        } else {
+            self.timed_suggestions.push(timed_suggestion);
+        }
+    }
+
+    /// Suggest a behavior whose score is combined from weighted considerations.
+    ///
+    /// Unlike suggesting the same key multiple times (which combines the scores according to the
+    /// [`aggregation`](Self::aggregation)), all the considerations of the same key that are added
+    /// before the advisor thinks - even from different systems - are combined into a single score
+    /// (see [`YoetzConsiderationMode`]):
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # use bevy_yoetz::scorers;
+    /// # #[derive(YoetzSuggestion)]
+    /// # enum AiBehavior {
+    /// #     Attack {
+    /// #         #[yoetz(key)]
+    /// #         target: Entity,
+    /// #     },
+    /// # }
+    /// # #[derive(Component)] struct Health(f32);
+    /// # #[derive(Component)] struct Ammo(f32);
+    /// fn consider_health(mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &Health)>) {
+    ///     for (mut advisor, health) in query.iter_mut() {
+    ///         # let target = Entity::PLACEHOLDER;
+    ///         advisor
+    ///             .consider(AiBehavior::Attack { target })
+    ///             .add(2.0, scorers::remap(health.0, 0.0, 100.0, 0.0, 1.0))
+    ///             .finish();
+    ///     }
+    /// }
+    ///
+    /// fn consider_ammo(mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &Ammo)>) {
+    ///     for (mut advisor, ammo) in query.iter_mut() {
+    ///         # let target = Entity::PLACEHOLDER;
+    ///         advisor
+    ///             .consider(AiBehavior::Attack { target })
+    ///             .add(1.0, scorers::remap(ammo.0, 0.0, 30.0, 0.0, 1.0))
+    ///             .finish();
+    ///     }
+    /// }
+    /// ```
+    pub fn consider(&mut self, suggestion: S) -> YoetzConsideration<'_, S, N> {
+        YoetzConsideration::new(self, suggestion)
+    }
+
+    pub(crate) fn add_considerations(
+        &mut self,
+        suggestion: S,
+        mode: Option<YoetzConsiderationMode>,
+        factors: Vec<(f32, f32)>,
+    ) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        let key = suggestion.key();
+        if let Some(existing) = self
+            .considerations
+            .iter_mut()
+            .find(|existing| existing.key == key)
+        {
+            existing.suggestion = suggestion;
+            if let Some(mode) = mode {
+                existing.mode = mode;
+            }
+            existing.factors.extend(factors);
+        } else {
+            self.considerations.push(YoetzConsiderations {
+                key,
+                suggestion,
+                mode: mode.unwrap_or_default(),
+                factors,
+            });
+        }
+    }
+
+    /// Combine the considerations into suggestions.
+    fn apply_considerations(&mut self) {
+        for considerations in std::mem::take(&mut self.considerations) {
+            let score = N::from_f32(considerations.score());
+            self.suggest_impl(score, considerations.suggestion, Vec::new());
+        }
+    }
+
+    /// Count the thinks without suggestions, and apply the
+    /// [`starvation_policy`](Self::starvation_policy) if the advisor is starving. Returns `true`
+    /// if a [`YoetzStarved`] event should be sent.
+    fn apply_starvation_policy(&mut self) -> bool {
+        if !self.candidates.is_empty() {
+            self.starved_thinks = 0;
+            return false;
+        }
+        self.starved_thinks = self.starved_thinks.saturating_add(1);
+        if !self.is_starving() {
+            return false;
+        }
+        let just_started = self.starved_thinks == self.settings.starvation_frames.max(1);
+        match self.settings.starvation_policy {
+            YoetzStarvationPolicy::Keep => false,
+            YoetzStarvationPolicy::RemoveComponents => {
+                self.drop_requested = true;
+                false
+            }
+            YoetzStarvationPolicy::Fallback => {
+                let Some(fallback) = S::fallback() else {
+                    return just_started;
+                };
+                let score = N::from_f32(self.settings.score_threshold.max(0.0));
+                self.suggest_impl(score, fallback, Vec::new());
+                false
+            }
+            YoetzStarvationPolicy::Report => just_started,
+        }
+    }
+
+    /// Drop the expired timed suggestions and suggest the rest.
+    fn apply_timed_suggestions(&mut self, now: Duration) {
+        self.timed_suggestions
+            .retain(|timed_suggestion| now < timed_suggestion.expires_at);
+        for index in 0..self.timed_suggestions.len() {
+            let timed_suggestion = &self.timed_suggestions[index];
+            let score = timed_suggestion.score;
+            let suggestion = (timed_suggestion.clone)(&timed_suggestion.suggestion);
+            self.suggest_impl(score, suggestion, Vec::new());
+        }
+    }
+
+    /// Start a new suggestion round, discarding the suggestions of the previous round.
+    fn begin_suggestion_round(&mut self, clone: fn(&S) -> S) {
+        self.candidates.clear();
+        let buffer = self
+            .suggestion_buffer
+            .get_or_insert_with(|| YoetzSuggestionBuffer {
+                clone,
+                candidates: Vec::new(),
+                new_round: false,
+            });
+        buffer.candidates.clear();
+        buffer.new_round = true;
+    }
+
+    /// Store the suggestions of a new suggestion round in the buffer, or - if there was no new
+    /// round since the last time the advisor thought - restore them from the buffer.
+    fn apply_suggestion_buffer(&mut self) {
+        let Some(buffer) = self.suggestion_buffer.as_mut() else {
+            return;
+        };
+        if buffer.new_round {
+            buffer.new_round = false;
+            buffer.candidates = self
+                .candidates
+                .iter()
+                .map(|candidate| candidate.clone_with(buffer.clone))
+                .collect();
+        } else {
+            for buffered in buffer.candidates.iter() {
+                if !self
+                    .candidates
+                    .iter()
+                    .any(|candidate| candidate.key == buffered.key)
+                {
+                    self.candidates.push(buffered.clone_with(buffer.clone));
+                }
+            }
+        }
+    }
+
+    /// Suggest a behavior that, once it ends (or fails), gets a penalty to its score that decays
+    /// over `cooldown`.
+    ///
+    /// The penalty starts at the score the behavior had when it was last chosen, and decays
+    /// linearly to zero - so the behavior is not re-selected right after it was aborted, but can
+    /// gradually compete again.
+    pub fn suggest_with_cooldown(&mut self, score: N, suggestion: S, cooldown: Duration) {
+        if let Some(candidate) = self.suggest_impl(score, suggestion, Vec::new()) {
+            candidate.cooldown = Some(cooldown);
+        }
+    }
+
+    /// Add the suggestion as a candidate, unless it is [skipped](YoetzSuggestion::is_skipped) or
+    /// its key is [inhibited](Self::inhibit).
+    fn suggest_impl(
+        &mut self,
+        score: N,
+        suggestion: S,
+        extra_costs: Vec<(&'static str, f32)>,
+    ) -> Option<&mut YoetzCandidate<S, N>> {
+        if suggestion.is_skipped() {
+            return None;
+        }
+        if !self.inhibitions.is_empty() && self.is_inhibited(&suggestion.key()) {
+            return None;
+        }
+        Some(self.add_candidate(score, suggestion, extra_costs))
+    }
+
+    fn add_candidate(
+        &mut self,
+        score: N,
+        suggestion: S,
+        extra_costs: Vec<(&'static str, f32)>,
+    ) -> &mut YoetzCandidate<S, N> {
+        let key = suggestion.key();
+        if let Some(index) = self
+            .candidates
+            .iter()
+            .position(|candidate| candidate.key == key)
+        {
+            let candidate = &mut self.candidates[index];
+            candidate.total = candidate.total + score;
+            candidate.count += 1;
+            if candidate.score <= score {
+                candidate.score = score;
+                candidate.extra_costs = extra_costs;
+                candidate.source = None;
+                candidate.suggestion = suggestion;
+            }
+            candidate
+        } else {
+            self.candidates.push(YoetzCandidate {
+                key,
+                score,
+                total: score,
+                count: 1,
+                modified_score: None,
+                extra_costs,
+                cooldown: None,
+                source: None,
+                suggestion,
+            });
+            self.candidates
+                .last_mut()
+                .expect("a candidate was just pushed")
+        }
+    }
+
+    /// Suggest a behavior that must be able to break through the stickiness of the active
+    /// behavior.
+    ///
+    /// In the next decision, the active behavior will not get its consistency bonus, and the
+    /// advisor will re-decide even if it is currently holding on to its active behavior (see
+    /// [`request_rethink`](Self::request_rethink)). Other than that, the interrupting suggestion
+    /// competes with the other suggestions normally - so it still needs the highest score to win.
+    pub fn interrupt(&mut self, score: N, suggestion: S) {
+        self.interrupted = true;
+        self.request_rethink();
+        self.suggest(score, suggestion);
+    }
+
+    /// Ask the advisor to hold on to the active behavior in the next decision, by giving it an
+    /// extra bonus on top of the consistency bonus.
+    ///
+    /// This is meant to be called by action systems, which may know things that the suggestion
+    /// systems can't see - e.g. that an animation is in the middle of a swing and it would look
+    /// bad to stop it now. Multiple calls before the same decision add up.
+    pub fn keep_current(&mut self, extra_bonus: f32) {
+        self.keep_bonus += extra_bonus;
+    }
+
+    /// Ask the advisor to drop the active behavior in the next decision.
+    ///
+    /// This is meant to be called by action systems, which may know that the behavior can no
+    /// longer be performed (e.g. the path is blocked). In the next decision, suggestions for the
+    /// active behavior are ignored, and if there are no other suggestions the behavior components
+    /// are removed.
+    pub fn request_drop(&mut self) {
+        self.drop_requested = true;
+    }
+
+    /// Allow replacing the active behavior even if its variant is not interruptible (see
+    /// [`YoetzSuggestion::interrupt_threshold`]).
+    ///
+    /// This is meant to be called by action systems once the behavior reaches a point where it is
+    /// safe to cut it (e.g. the wind-up of an attack is over). Unlike
+    /// [`request_drop`](Self::request_drop), the behavior stays active until a better suggestion
+    /// replaces it. The release lasts until the behavior ends.
+    pub fn release(&mut self) {
+        self.released = true;
+    }
+
+    /// Make a behavior active the next time the advisor thinks, bypassing the scoring.
+    ///
+    /// This is meant for scripted overrides - cutscenes, tutorials or debug commands. The forced
+    /// behavior replaces the active behavior regardless of the consistency bonus, the minimal
+    /// durations, the interruptibility of the active behavior and the switching requirements.
+    /// Once it is active, it has no score and competes with the suggestions like any other active
+    /// behavior - use [`force_for`](Self::force_for) to also ignore the suggestions for a while.
+    pub fn force(&mut self, suggestion: S) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        self.forced = Some((suggestion, None));
+    }
+
+    /// Make a behavior active the next time the advisor thinks (like [`force`](Self::force)), and
+    /// ignore all the suggestions for `lock` after that.
+    ///
+    /// The lock ends early if the behavior is dropped (e.g. with [`complete`](Self::complete)) or
+    /// with [`end_force`](Self::end_force).
+    pub fn force_for(&mut self, suggestion: S, lock: Duration) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        self.forced = Some((suggestion, Some(lock)));
+    }
+
+    /// Cancel a pending [`force`](Self::force) and end the lock of [`force_for`](Self::force_for),
+    /// so that the advisor goes back to choosing behaviors from the suggestions.
+    pub fn end_force(&mut self) {
+        self.forced = None;
+        self.forced_until = None;
+    }
+
+    /// Replace the active behavior, adding and removing the behavior components accordingly.
+    ///
+    /// This is meant for setting the advisor's state from outside - e.g. when loading a saved
+    /// game or when replicating the advisor over the network. The change is deferred to the next
+    /// time the advisor thinks, which applies it instead of choosing from the suggestions - so
+    /// the components are reconciled and the lifecycle events are sent just like with any other
+    /// decision. Afterwards the behavior competes with the suggestions like any other active
+    /// behavior.
+    ///
+    /// This cancels [`force`](Self::force) and [`force_for`](Self::force_for).
+    pub fn set_active(&mut self, suggestion: S) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        self.pending_active = Some(Some(suggestion));
+        self.end_force();
+    }
+
+    /// Remove the active behavior and its components the next time the advisor thinks, instead
+    /// of choosing from the suggestions (see [`set_active`](Self::set_active)).
+    pub fn clear_active(&mut self) {
+        self.pending_active = Some(None);
+        self.end_force();
+    }
+
+    /// Whether the advisor is ignoring the suggestions because of [`force_for`](Self::force_for).
+    pub fn is_force_locked(&self) -> bool {
+        self.forced.as_ref().is_some_and(|(_, lock)| lock.is_some())
+            || self
+                .forced_until
+                .is_some_and(|forced_until| self.last_think < forced_until)
+    }
+
+    /// Report that the active behavior has finished.
+    ///
+    /// This is meant to be called by action systems. In the next decision the active behavior is
+    /// dropped (see [`request_drop`](Self::request_drop)) and the advisor re-selects from the
+    /// fresh suggestions (see [`request_rethink`](Self::request_rethink)).
+    ///
+    /// If the active behavior is a step of a [`YoetzSequence`], the sequence advances to its next
+    /// step.
+    pub fn complete(&mut self) {
+        if let Some((keys, step)) = self.active_sequence.as_mut() {
+            if keys.get(*step) == self.active_key.as_ref() {
+                *step += 1;
+                if keys.len() <= *step {
+                    self.active_sequence = None;
+                }
+            }
+        }
+        self.request_drop();
+        self.request_rethink();
+    }
+
+    /// Report that the active behavior has failed.
+    ///
+    /// This behaves like [`complete`](Self::complete), except that if the active behavior is a
+    /// step of a [`YoetzSequence`] the sequence is abandoned instead of advanced. Use
+    /// [`fail_with_penalty`](Self::fail_with_penalty) to also prevent the failed variant from
+    /// being picked again right away.
+    pub fn fail(&mut self) {
+        self.active_sequence = None;
+        self.request_drop();
+        self.request_rethink();
+    }
+
+    /// Report that the active behavior has failed, and lower the scores of all the suggestions
+    /// of its variant by `penalty` for the next `duration`.
+    pub fn fail_with_penalty(&mut self, penalty: f32, duration: Duration) {
+        if let Some(active_key) = self.active_key.as_ref() {
+            self.failure_penalties.push((
+                S::variant_index(active_key),
+                penalty,
+                self.last_think + duration,
+            ));
+        }
+        self.fail();
+    }
+
+    /// Ignore the suggestions whose keys match the pattern for `duration` (measured from the last
+    /// time the advisor thought).
+    ///
+    /// This is meant for gameplay code that knows a behavior should not be attempted for a while,
+    /// without having to fold that knowledge into the scores of the suggestions:
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)]
+    /// # enum AiBehavior {
+    /// #     OpenDoor {
+    /// #         #[yoetz(key)]
+    /// #         door: Entity,
+    /// #     },
+    /// # }
+    /// # #[derive(Component)] struct DoorIsLocked;
+    /// fn give_up_on_locked_doors(
+    ///     mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &AiBehaviorOpenDoor)>,
+    ///     doors_query: Query<(), With<DoorIsLocked>>,
+    /// ) {
+    ///     for (mut advisor, open_door) in query.iter_mut() {
+    ///         if doors_query.contains(open_door.door) {
+    ///             advisor.inhibit(
+    ///                 YoetzKeyPattern::Key(AiBehaviorKey::OpenDoor {
+    ///                     door: open_door.door,
+    ///                 }),
+    ///                 Duration::from_secs(10),
+    ///             );
+    ///             advisor.fail();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Suggestions that were already made before the advisor thinks are discarded too. The active
+    /// behavior is not dropped when it gets inhibited - but since its suggestions are ignored, any
+    /// other suggestion replaces it.
+    pub fn inhibit(&mut self, pattern: YoetzKeyPattern<S>, duration: Duration) {
+        self.add_inhibition(pattern, Some(self.last_think + duration));
+    }
+
+    /// Ignore the suggestions whose keys match the pattern (like [`inhibit`](Self::inhibit))
+    /// until [`clear_inhibitions`](Self::clear_inhibitions) is called.
+    pub fn inhibit_until_cleared(&mut self, pattern: YoetzKeyPattern<S>) {
+        self.add_inhibition(pattern, None);
+    }
+
+    /// Remove all the inhibitions, including the ones that did not expire yet.
+    pub fn clear_inhibitions(&mut self) {
+        self.inhibitions.clear();
+    }
+
+    /// Check if the suggestions of the key are ignored because of [`inhibit`](Self::inhibit) or
+    /// [`inhibit_until_cleared`](Self::inhibit_until_cleared).
+    pub fn is_inhibited(&self, key: &S::Key) -> bool {
+        self.inhibitions.iter().any(|(pattern, expires_at)| {
+            expires_at.is_none_or(|expires_at| self.last_think < expires_at) && pattern.matches(key)
+        })
+    }
+
+    fn add_inhibition(&mut self, pattern: YoetzKeyPattern<S>, expires_at: Option<Duration>) {
+        self.candidates
+            .retain(|candidate| !pattern.matches(&candidate.key));
+        self.inhibitions.push((pattern, expires_at));
+    }
+
+    /// Make the advisor re-decide as soon as possible, even if it is currently holding on to its
+    /// active behavior - it ignores the [minimal duration](Self::set_min_duration) of the active
+    /// behavior, its [`YoetzThinkRate`](crate::think_rate::YoetzThinkRate) and the
+    /// [`YoetzThinkCadence`](crate::cadence::YoetzThinkCadence).
+    ///
+    /// Use this when something urgent happens that the advisor must respond to immediately. See
+    /// also [`YoetzPlugin::rethink_on_change`](crate::YoetzPlugin::rethink_on_change) and
+    /// [`YoetzPlugin::rethink_when`](crate::YoetzPlugin::rethink_when).
+    pub fn request_rethink(&mut self) {
+        self.rethink_requested = true;
+    }
+
+    /// The keys and scores (including the consistency bonus) of the top suggestions, sorted from
+    /// the highest score. The number of suggestions is limited by
+    /// [`with_retained_suggestions`](Self::with_retained_suggestions) (by default only the top
+    /// suggestion is listed).
+    ///
+    /// Use this, together with [`choose_suggestion`](Self::choose_suggestion), from a system that
+    /// runs after [`YoetzSystemSet::Suggest`](crate::YoetzSystemSet::Suggest) to implement custom
+    /// arbitration. Note that the [`YoetzScoreModifiers`] and the other per-entity adjustments are
+    /// only applied when the advisor thinks, so they are not reflected in these scores.
+    pub fn suggestions(&self) -> Vec<(&S::Key, N)> {
+        let mut suggestions = self.final_scores().collect::<Vec<_>>();
+        suggestions.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        suggestions.truncate(self.retained_suggestions);
+        suggestions
+    }
+
+    /// Make the suggestion with the given key win the next decision, regardless of its score.
+    ///
+    /// Only the [`suggestions`](Self::suggestions) can be chosen - returns `false` (and does
+    /// nothing) for any other key. If the chosen suggestion becomes ineligible by the time the
+    /// advisor thinks (e.g. it gets disabled by a [`YoetzScoreModifiers`]), the decision is made
+    /// normally.
+    pub fn choose_suggestion(&mut self, key: &S::Key) -> bool {
+        if !self
+            .suggestions()
+            .iter()
+            .any(|(suggestion_key, _)| *suggestion_key == key)
+        {
+            return false;
+        }
+        self.chosen_suggestion = Some(key.clone());
+        true
+    }
+
+    /// Check if [`request_rethink`](Self::request_rethink) was called since the last time the
+    /// advisor thought.
+    pub fn is_rethink_requested(&self) -> bool {
+        self.rethink_requested
+    }
+
+    /// Check if the advisor did not receive any suggestion for the last
+    /// [`starvation_frames`](Self::starvation_frames) times it thought.
+    ///
+    /// A fallback suggested by the [`starvation_policy`](Self::starvation_policy) does not end
+    /// the starvation.
+    pub fn is_starving(&self) -> bool {
+        self.starved_thinks >= self.settings.starvation_frames.max(1)
+    }
+
+    /// A structured summary of the advisor's state, for logging, UI and assertions.
+    ///
+    /// The timing information is as of the last time the advisor thought, and the top suggestion
+    /// is the one that would win if the advisor were to think now.
+    pub fn snapshot(&self) -> YoetzAdvisorSnapshot<S::Key, N> {
+        YoetzAdvisorSnapshot {
+            active_key: self.active_key.clone(),
+            active_score: self.active_score,
+            time_in_behavior: if self.active_key.is_some() {
+                self.last_think.saturating_sub(self.active_since)
             } else {
-                0.0
+                Duration::ZERO
+            },
+            top_suggestion: self
+                .choose_candidate()
+                .map(|(index, score)| (self.candidates[index].key.clone(), score)),
+        }
+    }
+
+    /// Restore the active behavior from a [`snapshot`](Self::snapshot) - e.g. when loading a saved
+    /// game - so that the advisor continues it instead of deciding from scratch.
+    ///
+    /// The behavior is restored with its
+    /// [`time_in_behavior`](YoetzAdvisorSnapshot::time_in_behavior), and the consistency bonus
+    /// applies to it the next time the advisor thinks. If the entity
+    /// does not have the behavior's strategy component (e.g. because it was not saved), it gets
+    /// created from the first suggestion of the behavior.
+    ///
+    /// This does not add or remove components, so it should be called before the advisor ever
+    /// thinks. Use [`set_active`](Self::set_active) to change the behavior of an advisor that
+    /// already has one.
+    pub fn restore(&mut self, snapshot: &YoetzAdvisorSnapshot<S::Key, N>) {
+        self.active_key = snapshot.active_key.clone();
+        self.active_score = snapshot.active_score;
+        self.restored_time_in_behavior = Some(snapshot.time_in_behavior);
+    }
+
+    fn reset_requests(&mut self) {
+        self.rethink_requested = false;
+        self.interrupted = false;
+        self.keep_bonus = 0.0;
+        self.drop_requested = false;
+        self.chosen_suggestion = None;
+    }
+
+    fn drop_active(&mut self, entity: Entity, commands: &mut Commands) {
+        if let Some(old_key) = self.active_key.take() {
+            S::remove_components(&old_key, &mut commands.entity(entity));
+        }
+        self.active_score = None;
+        self.active_costs.clear();
+        self.active_cooldown = None;
+        self.released = false;
+        self.last_decision_margin = None;
+        self.active_source = None;
+        self.forced_until = None;
+    }
+
+    pub(crate) fn num_candidates(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Whether the advisor is nested in a parent behavior (see
+    /// [`YoetzPlugin::nested_in`](crate::YoetzPlugin::nested_in)) which is not active.
+    pub(crate) fn is_gated(&self) -> bool {
+        self.gated
+    }
+
+    pub(crate) fn set_gated(&mut self, gated: bool) {
+        self.gated = gated;
+    }
+
+    pub(crate) fn resolve_settings(&mut self, config: &YoetzConfig<S>) {
+        self.settings = YoetzAdvisorSettings {
+            consistency_bonus: self.consistency_bonus.unwrap_or(config.consistency_bonus),
+            consistency_bonus_mode: self
+                .consistency_bonus_mode
+                .unwrap_or(config.consistency_bonus_mode),
+            score_threshold: self.score_threshold.unwrap_or(config.score_threshold),
+            tie_break: self.tie_break.unwrap_or(config.tie_break),
+            aggregation: self.aggregation.unwrap_or(config.aggregation),
+            warmup: self.warmup.unwrap_or(config.warmup),
+            switch_after_wins: self.switch_after_wins.unwrap_or(config.switch_after_wins),
+            starvation_policy: self.starvation_policy.unwrap_or(config.starvation_policy),
+            starvation_frames: self.starvation_frames.unwrap_or(config.starvation_frames),
+            score_resolution: self.score_resolution.or(config.score_resolution),
+        };
+    }
+
+    /// The consistency bonus, after applying the warm-up.
+    fn effective_consistency_bonus(&self) -> f32 {
+        self.settings.consistency_bonus * self.warmup_factor()
+    }
+
+    /// How much of the consistency bonus is applied during the warm-up, from 0.0 to 1.0.
+    fn warmup_factor(&self) -> f32 {
+        let Some(first_think) = self.first_think else {
+            return 0.0;
+        };
+        if self.settings.warmup.is_zero() {
+            return 1.0;
+        }
+        let warmed_up_for = self.last_think.saturating_sub(first_think);
+        (warmed_up_for.as_secs_f32() / self.settings.warmup.as_secs_f32()).min(1.0)
+    }
+
+    fn failure_penalty(&self, key: &S::Key) -> N {
+        let variant_index = S::variant_index(key);
+        self.failure_penalties
+            .iter()
+            .filter(|(penalized_variant, _, _)| *penalized_variant == variant_index)
+            .fold(N::ZERO, |total, (_, penalty, _)| total.add_f32(*penalty))
+    }
+
+    fn cooldown_penalty(&self, key: &S::Key) -> N {
+        self.cooldowns
+            .iter()
+            .filter(|(cooling_key, _, _, _)| cooling_key == key)
+            .map(|(_, penalty, started_at, duration)| {
+                let elapsed = self.last_think.saturating_sub(*started_at);
+                penalty.mul_f32((1.0 - elapsed.as_secs_f32() / duration.as_secs_f32()).max(0.0))
+            })
+            .fold(N::ZERO, |total, penalty| total + penalty)
+    }
+
+    /// Start the cooldown of a behavior that ended.
+    fn start_cooldown(&mut self, key: S::Key, penalty: N, cooldown: Duration) {
+        self.cooldowns
+            .retain(|(cooling_key, _, _, _)| *cooling_key != key);
+        self.cooldowns
+            .push((key, penalty, self.last_think, cooldown));
+    }
+
+    fn is_active(&self, key: &S::Key) -> bool {
+        self.active_key.as_ref() == Some(key)
+    }
+
+    /// Apply the modifiers, in order, to the aggregated scores of the candidates.
+    fn apply_modifiers(&mut self, modifiers: &[&YoetzScoreModifiers<S>]) {
+        let aggregation = self.settings.aggregation;
+        for candidate in self.candidates.iter_mut() {
+            candidate.modified_score = Some(modifiers.iter().try_fold(
+                candidate.aggregated_score(aggregation),
+                |score, modifiers| modifiers.apply(&candidate.key, score),
+            ));
+        }
+    }
+
+    fn apply_resources(&mut self, resources: &YoetzBehaviorResources) {
+        for candidate in self.candidates.iter_mut() {
+            if !resources.can_afford(candidate.costs()) {
+                candidate.modified_score = Some(None);
+            }
+        }
+    }
+
+    fn restrict_to(&mut self, allowed: &YoetzKeyPattern<S>) {
+        for candidate in self.candidates.iter_mut() {
+            if !allowed.matches(&candidate.key) {
+                candidate.modified_score = Some(None);
+            }
+        }
+    }
+
+    /// Disable or penalize the candidates the table does not allow switching to from the active
+    /// behavior.
+    fn apply_transitions(&mut self, transitions: &YoetzTransitions<S>) {
+        let Some(active_key) = self.active_key.as_ref() else {
+            return;
+        };
+        let aggregation = self.settings.aggregation;
+        for candidate in self.candidates.iter_mut() {
+            if &candidate.key == active_key {
+                continue;
+            }
+            candidate.modified_score = Some(
+                transitions
+                    .penalty(active_key, &candidate.key)
+                    .and_then(|penalty| Some(candidate.base_score(aggregation)?.add_f32(-penalty))),
+            );
+        }
+    }
+
+    /// Whether any candidate other than the active behavior has a final score of at least
+    /// `threshold`.
+    fn has_urgent(&self, threshold: N) -> bool {
+        self.final_scores()
+            .any(|(key, score)| threshold <= score && !self.is_active(key))
+    }
+
+    /// Disable all the candidates except for the active behavior, so that thinking only updates
+    /// it.
+    fn hold_active(&mut self) {
+        for candidate in self.candidates.iter_mut() {
+            if self.active_key.as_ref() != Some(&candidate.key) {
+                candidate.modified_score = Some(None);
+            }
+        }
+    }
+
+    /// Disable the candidates below the highest tier (see [`YoetzSuggestion::tier`]) that has an
+    /// eligible candidate.
+    fn apply_tiers(&mut self) {
+        let tier = |candidate: &YoetzCandidate<S, N>| S::tier(&candidate.key).unwrap_or(0);
+        if self.candidates.iter().all(|candidate| tier(candidate) == 0) {
+            return;
+        }
+        let consistency_bonus = self.effective_consistency_bonus();
+        let Some(top_tier) = self
+            .candidates
+            .iter()
+            .filter(|candidate| self.final_score(candidate, consistency_bonus).is_some())
+            .map(tier)
+            .max()
+        else {
+            return;
+        };
+        for candidate in self.candidates.iter_mut() {
+            if tier(candidate) < top_tier {
+                candidate.modified_score = Some(None);
+            }
+        }
+    }
+
+    /// The score of a candidate after applying all the bonuses, or `None` if the candidate is
+    /// not eligible.
+    fn final_score(&self, candidate: &YoetzCandidate<S, N>, consistency_bonus: f32) -> Option<N> {
+        let mut score = candidate.base_score(self.settings.aggregation)?;
+        if !self.failure_penalties.is_empty() {
+            score = score - self.failure_penalty(&candidate.key);
+        }
+        if !self.cooldowns.is_empty() {
+            score = score - self.cooldown_penalty(&candidate.key);
+        }
+        let score = self.round_score(score);
+        if score < N::from_f32(self.settings.score_threshold) {
+            return None;
+        }
+        if self.is_active(&candidate.key) && self.drop_requested {
+            return None;
+        }
+        Some(self.round_score(score + self.applied_bonus(candidate, consistency_bonus)))
+    }
+
+    /// Round a score to the [`score_resolution`](Self::score_resolution), if there is one.
+    fn round_score(&self, score: N) -> N {
+        match self.settings.score_resolution {
+            Some(resolution) if 0.0 < resolution => score.round_to(resolution),
+            _ => score,
+        }
+    }
+
+    /// The bonus (consistency bonus and kept bonus) a candidate gets for being the active
+    /// behavior.
+    fn applied_bonus(&self, candidate: &YoetzCandidate<S, N>, consistency_bonus: f32) -> N {
+        if !self.is_active(&candidate.key) || self.interrupted {
+            return N::ZERO;
+        }
+        let consistency_bonus = match S::consistency_bonus(&candidate.key) {
+            Some(variant_bonus) => variant_bonus * self.warmup_factor(),
+            None => consistency_bonus,
+        };
+        let base_score = candidate.base_score(self.settings.aggregation);
+        let bonus = match self.settings.consistency_bonus_mode {
+            YoetzConsistencyBonusMode::Fixed => N::ZERO.add_f32(consistency_bonus),
+            YoetzConsistencyBonusMode::Proportional => {
+                let score = base_score.unwrap_or(N::ZERO);
+                let magnitude = if score < N::ZERO {
+                    N::ZERO - score
+                } else {
+                    score
+                };
+                magnitude.mul_f32(consistency_bonus)
+            }
+            mode => N::from_f32(mode.bonus(
+                consistency_bonus,
+                base_score.map_or(0.0, N::to_f32),
+                self.last_think.saturating_sub(self.active_since),
+            )),
+        };
+        bonus.add_f32(self.keep_bonus)
+    }
+
+    /// A breakdown of the scores of all the candidates, including the ineligible ones.
+    pub(crate) fn diagnose(&self) -> impl '_ + Iterator<Item = YoetzSuggestionDiagnostics<S::Key>> {
+        let aggregation = self.settings.aggregation;
+        let consistency_bonus = self.effective_consistency_bonus();
+        self.candidates
+            .iter()
+            .map(move |candidate| YoetzSuggestionDiagnostics {
+                key: candidate.key.clone(),
+                score: candidate.aggregated_score(aggregation).to_f32(),
+                modified_score: candidate.base_score(aggregation).map(N::to_f32),
+                bonus: self.applied_bonus(candidate, consistency_bonus).to_f32(),
+                final_score: self
+                    .final_score(candidate, consistency_bonus)
+                    .map(N::to_f32),
+                source: candidate.source,
+            })
+    }
+
+    /// The keys and final scores of all the eligible candidates.
+    fn final_scores(&self) -> impl '_ + Iterator<Item = (&S::Key, N)> {
+        let consistency_bonus = self.effective_consistency_bonus();
+        self.candidates.iter().filter_map(move |candidate| {
+            Some((
+                &candidate.key,
+                self.final_score(candidate, consistency_bonus)?,
+            ))
+        })
+    }
+
+    /// Set the minimal time behaviors that match the pattern stay active before the advisor may
+    /// switch away from them, overriding the `#[yoetz(min_duration = ...)]` of their variants.
+    ///
+    /// Until the minimal duration elapses, the advisor keeps the behavior even if other
+    /// suggestions beat it or it is no longer suggested. [`interrupt`](Self::interrupt),
+    /// [`request_drop`](Self::request_drop) and [`complete`](Self::complete) still end it
+    /// immediately, and [`request_rethink`](Self::request_rethink) lets the advisor re-decide
+    /// regardless of it.
+    ///
+    /// When multiple patterns match a behavior, the one that was set last is used.
+    pub fn set_min_duration(&mut self, pattern: YoetzKeyPattern<S>, duration: Duration) {
+        self.min_durations.push((pattern, duration));
+    }
+
+    /// Set the maximal time behaviors that match the pattern stay active, overriding the
+    /// `#[yoetz(max_duration = ...)]` of their variants.
+    ///
+    /// Once the maximal duration elapses, the advisor drops the behavior (as if
+    /// [`request_drop`](Self::request_drop) was called) and chooses another one. It may choose the
+    /// same behavior again later, which starts a new duration.
+    ///
+    /// When multiple patterns match a behavior, the one that was set last is used.
+    pub fn set_max_duration(&mut self, pattern: YoetzKeyPattern<S>, duration: Duration) {
+        self.max_durations.push((pattern, duration));
+    }
+
+    fn duration_limit(
+        limits: &[(YoetzKeyPattern<S>, Duration)],
+        key: &S::Key,
+        default: fn(&S::Key) -> Option<Duration>,
+    ) -> Option<Duration> {
+        limits
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.matches(key))
+            .map(|(_, duration)| *duration)
+            .or_else(|| default(key))
+    }
+
+    fn time_in_behavior(&self) -> Duration {
+        self.last_think.saturating_sub(self.active_since)
+    }
+
+    /// Whether the active behavior has not been active for its minimal duration yet, and nothing
+    /// requested to end it or to re-decide.
+    fn within_min_duration(&self) -> bool {
+        let Some(active_key) = self.active_key.as_ref() else {
+            return false;
+        };
+        if self.interrupted || self.drop_requested || self.rethink_requested {
+            return false;
+        }
+        Self::duration_limit(&self.min_durations, active_key, S::min_duration)
+            .is_some_and(|min_duration| self.time_in_behavior() < min_duration)
+    }
+
+    /// Whether a suggestion with the given final score may replace the active behavior, as far as
+    /// its [interruptibility](YoetzSuggestion::interrupt_threshold) is concerned.
+    fn may_interrupt_with(&self, score: N) -> bool {
+        let Some(active_key) = self.active_key.as_ref() else {
+            return true;
+        };
+        if self.released || self.drop_requested {
+            return true;
+        }
+        S::interrupt_threshold(active_key).is_none_or(|threshold| N::from_f32(threshold) <= score)
+    }
+
+    fn exceeded_max_duration(&self) -> bool {
+        let Some(active_key) = self.active_key.as_ref() else {
+            return false;
+        };
+        Self::duration_limit(&self.max_durations, active_key, S::max_duration)
+            .is_some_and(|max_duration| max_duration <= self.time_in_behavior())
+    }
+
+    /// Keep the incumbent (by returning its index instead of the winner's) until the winner has
+    /// won enough consecutive decisions.
+    fn apply_switch_requirement(&mut self, winner: usize) -> usize {
+        if self.settings.switch_after_wins <= 1
+            || self.interrupted
+            || self.chosen_suggestion.as_ref() == Some(&self.candidates[winner].key)
+        {
+            self.challenger = None;
+            return winner;
+        }
+        let aggregation = self.settings.aggregation;
+        let key = &self.candidates[winner].key;
+        let incumbent = self.candidates.iter().position(|candidate| {
+            self.is_active(&candidate.key) && candidate.base_score(aggregation).is_some()
+        });
+        let Some(incumbent) = incumbent.filter(|_| !self.is_active(key) && !self.drop_requested)
+        else {
+            self.challenger = None;
+            return winner;
+        };
+        let streak = match &self.challenger {
+            Some((challenger_key, streak)) if challenger_key == key => streak + 1,
+            _ => 1,
+        };
+        if self.settings.switch_after_wins <= streak {
+            self.challenger = None;
+            winner
+        } else {
+            self.challenger = Some((key.clone(), streak));
+            incumbent
+        }
+    }
+
+    /// Find the index of the winning candidate and its final score.
+    fn choose_candidate(&self) -> Option<(usize, N)> {
+        let settings = &self.settings;
+        let consistency_bonus = self.effective_consistency_bonus();
+        if let Some(chosen) = self.chosen_suggestion.as_ref() {
+            let chosen = self
+                .candidates
+                .iter()
+                .enumerate()
+                .find_map(|(index, candidate)| {
+                    if candidate.key != *chosen {
+                        return None;
+                    }
+                    Some((index, self.final_score(candidate, consistency_bonus)?))
+                });
+            if chosen.is_some() {
+                return chosen;
+            }
+        }
+        let mut best: Option<(usize, N, bool)> = None;
+        for (index, candidate) in self.candidates.iter().enumerate() {
+            let Some(score) = self.final_score(candidate, consistency_bonus) else {
+                continue;
             };
-            if score + bonus < *current_score {
-                return;
+            let is_active = self.is_active(&candidate.key);
+            let is_better = match best {
+                None => true,
+                Some((best_index, best_score, best_is_active)) => {
+                    if score == best_score {
+                        match settings.tie_break {
+                            YoetzTieBreak::PreferLatest => true,
+                            YoetzTieBreak::PreferEarliest => false,
+                            YoetzTieBreak::PreferActive => is_active || !best_is_active,
+                            YoetzTieBreak::PreferKeyOrder => {
+                                S::variant_index(&candidate.key)
+                                    < S::variant_index(&self.candidates[best_index].key)
+                            }
+                        }
+                    } else {
+                        best_score < score
+                    }
+                }
+            };
+            if is_better {
+                best = Some((index, score, is_active));
             }
         }
-        self.top_suggestion = Some((score, suggestion));
+        best.map(|(index, score, _)| (index, score))
+    }
+
+    fn decision_margin(&self, winner: usize) -> Option<N> {
+        let consistency_bonus = self.effective_consistency_bonus();
+        let winner_score = self.final_score(&self.candidates[winner], consistency_bonus)?;
+        let runner_up_score = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != winner)
+            .filter_map(|(_, candidate)| self.final_score(candidate, consistency_bonus))
+            .reduce(|best, score| if best < score { score } else { best })?;
+        Some(winner_score - runner_up_score)
+    }
+
+    /// Like [`choose_candidate`](Self::choose_candidate), but with the
+    /// [`arbitration`](Self::arbitration) policy.
+    fn arbitrate(&mut self, entity: Entity) -> Option<(usize, N)> {
+        if self.chosen_suggestion.is_some()
+            || matches!(self.arbitration, YoetzArbitration::HighestScore)
+        {
+            return self.choose_candidate();
+        }
+        let consistency_bonus = self.effective_consistency_bonus();
+        let scored = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                Some((index, self.final_score(candidate, consistency_bonus)?))
+            })
+            .collect::<Vec<_>>();
+        let suggestions = scored
+            .iter()
+            .map(|(index, score)| (score.to_f32(), &self.candidates[*index].key))
+            .collect::<Vec<_>>();
+        let rng = self
+            .rng
+            .get_or_insert_with(|| YoetzRng::new(entity.to_bits()));
+        let chosen = self.arbitration.choose(&suggestions, rng)?;
+        Some(scored[chosen])
     }
 }
 
-pub fn update_advisor<S: YoetzSuggestion>(
-    mut query: Query<(Entity, &mut YoetzAdvisor<S>, S::OmniQuery)>,
-    mut commands: Commands,
+type AdvisorUpdateData<S, N> = (
+    Entity,
+    &'static mut YoetzAdvisor<S, N>,
+    <S as YoetzSuggestion>::OmniQuery,
+    Option<&'static mut YoetzOscillationDamping<S>>,
+    AdvisorReportsData<S>,
+    Option<&'static YoetzScoreModifiers<S>>,
+    Option<&'static mut YoetzBehaviorResources>,
+    Option<&'static YoetzInteractionLock<S>>,
+    Option<&'static YoetzSwitchGroup>,
+    Option<&'static YoetzPaused>,
+    Option<&'static mut YoetzTransitionGate<S>>,
+    Option<&'static mut YoetzThinkRate<S, N>>,
+);
+
+/// The optional components that report on the decisions of the advisor.
+type AdvisorReportsData<S> = (
+    Option<&'static mut YoetzScoreDistribution<S>>,
+    Option<&'static mut YoetzDiagnostics<S>>,
+    Option<&'static mut YoetzScoreHistory<S>>,
+    Option<&'static mut YoetzActiveSince<S>>,
+);
+
+/// The events sent while updating the advisors, buffered per thread so that the advisors can be
+/// updated in parallel.
+pub struct YoetzUpdateEvents<S: YoetzSuggestion> {
+    oscillations: Vec<YoetzOscillationDetected<S>>,
+    started: Vec<YoetzBehaviorStarted<S>>,
+    ended: Vec<YoetzBehaviorEnded<S>>,
+    starved: Vec<YoetzStarved<S>>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzUpdateEvents<S> {
+    fn default() -> Self {
+        Self {
+            oscillations: Vec::new(),
+            started: Vec::new(),
+            ended: Vec::new(),
+            starved: Vec::new(),
+        }
+    }
+}
+
+/// Updates all the advisors of `S`.
+///
+/// The advisors are updated in parallel, with the commands and the events buffered per thread and
+/// applied afterwards. If there is a [`YoetzSwitchRateLimit`] they are updated serially instead,
+/// because the rate limit depends on the order of the decisions - and so are they in the
+/// [`deterministic`](YoetzConfig::deterministic) mode.
+#[allow(clippy::too_many_arguments)]
+pub fn update_advisor<S: YoetzSuggestion, N: YoetzScore>(
+    mut query: Query<AdvisorUpdateData<S, N>>,
+    config: Res<YoetzConfig<S>>,
+    global_modifiers: Res<YoetzGlobalScoreModifiers<S>>,
+    transitions: Option<Res<YoetzTransitions<S>>>,
+    mut census: ResMut<YoetzBehaviorCensus<S>>,
+    time: Res<Time>,
+    mut oscillation_events: EventWriter<YoetzOscillationDetected<S>>,
+    mut started_events: EventWriter<YoetzBehaviorStarted<S>>,
+    mut ended_events: EventWriter<YoetzBehaviorEnded<S>>,
+    mut starved_events: EventWriter<YoetzStarved<S>>,
+    mut switch_rate_limit: Option<ResMut<YoetzSwitchRateLimit<S>>>,
+    mut events: Local<Parallel<YoetzUpdateEvents<S>>>,
+    commands: ParallelCommands,
 ) {
-    for (entity, mut advisor, mut components) in query.iter_mut() {
-        let Some((_, mut suggestion)) = advisor.top_suggestion.take() else {
-            continue;
+    let context = AdvisorUpdateContext {
+        config: &config,
+        global_modifiers: &global_modifiers,
+        transitions: transitions.as_deref(),
+        now: time.elapsed(),
+    };
+    if config.deterministic || switch_rate_limit.is_some() {
+        if let Some(switch_rate_limit) = switch_rate_limit.as_mut() {
+            switch_rate_limit.reset();
+        }
+        let mut events = events.borrow_local_mut();
+        commands.command_scope(|mut commands| {
+            for item in query.iter_mut() {
+                context.update(
+                    item,
+                    switch_rate_limit.as_deref_mut(),
+                    &mut events,
+                    &mut commands,
+                );
+            }
+        });
+    } else {
+        query.par_iter_mut().for_each(|item| {
+            events.scope(|events| {
+                commands.command_scope(|mut commands| {
+                    context.update(item, None, events, &mut commands);
+                });
+            });
+        });
+    }
+    for events in events.iter_mut() {
+        oscillation_events.send_batch(events.oscillations.drain(..));
+        ended_events.send_batch(events.ended.drain(..));
+        started_events.send_batch(events.started.drain(..));
+        starved_events.send_batch(events.starved.drain(..));
+    }
+    census.reset();
+    for (_, advisor, ..) in query.iter() {
+        if let Some(active_key) = advisor.active_key.as_ref() {
+            census.count(active_key);
+        }
+    }
+}
+
+struct AdvisorUpdateContext<'a, S: YoetzSuggestion> {
+    config: &'a YoetzConfig<S>,
+    global_modifiers: &'a YoetzScoreModifiers<S>,
+    transitions: Option<&'a YoetzTransitions<S>>,
+    now: Duration,
+}
+
+impl<S: YoetzSuggestion> AdvisorUpdateContext<'_, S> {
+    fn update<N: YoetzScore>(
+        &self,
+        (
+            entity,
+            mut advisor,
+            mut components,
+            mut oscillation_damping,
+            (distribution, mut diagnostics, mut history, active_since),
+            modifiers,
+            mut resources,
+            interaction_lock,
+            switch_group,
+            paused,
+            mut gate,
+            think_rate,
+        ): QueryItem<'_, AdvisorUpdateData<S, N>>,
+        switch_rate_limit: Option<&mut YoetzSwitchRateLimit<S>>,
+        events: &mut YoetzUpdateEvents<S>,
+        commands: &mut Commands,
+    ) {
+        let now = self.now;
+        advisor.resolve_settings(self.config);
+        // The advisor may not think on every run of the schedule (e.g. with a think cadence), so
+        // the think rate timer is ticked with all the time that passed since the last think.
+        let since_last_think = if advisor.first_think.is_some() {
+            now.saturating_sub(advisor.last_think)
+        } else {
+            Duration::ZERO
         };
-        let key = suggestion.key();
-        let mut cmd;
-        if let Some(old_key) = advisor.active_key.as_ref() {
-            if *old_key == key {
-                let update_result = suggestion.update_into_components(&mut components);
-                if let Err(update_result) = update_result {
-                    warn!(
-                        "Components were wrong - will not update, add them with a command instead"
-                    );
-                    suggestion = update_result;
-                } else {
-                    continue;
+        advisor.last_think = now;
+        advisor.first_think.get_or_insert(now);
+        if let Some(time_in_behavior) = advisor.restored_time_in_behavior.take() {
+            advisor.active_since = now.saturating_sub(time_in_behavior);
+        }
+        advisor
+            .failure_penalties
+            .retain(|(_, _, expires_at)| now < *expires_at);
+        advisor
+            .inhibitions
+            .retain(|(_, expires_at)| expires_at.is_none_or(|expires_at| now < expires_at));
+        advisor
+            .cooldowns
+            .retain(|(_, _, started_at, duration)| now < *started_at + *duration);
+        if advisor.exceeded_max_duration() {
+            advisor.drop_requested = true;
+        }
+        advisor.apply_suggestion_buffer();
+        advisor.apply_timed_suggestions(now);
+        advisor.apply_considerations();
+        if advisor.apply_starvation_policy() {
+            events.starved.push(YoetzStarved {
+                entity,
+                _phantom: PhantomData,
+            });
+        }
+        if let Some(oscillation_damping) = oscillation_damping.as_ref() {
+            advisor.keep_bonus += oscillation_damping.bonus(now);
+        }
+        match (self.global_modifiers.is_empty(), modifiers) {
+            (true, None) => {}
+            (true, Some(modifiers)) => advisor.apply_modifiers(&[modifiers]),
+            (false, None) => advisor.apply_modifiers(&[self.global_modifiers]),
+            (false, Some(modifiers)) => {
+                advisor.apply_modifiers(&[self.global_modifiers, modifiers])
+            }
+        }
+        if let Some(resources) = resources.as_ref() {
+            advisor.apply_resources(resources);
+        }
+        let suspended = match interaction_lock.map(|lock| lock.allowed()) {
+            None => false,
+            Some(None) => true,
+            Some(Some(allowed)) => {
+                advisor.restrict_to(allowed);
+                false
+            }
+        };
+        if let Some(transitions) = self.transitions {
+            advisor.apply_transitions(transitions);
+        }
+        advisor.apply_tiers();
+        if let Some(mut distribution) = distribution {
+            distribution.fill(
+                advisor
+                    .final_scores()
+                    .map(|(key, score)| (key, score.to_f32())),
+            );
+        }
+        let mut rate_limit = switch_rate_limit.zip(switch_group);
+        let may_switch = rate_limit
+            .as_ref()
+            .is_none_or(|(limit, group)| limit.may_switch(**group));
+        if let Some(diagnostics) = diagnostics.as_mut() {
+            diagnostics.record(now, advisor.diagnose());
+        }
+        let history_scores = history.is_some().then(|| {
+            advisor
+                .final_scores()
+                .map(|(key, score)| (key.clone(), score.to_f32()))
+                .collect::<Vec<_>>()
+        });
+        if let Some(mut think_rate) = think_rate {
+            let due = think_rate.timer.tick(since_last_think).just_finished();
+            if advisor.active_key.is_some() && !advisor.drop_requested {
+                if advisor.rethink_requested
+                    || think_rate
+                        .urgent_threshold
+                        .is_some_and(|threshold| advisor.has_urgent(threshold))
+                {
+                    think_rate.timer.reset();
+                } else if !due {
+                    advisor.hold_active();
                 }
             }
-            cmd = commands.entity(entity);
-            S::remove_components(old_key, &mut cmd)
+        }
+        let previous_key = advisor.active_key.clone();
+        let previous_cooldown = advisor
+            .active_cooldown
+            .zip(advisor.active_score)
+            .filter(|_| previous_key.is_some());
+        let switched = if advisor.gated || paused == Some(&YoetzPaused::Clear) {
+            advisor.candidates.clear();
+            advisor.drop_active(entity, commands);
+            false
+        } else if let Some(pending) = advisor.pending_active.take() {
+            if let Some(gate) = gate.as_deref_mut() {
+                gate.cancel();
+            }
+            advisor.candidates.clear();
+            if let Some(suggestion) = pending {
+                advisor.add_candidate(N::ZERO, suggestion, Vec::new());
+                let switched = activate(entity, &mut advisor, &mut components, 0, commands);
+                advisor.active_score = None;
+                advisor.last_decision_margin = None;
+                switched
+            } else {
+                advisor.drop_active(entity, commands);
+                false
+            }
+        } else if let Some((suggestion, lock)) = advisor.forced.take() {
+            if let Some(gate) = gate.as_deref_mut() {
+                gate.cancel();
+            }
+            advisor.candidates.clear();
+            advisor.add_candidate(N::ZERO, suggestion, Vec::new());
+            let switched = activate(entity, &mut advisor, &mut components, 0, commands);
+            advisor.active_score = None;
+            advisor.last_decision_margin = None;
+            advisor.forced_until = lock.map(|lock| now + lock);
+            switched
+        } else if advisor
+            .forced_until
+            .is_some_and(|forced_until| now < forced_until)
+        {
+            advisor.candidates.clear();
+            if advisor.drop_requested {
+                advisor.drop_active(entity, commands);
+            }
+            false
+        } else if suspended || paused == Some(&YoetzPaused::Freeze) {
+            advisor.candidates.clear();
+            false
         } else {
-            cmd = commands.entity(entity);
+            think(
+                entity,
+                &mut advisor,
+                &mut components,
+                may_switch,
+                gate.as_deref_mut(),
+                commands,
+            )
+        };
+        if let (true, Some((limit, group))) = (switched, rate_limit.as_mut()) {
+            limit.count_switch(**group);
+        }
+        advisor.track_sequence();
+        advisor.reset_requests();
+        if let Some(diagnostics) = diagnostics.as_mut() {
+            diagnostics.record_decision(advisor.active_key.clone(), switched);
+        }
+        if let (Some(history), Some(scores)) = (history.as_mut(), history_scores) {
+            history.record(now, scores, advisor.active_key.as_ref());
+        }
+        if let Some(mut active_since) = active_since {
+            if active_since.key() != advisor.active_key.as_ref()
+                || active_since.since() != advisor.active_since
+            {
+                active_since.set(advisor.active_key.as_ref(), advisor.active_since);
+            }
+        }
+        if previous_key != advisor.active_key {
+            if let (Some(key), Some((cooldown, penalty))) =
+                (previous_key.as_ref(), previous_cooldown)
+            {
+                advisor.start_cooldown(key.clone(), penalty, cooldown);
+            }
+            if let Some(key) = previous_key.clone() {
+                events.ended.push(YoetzBehaviorEnded { entity, key });
+            }
+            if let Some(key) = advisor.active_key.clone() {
+                events.started.push(YoetzBehaviorStarted { entity, key });
+            }
+            if let Some(transition) =
+                YoetzTransition::<S>::new(previous_key, advisor.active_key.clone())
+            {
+                commands.trigger_targets(transition, entity);
+            }
+        }
+        if let (true, Some(resources)) = (switched, resources.as_mut()) {
+            if resources.spend_on_selection {
+                resources.spend(advisor.active_costs.iter());
+            }
+        }
+        if let (true, Some(oscillation_damping), Some(active_key)) = (
+            switched,
+            oscillation_damping.as_mut(),
+            advisor.active_key.as_ref(),
+        ) {
+            if let Some((keys, switches)) = oscillation_damping.record_switch(now, active_key) {
+                events.oscillations.push(YoetzOscillationDetected {
+                    entity,
+                    keys,
+                    switches,
+                });
+            }
         }
-        suggestion.add_components(&mut cmd);
-        advisor.active_key = Some(key);
     }
 }
+
+/// When an advisor is removed from an entity that stays alive (e.g. an entity that gets reused by
+/// a pool), remove the components of its active behavior along with it.
+pub(crate) fn remove_behavior_on_advisor_removal<S: YoetzSuggestion, N: YoetzScore>(
+    trigger: Trigger<OnRemove, YoetzAdvisor<S, N>>,
+    query: Query<&YoetzAdvisor<S, N>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let Some(active_key) = query
+        .get(entity)
+        .ok()
+        .and_then(|advisor| advisor.active_key.clone())
+    else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        // If the entity was despawned, the behavior components were despawned with it.
+        if !world.entities().contains(entity) {
+            return;
+        }
+        S::remove_components(&active_key, &mut world.commands().entity(entity));
+        world.flush();
+    });
+}
+
+pub fn begin_suggestion_round<S: YoetzSuggestion + Clone, N: YoetzScore>(
+    mut query: Query<&mut YoetzAdvisor<S, N>>,
+) {
+    for mut advisor in query.iter_mut() {
+        advisor.begin_suggestion_round(S::clone);
+    }
+}
+
+/// Returns `true` if a new behavior became active.
+fn think<S: YoetzSuggestion, N: YoetzScore>(
+    entity: Entity,
+    advisor: &mut YoetzAdvisor<S, N>,
+    components: &mut <S::OmniQuery as WorldQuery>::Item<'_>,
+    may_switch: bool,
+    gate: Option<&mut YoetzTransitionGate<S>>,
+    commands: &mut Commands,
+) -> bool {
+    let Some((index, score)) = advisor.arbitrate(entity) else {
+        advisor.candidates.clear();
+        if advisor.drop_requested {
+            advisor.drop_active(entity, commands);
+        }
+        return false;
+    };
+    let mut index = advisor.apply_switch_requirement(index);
+    let switching = !advisor.is_active(&advisor.candidates[index].key);
+    let blocked =
+        !may_switch || advisor.within_min_duration() || !advisor.may_interrupt_with(score);
+    let gate_closed = match gate {
+        Some(gate) if switching && advisor.active_key.is_some() => {
+            !blocked && gate.hold(&advisor.candidates[index].key)
+        }
+        Some(gate) => {
+            gate.cancel();
+            false
+        }
+        None => false,
+    };
+    if (blocked || gate_closed) && switching {
+        let aggregation = advisor.settings.aggregation;
+        let incumbent = advisor.candidates.iter().position(|candidate| {
+            advisor.is_active(&candidate.key) && candidate.base_score(aggregation).is_some()
+        });
+        let Some(incumbent) = incumbent else {
+            advisor.candidates.clear();
+            return false;
+        };
+        index = incumbent;
+    }
+    activate(entity, advisor, components, index, commands)
+}
+
+/// Make the candidate at `index` the active behavior.
+///
+/// Returns `true` if a new behavior became active.
+fn activate<S: YoetzSuggestion, N: YoetzScore>(
+    entity: Entity,
+    advisor: &mut YoetzAdvisor<S, N>,
+    components: &mut <S::OmniQuery as WorldQuery>::Item<'_>,
+    index: usize,
+    commands: &mut Commands,
+) -> bool {
+    advisor.active_score = advisor.candidates[index].base_score(advisor.settings.aggregation);
+    advisor.last_decision_margin = advisor.decision_margin(index);
+    let YoetzCandidate {
+        key,
+        mut suggestion,
+        extra_costs,
+        cooldown,
+        source,
+        ..
+    } = advisor.candidates.swap_remove(index);
+    advisor.active_cooldown = cooldown;
+    advisor.active_source = source;
+    advisor.candidates.clear();
+    let mut cmd;
+    if let Some(old_key) = advisor.active_key.as_ref() {
+        if *old_key == key {
+            let update_result = suggestion.update_into_components(components);
+            if let Err(update_result) = update_result {
+                warn!("Components were wrong - will not update, add them with a command instead");
+                suggestion = update_result;
+            } else {
+                return false;
+            }
+        }
+        set_costs(&mut advisor.active_costs, &suggestion, &extra_costs);
+        if S::SINGLE_COMPONENT || S::variant_index(old_key) == S::variant_index(&key) {
+            // Only the key fields changed (or all the variants share the same component), so the
+            // component can be replaced in place instead of being removed and re-inserted.
+            match suggestion.replace_into_components(components) {
+                Ok(()) => {
+                    advisor.active_key = Some(key);
+                    advisor.active_since = advisor.last_think;
+                    advisor.released = false;
+                    return true;
+                }
+                Err(replace_result) => {
+                    suggestion = replace_result;
+                }
+            }
+        }
+        cmd = commands.entity(entity);
+        S::remove_components(old_key, &mut cmd)
+    } else {
+        set_costs(&mut advisor.active_costs, &suggestion, &extra_costs);
+        cmd = commands.entity(entity);
+    }
+    suggestion.add_components(&mut cmd);
+    advisor.active_key = Some(key);
+    advisor.active_since = advisor.last_think;
+    advisor.released = false;
+    true
+}
+
+fn set_costs<S: YoetzSuggestion>(
+    costs: &mut Vec<(&'static str, f32)>,
+    suggestion: &S,
+    extra_costs: &[(&'static str, f32)],
+) {
+    costs.clear();
+    costs.extend(suggestion.costs().iter().chain(extra_costs.iter()));
+}
+
+/// A plain summary of a [`YoetzAdvisor`]'s state, created with [`YoetzAdvisor::snapshot`].
+///
+/// With the `serde` feature, this can be serialized (as long as the key `enum` can - see the
+/// `key_enum(serde)` setting of the [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion)
+/// macro) for saving the active behaviors, and restored with [`YoetzAdvisor::restore`].
+///
+/// The scores are kept in the advisor's score type (see [`YoetzScore`]), so restoring a snapshot
+/// of an integer-scored advisor is exact.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YoetzAdvisorSnapshot<K, N = f32> {
+    /// The [`Key`](YoetzSuggestion::Key) of the currently active behavior.
+    pub active_key: Option<K>,
+    /// The score (without the consistency bonus) the active behavior had when the advisor last
+    /// thought.
+    pub active_score: Option<N>,
+    /// How long the active behavior has been active.
+    pub time_in_behavior: Duration,
+    /// The key and the score (including the consistency bonus) of the suggestion that would win
+    /// if the advisor were to think now.
+    pub top_suggestion: Option<(K, N)>,
+}
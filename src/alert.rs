@@ -0,0 +1,326 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{update_advisor, YoetzAdvisor, YoetzSuggestion};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::modifiers::{update_score_modifiers, YoetzScoreModifier, YoetzScoreModifiers};
+use crate::{YoetzInternalSystemSet, YoetzSystemSet};
+
+/// The rungs of the alert escalation ladder.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub enum YoetzAlertLevel {
+    /// Nothing unusual was perceived.
+    #[default]
+    Calm,
+    /// Something was perceived, but not enough to be sure.
+    Suspicious,
+    /// The entity is sure something is wrong and is actively looking for it.
+    Alerted,
+    /// The entity is fighting.
+    Combat,
+}
+
+/// Tracks an entity's alertness, driven by perception stimuli.
+///
+/// Perception systems call [`stimulate`](Self::stimulate) to raise the alertness meter, which
+/// decays over time. The [`level`](Self::level) is derived from the meter using the
+/// [`thresholds`](Self::thresholds) - escalation is immediate, and de-escalation happens as the
+/// meter decays. Use [`YoetzAlertEffects`] to make the level affect the entity's behaviors.
+#[derive(Component, Debug, Clone)]
+pub struct YoetzAlert {
+    /// The minimal meter values for [`Suspicious`](YoetzAlertLevel::Suspicious),
+    /// [`Alerted`](YoetzAlertLevel::Alerted) and [`Combat`](YoetzAlertLevel::Combat).
+    pub thresholds: [f32; 3],
+    /// How much the meter decreases every second.
+    pub decay_rate: f32,
+    /// The meter cannot go above this value.
+    pub max_meter: f32,
+    meter: f32,
+    level: YoetzAlertLevel,
+}
+
+impl YoetzAlert {
+    /// Create a calm alert with the specified thresholds for
+    /// [`Suspicious`](YoetzAlertLevel::Suspicious), [`Alerted`](YoetzAlertLevel::Alerted) and
+    /// [`Combat`](YoetzAlertLevel::Combat), that does not decay.
+    pub fn new(thresholds: [f32; 3]) -> Self {
+        Self {
+            thresholds,
+            decay_rate: 0.0,
+            max_meter: thresholds[2],
+            meter: 0.0,
+            level: YoetzAlertLevel::Calm,
+        }
+    }
+
+    /// Set the [`decay_rate`](Self::decay_rate).
+    pub fn with_decay_rate(mut self, decay_rate: f32) -> Self {
+        self.decay_rate = decay_rate;
+        self
+    }
+
+    /// Set the [`max_meter`](Self::max_meter).
+    pub fn with_max_meter(mut self, max_meter: f32) -> Self {
+        self.max_meter = max_meter;
+        self
+    }
+
+    /// Raise the alertness meter because of a perceived stimulus.
+    pub fn stimulate(&mut self, amount: f32) {
+        self.meter = (self.meter + amount).min(self.max_meter);
+    }
+
+    /// Raise the alertness meter to at least the threshold of the specified level.
+    pub fn escalate_to(&mut self, level: YoetzAlertLevel) {
+        let threshold = match level {
+            YoetzAlertLevel::Calm => 0.0,
+            YoetzAlertLevel::Suspicious => self.thresholds[0],
+            YoetzAlertLevel::Alerted => self.thresholds[1],
+            YoetzAlertLevel::Combat => self.thresholds[2],
+        };
+        self.meter = self.meter.max(threshold);
+    }
+
+    /// The current value of the alertness meter.
+    pub fn meter(&self) -> f32 {
+        self.meter
+    }
+
+    /// The current alert level, as of the last time the [`YoetzAlertPlugin`] updated it.
+    pub fn level(&self) -> YoetzAlertLevel {
+        self.level
+    }
+
+    fn level_for_meter(&self) -> YoetzAlertLevel {
+        if self.thresholds[2] <= self.meter {
+            YoetzAlertLevel::Combat
+        } else if self.thresholds[1] <= self.meter {
+            YoetzAlertLevel::Alerted
+        } else if self.thresholds[0] <= self.meter {
+            YoetzAlertLevel::Suspicious
+        } else {
+            YoetzAlertLevel::Calm
+        }
+    }
+}
+
+/// Sent when the [`YoetzAlertLevel`] of an entity changes.
+#[derive(Event, Debug, Clone)]
+pub struct YoetzAlertLevelChanged {
+    /// The entity whose alert level changed.
+    pub entity: Entity,
+    /// The previous alert level.
+    pub from: YoetzAlertLevel,
+    /// The new alert level.
+    pub to: YoetzAlertLevel,
+}
+
+/// How an entity's [`YoetzAlert`] level affects its behaviors.
+///
+/// The [`YoetzAlertPlugin`] translates this into [`YoetzScoreModifiers`] (under the
+/// [`MODIFIER_SOURCE`](Self::MODIFIER_SOURCE) source) right before the advisor thinks, adding the
+/// `YoetzScoreModifiers` component if the entity does not have it.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Patrol,
+/// #     Investigate,
+/// # }
+/// # let mut commands: Commands = panic!();
+/// commands.spawn((
+///     YoetzAdvisor::<AiBehavior>::new(2.0),
+///     YoetzAlert::new([1.0, 5.0, 10.0]).with_decay_rate(0.5),
+///     YoetzAlertEffects::<AiBehavior>::default()
+///         .with_max_level(
+///             YoetzKeyPattern::variant::<AiBehaviorPatrol>(),
+///             YoetzAlertLevel::Suspicious,
+///         )
+///         .with_min_level(
+///             YoetzKeyPattern::variant::<AiBehaviorInvestigate>(),
+///             YoetzAlertLevel::Suspicious,
+///         )
+///         .with_multiplier(
+///             YoetzKeyPattern::variant::<AiBehaviorInvestigate>(),
+///             YoetzAlertLevel::Alerted,
+///             2.0,
+///         ),
+/// ));
+/// ```
+#[derive(Component)]
+pub struct YoetzAlertEffects<S: YoetzSuggestion> {
+    effects: Vec<(YoetzKeyPattern<S>, YoetzAlertEffect)>,
+}
+
+enum YoetzAlertEffect {
+    Multiplier(YoetzAlertLevel, f32),
+    MinLevel(YoetzAlertLevel),
+    MaxLevel(YoetzAlertLevel),
+}
+
+impl<S: YoetzSuggestion> Default for YoetzAlertEffects<S> {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzAlertEffects<S> {
+    /// The source label of the modifiers this component generates.
+    pub const MODIFIER_SOURCE: &'static str = "alert";
+
+    /// Multiply the scores of the keys that match the pattern by a factor while at the specified
+    /// level.
+    pub fn with_multiplier(
+        mut self,
+        pattern: YoetzKeyPattern<S>,
+        level: YoetzAlertLevel,
+        factor: f32,
+    ) -> Self {
+        self.effects
+            .push((pattern, YoetzAlertEffect::Multiplier(level, factor)));
+        self
+    }
+
+    /// Disable the keys that match the pattern while below the specified level.
+    pub fn with_min_level(mut self, pattern: YoetzKeyPattern<S>, level: YoetzAlertLevel) -> Self {
+        self.effects
+            .push((pattern, YoetzAlertEffect::MinLevel(level)));
+        self
+    }
+
+    /// Disable the keys that match the pattern while above the specified level.
+    pub fn with_max_level(mut self, pattern: YoetzKeyPattern<S>, level: YoetzAlertLevel) -> Self {
+        self.effects
+            .push((pattern, YoetzAlertEffect::MaxLevel(level)));
+        self
+    }
+
+    fn apply_to(&self, alert: &YoetzAlert, modifiers: &mut YoetzScoreModifiers<S>) {
+        modifiers.remove_source(Self::MODIFIER_SOURCE);
+        for (pattern, effect) in self.effects.iter() {
+            let modifier = match effect {
+                YoetzAlertEffect::Multiplier(level, factor) if *level == alert.level => {
+                    YoetzScoreModifier::Multiply(*factor)
+                }
+                YoetzAlertEffect::MinLevel(level) if alert.level < *level => {
+                    YoetzScoreModifier::Disable
+                }
+                YoetzAlertEffect::MaxLevel(level) if *level < alert.level => {
+                    YoetzScoreModifier::Disable
+                }
+                _ => continue,
+            };
+            modifiers.insert(Self::MODIFIER_SOURCE, pattern.clone(), modifier);
+        }
+    }
+}
+
+/// Decays the [`YoetzAlert`]s, updates their levels (sending [`YoetzAlertLevelChanged`] events),
+/// and applies the [`YoetzAlertEffects`] of the advisors of `S`.
+///
+/// Advisors re-decide (see [`YoetzAdvisor::request_rethink`]) whenever the alert level of their
+/// entity changes.
+pub struct YoetzAlertPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzAlertPlugin<S> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzAlertPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<YoetzAlertLevelPlugin>() {
+            app.add_plugins(YoetzAlertLevelPlugin {
+                schedule: self.schedule,
+            });
+        }
+        app.add_systems(
+            self.schedule,
+            (rethink_on_alert_level_change::<S>, apply_alert_effects::<S>)
+                .in_set(YoetzInternalSystemSet::Think)
+                .before(update_advisor::<S, f32>),
+        );
+    }
+}
+
+/// The part of [`YoetzAlertPlugin`] that does not depend on the suggestion type, so that it only
+/// gets added once.
+struct YoetzAlertLevelPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl Plugin for YoetzAlertLevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<YoetzAlertLevelChanged>();
+        // Stimuli are usually given by perception systems, which run in the suggest phase, so the
+        // level is updated after it.
+        app.add_systems(
+            self.schedule,
+            update_alert_levels
+                .after(YoetzSystemSet::Suggest)
+                .before(YoetzInternalSystemSet::Think),
+        );
+    }
+}
+
+fn update_alert_levels(
+    mut query: Query<(Entity, &mut YoetzAlert)>,
+    time: Res<Time>,
+    mut events: EventWriter<YoetzAlertLevelChanged>,
+) {
+    let delta_secs = time.delta_secs();
+    for (entity, mut alert) in query.iter_mut() {
+        alert.meter = (alert.meter - alert.decay_rate * delta_secs).max(0.0);
+        let level = alert.level_for_meter();
+        if level != alert.level {
+            events.send(YoetzAlertLevelChanged {
+                entity,
+                from: alert.level,
+                to: level,
+            });
+            alert.level = level;
+        }
+    }
+}
+
+fn rethink_on_alert_level_change<S: YoetzSuggestion>(
+    mut events: EventReader<YoetzAlertLevelChanged>,
+    mut query: Query<&mut YoetzAdvisor<S>>,
+) {
+    for event in events.read() {
+        if let Ok(mut advisor) = query.get_mut(event.entity) {
+            advisor.request_rethink();
+        }
+    }
+}
+
+fn apply_alert_effects<S: YoetzSuggestion>(
+    mut query: Query<(
+        Entity,
+        &YoetzAlert,
+        &YoetzAlertEffects<S>,
+        Option<&mut YoetzScoreModifiers<S>>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, alert, effects, modifiers) in query.iter_mut() {
+        update_score_modifiers(entity, modifiers, &mut commands, |modifiers| {
+            effects.apply_to(alert, modifiers);
+        });
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::advisor::YoetzSuggestion;
+
+/// How a [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) chooses the winner among the scored
+/// suggestions.
+///
+/// The scores the policies see are the final scores - after the consistency bonus and all the
+/// penalties and modifiers. Suggestions below the score threshold are not considered at all.
+///
+/// The random policies pick again every time the advisor thinks. To prevent them from switching
+/// behaviors on every decision, combine them with a consistency bonus or with
+/// [`switch_after_wins`](crate::advisor::YoetzAdvisor::switch_after_wins).
+pub enum YoetzArbitration<S: YoetzSuggestion> {
+    /// Choose the suggestion with the highest score, breaking ties with the advisor's
+    /// [`tie_break`](crate::advisor::YoetzAdvisor::tie_break).
+    HighestScore,
+    /// Choose a suggestion randomly, with probabilities proportional to `exp(score /
+    /// temperature)`. The higher the temperature, the more likely lower scored suggestions are to
+    /// be chosen.
+    Softmax { temperature: f32 },
+    /// Choose one of the `k` highest scored suggestions uniformly at random.
+    TopKRandom(usize),
+    /// Choose with a custom function, which receives the scores and keys of the suggestions and
+    /// returns the index of the chosen one.
+    Custom(Arc<dyn Fn(&[(f32, &S::Key)]) -> usize + Send + Sync>),
+}
+
+impl<S: YoetzSuggestion> Default for YoetzArbitration<S> {
+    fn default() -> Self {
+        Self::HighestScore
+    }
+}
+
+impl<S: YoetzSuggestion> Clone for YoetzArbitration<S> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::HighestScore => Self::HighestScore,
+            Self::Softmax { temperature } => Self::Softmax {
+                temperature: *temperature,
+            },
+            Self::TopKRandom(k) => Self::TopKRandom(*k),
+            Self::Custom(choose) => Self::Custom(choose.clone()),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzArbitration<S> {
+    /// Create a [`Custom`](Self::Custom) policy.
+    ///
+    /// ```no_run
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+    /// // Prefer the suggestion that was made first, unless another one beats it by a wide margin.
+    /// let arbitration = YoetzArbitration::<AiBehavior>::custom(|suggestions| {
+    ///     let (first_score, _) = suggestions[0];
+    ///     suggestions
+    ///         .iter()
+    ///         .position(|(score, _)| first_score + 10.0 < *score)
+    ///         .unwrap_or(0)
+    /// });
+    /// ```
+    pub fn custom(choose: impl Fn(&[(f32, &S::Key)]) -> usize + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(choose))
+    }
+
+    /// Returns the index of the chosen suggestion, or `None` if the policy is
+    /// [`HighestScore`](Self::HighestScore) (which the advisor handles by itself).
+    pub(crate) fn choose(
+        &self,
+        suggestions: &[(f32, &S::Key)],
+        rng: &mut YoetzRng,
+    ) -> Option<usize> {
+        if suggestions.is_empty() {
+            return None;
+        }
+        let index = match self {
+            Self::HighestScore => return None,
+            Self::Softmax { temperature } => {
+                let max_score = suggestions
+                    .iter()
+                    .map(|(score, _)| *score)
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let temperature = temperature.max(f32::EPSILON);
+                let weights = suggestions
+                    .iter()
+                    .map(|(score, _)| ((score - max_score) / temperature).exp())
+                    .collect::<Vec<_>>();
+                let mut roll = rng.next_f32() * weights.iter().sum::<f32>();
+                weights
+                    .iter()
+                    .position(|weight| {
+                        roll -= weight;
+                        roll < 0.0
+                    })
+                    .unwrap_or(suggestions.len() - 1)
+            }
+            Self::TopKRandom(k) => {
+                let mut indices = (0..suggestions.len()).collect::<Vec<_>>();
+                indices.sort_by(|a, b| suggestions[*b].0.total_cmp(&suggestions[*a].0));
+                indices[rng.next_below((*k).clamp(1, suggestions.len()))]
+            }
+            Self::Custom(choose) => choose(suggestions),
+        };
+        Some(index.min(suggestions.len() - 1))
+    }
+}
+
+/// A small PRNG (SplitMix64), so that the random arbitration policies don't require a dependency.
+pub(crate) struct YoetzRng(u64);
+
+impl YoetzRng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `[0.0, 1.0)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// A number in `[0, bound)`.
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
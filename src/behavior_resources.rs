@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Amounts of resources (stamina, mana, ammo...) an entity can spend on its behaviors.
+///
+/// When an entity with a [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) has this component,
+/// suggestions whose costs (see [`YoetzSuggestion::costs`](crate::advisor::YoetzSuggestion::costs)
+/// and [`YoetzAdvisor::suggest_with_costs`](crate::advisor::YoetzAdvisor::suggest_with_costs))
+/// the entity cannot afford are ignored when the advisor thinks. Resources that were never set
+/// count as zero.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Idle,
+///     #[yoetz(cost(mana = 10))]
+///     CastFireball {
+///         #[yoetz(key)]
+///         target: Entity,
+///     },
+/// }
+///
+/// # let mut commands: Commands = panic!();
+/// commands.spawn((
+///     YoetzAdvisor::<AiBehavior>::new(2.0),
+///     YoetzBehaviorResources::default()
+///         .with("mana", 25.0)
+///         .spending_on_selection(),
+/// ));
+/// ```
+#[derive(Component, Default, Debug, Clone)]
+pub struct YoetzBehaviorResources {
+    /// Deduct the costs of a behavior from the resources when the advisor switches to it.
+    ///
+    /// When this is `false`, it is up to the action systems to spend the resources.
+    pub spend_on_selection: bool,
+    amounts: HashMap<&'static str, f32>,
+}
+
+impl YoetzBehaviorResources {
+    /// Set the initial amount of a resource.
+    pub fn with(mut self, resource: &'static str, amount: f32) -> Self {
+        self.set(resource, amount);
+        self
+    }
+
+    /// Turn on [`spend_on_selection`](Self::spend_on_selection).
+    pub fn spending_on_selection(mut self) -> Self {
+        self.spend_on_selection = true;
+        self
+    }
+
+    /// The current amount of a resource.
+    pub fn get(&self, resource: &str) -> f32 {
+        self.amounts.get(resource).copied().unwrap_or(0.0)
+    }
+
+    /// Set the amount of a resource.
+    pub fn set(&mut self, resource: &'static str, amount: f32) {
+        self.amounts.insert(resource, amount);
+    }
+
+    /// Add to (or, with a negative amount, subtract from) a resource.
+    pub fn add(&mut self, resource: &'static str, amount: f32) {
+        *self.amounts.entry(resource).or_insert(0.0) += amount;
+    }
+
+    /// Check if there is enough of every resource to pay the costs.
+    pub fn can_afford<'a>(&self, costs: impl IntoIterator<Item = &'a (&'static str, f32)>) -> bool {
+        costs
+            .into_iter()
+            .all(|(resource, amount)| *amount <= self.get(resource))
+    }
+
+    /// Deduct the costs from the resources.
+    pub fn spend<'a>(&mut self, costs: impl IntoIterator<Item = &'a (&'static str, f32)>) {
+        for (resource, amount) in costs {
+            self.add(resource, -amount);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::advisor::YoetzAdvisor;
+use crate::scorers;
+use crate::simple::YoetzSimpleSuggestion;
+
+/// The suggestion type of behaviors defined in [`YoetzBehaviorSet`]s. The key is the name of the
+/// behavior, and the data is its parameters.
+pub type YoetzDataSuggestion = YoetzSimpleSuggestion<String, HashMap<String, f32>>;
+
+/// Behaviors defined in a data file, so that designers can tweak the AI without recompiling.
+///
+/// The [`YoetzBehaviorSetPlugin`] loads these from `.yoetz.ron` files:
+///
+/// ```ron
+/// (
+///     behaviors: [
+///         (
+///             name: "flee",
+///             score: 10.0,
+///             considerations: [
+///                 (input: "health", curve: Linear(from: 1.0, to: 0.0)),
+///                 (input: "enemy_distance", curve: Logistic(midpoint: 5.0, steepness: -1.0)),
+///             ],
+///             params: {"speed": 4.0},
+///         ),
+///         (
+///             name: "wander",
+///             score: 1.0,
+///         ),
+///     ],
+/// )
+/// ```
+///
+/// Use [`suggest`](Self::suggest) in a suggestion system to suggest all the behaviors, and query
+/// [`YoetzSimpleBehavior<String, HashMap<String, f32>>`](crate::simple::YoetzSimpleBehavior) in
+/// the action systems. Since the behaviors are scored from the asset every time, changes to the
+/// file take effect as soon as it is reloaded.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(Component)]
+/// struct Soldier {
+///     behaviors: Handle<YoetzBehaviorSet>,
+///     health: f32,
+///     enemy_distance: f32,
+/// }
+///
+/// fn suggest_from_data(
+///     mut query: Query<(&mut YoetzAdvisor<YoetzDataSuggestion>, &Soldier)>,
+///     behavior_sets: Res<Assets<YoetzBehaviorSet>>,
+/// ) {
+///     for (mut advisor, soldier) in query.iter_mut() {
+///         let Some(behavior_set) = behavior_sets.get(&soldier.behaviors) else {
+///             continue;
+///         };
+///         behavior_set.suggest(&mut advisor, |input| match input {
+///             "health" => Some(soldier.health),
+///             "enemy_distance" => Some(soldier.enemy_distance),
+///             _ => None,
+///         });
+///     }
+/// }
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct YoetzBehaviorSet {
+    /// The behaviors, in the order they are suggested.
+    pub behaviors: Vec<YoetzBehaviorDefinition>,
+}
+
+/// A behavior in a [`YoetzBehaviorSet`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct YoetzBehaviorDefinition {
+    /// The name of the behavior, used as its [`Key`](crate::advisor::YoetzSuggestion::Key).
+    pub name: String,
+    /// The score of the behavior before applying the considerations.
+    pub score: f32,
+    /// Curves that are multiplied into the score.
+    #[serde(default)]
+    pub considerations: Vec<YoetzConsiderationDefinition>,
+    /// Parameters passed to the action systems.
+    #[serde(default)]
+    pub params: HashMap<String, f32>,
+}
+
+/// A named input and the curve that maps it to a factor of the score.
+#[derive(Debug, Clone, Deserialize)]
+pub struct YoetzConsiderationDefinition {
+    /// The name of the input, passed to the inputs function of [`YoetzBehaviorSet::suggest`].
+    pub input: String,
+    /// Maps the input to a factor of the score.
+    pub curve: YoetzCurve,
+}
+
+/// A curve that maps an input to a factor of the score (usually in `0.0..=1.0`), using the
+/// functions in the [`scorers`] module.
+#[derive(Debug, Clone, Deserialize)]
+pub enum YoetzCurve {
+    /// `0.0` at `from`, `1.0` at `to`, linear in between and clamped outside (see
+    /// [`scorers::remap`]).
+    Linear { from: f32, to: f32 },
+    /// See [`scorers::logistic`].
+    Logistic { midpoint: f32, steepness: f32 },
+    /// See [`scorers::inverse_square_falloff`].
+    InverseSquare { half_distance: f32 },
+    /// See [`scorers::bucketed`].
+    Bucketed {
+        buckets: Vec<(f32, f32)>,
+        below_all: f32,
+    },
+}
+
+impl YoetzCurve {
+    /// Map the input to a factor of the score.
+    pub fn evaluate(&self, input: f32) -> f32 {
+        match self {
+            Self::Linear { from, to } => scorers::remap(input, *from, *to, 0.0, 1.0),
+            Self::Logistic {
+                midpoint,
+                steepness,
+            } => scorers::logistic(input, *midpoint, *steepness),
+            Self::InverseSquare { half_distance } => {
+                scorers::inverse_square_falloff(input, *half_distance)
+            }
+            Self::Bucketed { buckets, below_all } => scorers::bucketed(input, buckets, *below_all),
+        }
+    }
+}
+
+impl YoetzBehaviorDefinition {
+    /// The score of the behavior with the given inputs, or `None` if one of the inputs its
+    /// considerations need is missing.
+    pub fn evaluate(&self, inputs: impl Fn(&str) -> Option<f32>) -> Option<f32> {
+        self.considerations
+            .iter()
+            .try_fold(self.score, |score, consideration| {
+                Some(score * consideration.curve.evaluate(inputs(&consideration.input)?))
+            })
+    }
+}
+
+impl YoetzBehaviorSet {
+    /// Suggest all the behaviors of the set, scored with the given inputs.
+    ///
+    /// Behaviors whose considerations need inputs that the inputs function does not provide are
+    /// not suggested.
+    pub fn suggest(
+        &self,
+        advisor: &mut YoetzAdvisor<YoetzDataSuggestion>,
+        inputs: impl Fn(&str) -> Option<f32>,
+    ) {
+        for behavior in self.behaviors.iter() {
+            if let Some(score) = behavior.evaluate(&inputs) {
+                advisor.suggest(
+                    score,
+                    YoetzSimpleSuggestion::new(behavior.name.clone(), behavior.params.clone()),
+                );
+            }
+        }
+    }
+}
+
+/// Registers the [`YoetzBehaviorSet`] asset and its loader.
+///
+/// This does not add the [`YoetzPlugin`](crate::YoetzPlugin) - add
+/// `YoetzPlugin::<YoetzDataSuggestion>` separately.
+pub struct YoetzBehaviorSetPlugin;
+
+impl Plugin for YoetzBehaviorSetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<YoetzBehaviorSet>();
+        app.register_asset_loader(YoetzBehaviorSetLoader);
+    }
+}
+
+struct YoetzBehaviorSetLoader;
+
+impl AssetLoader for YoetzBehaviorSetLoader {
+    type Asset = YoetzBehaviorSet;
+    type Settings = ();
+    type Error = YoetzBehaviorSetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["yoetz.ron"]
+    }
+}
+
+/// An error loading a [`YoetzBehaviorSet`].
+#[derive(Debug)]
+pub enum YoetzBehaviorSetLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl From<std::io::Error> for YoetzBehaviorSetLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for YoetzBehaviorSetLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+impl fmt::Display for YoetzBehaviorSetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read behavior set: {error}"),
+            Self::Ron(error) => write!(f, "could not parse behavior set: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for YoetzBehaviorSetLoaderError {}
@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::YoetzStrategy;
+use crate::YoetzSystemSet;
+
+/// Lets a behavior chosen by Yoetz be enacted by a behavior tree (e.g. one from `bevy_behave`).
+///
+/// Whenever the strategy component `T` is added to an entity, the plugin calls its `spawn_tree`
+/// function to spawn the tree, makes the tree a child of the entity, and marks the entity with a
+/// [`YoetzBehaviorTree<T>`] and the tree with a [`YoetzBehaviorTreeOf`]. When `T` is removed -
+/// because the advisor switched to another behavior - the tree is despawned (recursively). Choosing
+/// the same variant with a different key does not replace the tree, and neither does the
+/// strategy's input fields changing, so the tree should read the strategy component of its agent
+/// if it needs them.
+///
+/// In the other direction, the tree's tasks can find the agent through the [`YoetzBehaviorTreeOf`]
+/// of the tree (or through their tree crate's own notion of the target entity, since the tree is a
+/// child of the agent) and use [`YoetzEntityCommandsExt`](crate::commands::YoetzEntityCommandsExt)
+/// to make suggestions, or to report that the behavior completed or failed.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Idle,
+///     Investigate {
+///         #[yoetz(key)]
+///         position: IVec2,
+///     },
+/// }
+///
+/// # #[derive(Component)] struct MyTree;
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzBehaviorTreePlugin::<AiBehaviorInvestigate>::new(
+///     Update,
+///     |_investigate, _agent, commands| {
+///         // Spawn the tree of your behavior tree crate here.
+///         commands.spawn(MyTree).id()
+///     },
+/// ));
+///
+/// fn tree_finished(
+///     trees: Query<&YoetzBehaviorTreeOf, With<MyTree>>,
+///     mut commands: Commands,
+/// ) {
+///     for tree_of in trees.iter() {
+///         # let tree_is_done = true;
+///         if tree_is_done {
+///             commands.entity(tree_of.agent).yoetz_complete::<AiBehavior, f32>();
+///         }
+///     }
+/// }
+/// ```
+///
+/// Trees that are not children of their agent are not despawned when their agent is despawned, so
+/// agents with trees should be despawned recursively.
+pub struct YoetzBehaviorTreePlugin<T: YoetzStrategy> {
+    schedule: InternedScheduleLabel,
+    spawn_tree: fn(&T, Entity, &mut Commands) -> Entity,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T: YoetzStrategy> YoetzBehaviorTreePlugin<T> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with), and spawns trees with `spawn_tree`.
+    ///
+    /// `spawn_tree` receives the strategy component and the agent entity, and returns the root
+    /// entity of the tree it spawned.
+    pub fn new(
+        schedule: impl ScheduleLabel,
+        spawn_tree: fn(&T, Entity, &mut Commands) -> Entity,
+    ) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            spawn_tree,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: YoetzStrategy> Plugin for YoetzBehaviorTreePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            manage_behavior_trees::<T>(self.spawn_tree).in_set(YoetzSystemSet::Act),
+        );
+    }
+}
+
+/// The behavior tree spawned by the [`YoetzBehaviorTreePlugin<T>`] to enact the strategy `T`.
+#[derive(Component)]
+pub struct YoetzBehaviorTree<T: YoetzStrategy> {
+    tree: Entity,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T: YoetzStrategy> YoetzBehaviorTree<T> {
+    /// The root entity of the tree.
+    pub fn tree(&self) -> Entity {
+        self.tree
+    }
+}
+
+/// Marks the root of a behavior tree spawned by a [`YoetzBehaviorTreePlugin`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct YoetzBehaviorTreeOf {
+    /// The entity whose strategy the tree enacts.
+    pub agent: Entity,
+}
+
+#[allow(clippy::type_complexity)]
+fn manage_behavior_trees<T: YoetzStrategy>(
+    spawn_tree: fn(&T, Entity, &mut Commands) -> Entity,
+) -> impl FnMut(
+    Query<(Entity, &T), Added<T>>,
+    Query<&YoetzBehaviorTree<T>>,
+    RemovedComponents<T>,
+    Commands,
+) {
+    move |added_query, trees_query, mut removed, mut commands| {
+        let despawn_tree = |agent: Entity, commands: &mut Commands| {
+            let Ok(behavior_tree) = trees_query.get(agent) else {
+                return;
+            };
+            if let Some(tree_commands) = commands.get_entity(behavior_tree.tree) {
+                tree_commands.despawn_recursive();
+            }
+            commands.entity(agent).remove::<YoetzBehaviorTree<T>>();
+        };
+        let removed = removed.read().collect::<Vec<_>>();
+        for agent in removed.iter() {
+            despawn_tree(*agent, &mut commands);
+        }
+        for (agent, strategy) in added_query.iter() {
+            if !removed.contains(&agent) {
+                // In case the strategy was re-inserted without being removed first
+                despawn_tree(agent, &mut commands);
+            }
+            let tree = spawn_tree(strategy, agent, &mut commands);
+            commands.entity(tree).insert(YoetzBehaviorTreeOf { agent });
+            commands
+                .entity(agent)
+                .add_child(tree)
+                .insert(YoetzBehaviorTree::<T> {
+                    tree,
+                    _phantom: PhantomData,
+                });
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::any::{Any, TypeId};
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::YoetzSystemSet;
+
+/// A per-entity store of typed values, for passing data that suggestion systems derive (e.g. the
+/// nearest enemy, or a path) to the action systems without adding fields to the variants.
+///
+/// Each type is its own key - use newtypes to store multiple values of the same type.
+///
+/// The [`YoetzBlackboardPlugin`] starts a new round every time its schedule runs, before
+/// [`YoetzSystemSet::Suggest`]. The values of the previous round are moved to a snapshot (see
+/// [`previous`](Self::previous)), so that the values the action systems see are always the ones
+/// written by the suggestion systems in the same round.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Attack {
+/// #         #[yoetz(key)]
+/// #         target: Entity,
+/// #     },
+/// # }
+/// struct NearestEnemyDistance(f32);
+///
+/// fn suggest_attack(mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &mut YoetzBlackboard)>) {
+///     for (mut advisor, mut blackboard) in query.iter_mut() {
+///         # let (target, distance) = (Entity::PLACEHOLDER, 0.0);
+///         blackboard.set(NearestEnemyDistance(distance));
+///         advisor.suggest(10.0 - distance, AiBehavior::Attack { target });
+///     }
+/// }
+///
+/// fn perform_attack(query: Query<(&AiBehaviorAttack, &YoetzBlackboard)>) {
+///     for (attack, blackboard) in query.iter() {
+///         if let Some(NearestEnemyDistance(distance)) = blackboard.get() {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Default)]
+pub struct YoetzBlackboard {
+    current: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    previous: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl YoetzBlackboard {
+    /// Store a value, replacing the value of the same type if there was one.
+    pub fn set<T: 'static + Send + Sync>(&mut self, value: T) {
+        self.current.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Get the value of the type `T` from the current round.
+    pub fn get<T: 'static + Send + Sync>(&self) -> Option<&T> {
+        self.current.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Get the value of the type `T` from the current round for modifying it.
+    pub fn get_mut<T: 'static + Send + Sync>(&mut self) -> Option<&mut T> {
+        self.current.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+
+    /// Get the value of the type `T` from the current round, or insert one if there is none.
+    pub fn get_or_insert_with<T: 'static + Send + Sync>(
+        &mut self,
+        insert: impl FnOnce() -> T,
+    ) -> &mut T {
+        self.current
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(insert()))
+            .downcast_mut()
+            .expect("values are stored under their own type")
+    }
+
+    /// Remove the value of the type `T` from the current round.
+    pub fn remove<T: 'static + Send + Sync>(&mut self) -> Option<T> {
+        let value = self.current.remove(&TypeId::of::<T>())?;
+        Some(*value.downcast().ok()?)
+    }
+
+    /// Check if the current round has a value of the type `T`.
+    pub fn contains<T: 'static + Send + Sync>(&self) -> bool {
+        self.current.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Get the value of the type `T` from the previous round.
+    pub fn previous<T: 'static + Send + Sync>(&self) -> Option<&T> {
+        self.previous.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Remove all the values of the current round.
+    pub fn clear(&mut self) {
+        self.current.clear();
+    }
+
+    /// Move the values of the current round to the snapshot of the previous round.
+    pub fn start_round(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+    }
+}
+
+/// Starts a new round for all the [`YoetzBlackboard`]s in the given schedule (which should be the
+/// same schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with), before
+/// [`YoetzSystemSet::Suggest`].
+///
+/// This plugin does not add the blackboards - add them to the entities that need them.
+pub struct YoetzBlackboardPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl YoetzBlackboardPlugin {
+    /// Create a plugin that starts the blackboard rounds in the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Plugin for YoetzBlackboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            start_blackboard_rounds.before(YoetzSystemSet::Suggest),
+        );
+    }
+}
+
+fn start_blackboard_rounds(mut query: Query<&mut YoetzBlackboard>) {
+    for mut blackboard in query.iter_mut() {
+        blackboard.start_round();
+    }
+}
@@ -0,0 +1,122 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::modifiers::YoetzScoreModifiers;
+use crate::score::YoetzScore;
+use crate::score_history::YoetzScoreHistory;
+use crate::think_rate::YoetzThinkRate;
+
+/// Everything needed for making an entity AI controlled by a [`YoetzAdvisor`].
+///
+/// Besides the advisor, the bundle can carry the components that configure it - the
+/// [`YoetzScoreModifiers`] (e.g. for giving the entity a personality), the [`YoetzThinkRate`]
+/// (e.g. for lowering the level of detail of background NPCs) and the [`YoetzScoreHistory`].
+///
+/// This is not a Bevy [`Bundle`] because these components are optional. Use
+/// [`YoetzCommandsExt::spawn_yoetz`] or [`YoetzBundle::insert_into`] to add it to an entity.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # let mut commands: Commands = panic!();
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// # #[derive(Component)] struct OtherComponentsForThisEntity;
+/// commands.spawn_yoetz(
+///     YoetzBundle::<AiBehavior>::new(2.0)
+///         .with_think_rate(YoetzThinkRate::new(Duration::from_millis(500)))
+///         .with_score_history(YoetzScoreHistory::new(30)),
+///     OtherComponentsForThisEntity,
+/// );
+/// ```
+pub struct YoetzBundle<S: YoetzSuggestion, N: YoetzScore = f32> {
+    advisor: YoetzAdvisor<S, N>,
+    score_modifiers: Option<YoetzScoreModifiers<S>>,
+    think_rate: Option<YoetzThinkRate<S, N>>,
+    score_history: Option<YoetzScoreHistory<S>>,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzBundle<S, N> {
+    /// Create a bundle for an advisor with the specified
+    /// [`consistency_bonus`](YoetzAdvisor::consistency_bonus).
+    pub fn new(consistency_bonus: f32) -> Self {
+        Self::from_advisor(YoetzAdvisor::new(consistency_bonus))
+    }
+
+    /// Create a bundle around an already configured advisor.
+    pub fn from_advisor(advisor: YoetzAdvisor<S, N>) -> Self {
+        Self {
+            advisor,
+            score_modifiers: None,
+            think_rate: None,
+            score_history: None,
+        }
+    }
+
+    /// Modify the advisor before it gets inserted.
+    pub fn with_advisor(mut self, dlg: impl FnOnce(&mut YoetzAdvisor<S, N>)) -> Self {
+        dlg(&mut self.advisor);
+        self
+    }
+
+    /// Insert [`YoetzScoreModifiers`] next to the advisor.
+    pub fn with_score_modifiers(mut self, score_modifiers: YoetzScoreModifiers<S>) -> Self {
+        self.score_modifiers = Some(score_modifiers);
+        self
+    }
+
+    /// Insert a [`YoetzThinkRate`] next to the advisor.
+    pub fn with_think_rate(mut self, think_rate: YoetzThinkRate<S, N>) -> Self {
+        self.think_rate = Some(think_rate);
+        self
+    }
+
+    /// Insert a [`YoetzScoreHistory`] next to the advisor.
+    pub fn with_score_history(mut self, score_history: YoetzScoreHistory<S>) -> Self {
+        self.score_history = Some(score_history);
+        self
+    }
+
+    /// Insert the advisor and all the configured components to an entity.
+    pub fn insert_into(self, cmd: &mut EntityCommands) {
+        let Self {
+            advisor,
+            score_modifiers,
+            think_rate,
+            score_history,
+        } = self;
+        cmd.insert(advisor);
+        if let Some(score_modifiers) = score_modifiers {
+            cmd.insert(score_modifiers);
+        }
+        if let Some(think_rate) = think_rate {
+            cmd.insert(think_rate);
+        }
+        if let Some(score_history) = score_history {
+            cmd.insert(score_history);
+        }
+    }
+}
+
+/// Extension methods for spawning AI controlled entities from [`Commands`].
+pub trait YoetzCommandsExt {
+    /// Spawn an entity with the [`YoetzBundle`] and the other given components.
+    fn spawn_yoetz<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        yoetz_bundle: YoetzBundle<S, N>,
+        bundle: impl Bundle,
+    ) -> EntityCommands;
+}
+
+impl YoetzCommandsExt for Commands<'_, '_> {
+    fn spawn_yoetz<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        yoetz_bundle: YoetzBundle<S, N>,
+        bundle: impl Bundle,
+    ) -> EntityCommands {
+        let mut cmd = self.spawn(bundle);
+        yoetz_bundle.insert_into(&mut cmd);
+        cmd
+    }
+}
@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::YoetzSystemSet;
+
+/// A per-entity cache for an expensive score computation.
+///
+/// Use this in suggestion systems for scores that do not need to be recomputed every frame (e.g.
+/// visibility checks or path costs). The marker type `M` allows multiple caches on the same
+/// entity, and the inputs type `I` can be used to invalidate the cache when the things the score
+/// depends on change.
+///
+/// The [`YoetzScoreCachePlugin`] ticks the caches and adds them to entities with
+/// [`YoetzAdvisor`]s that don't have them yet.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Hide,
+/// # }
+/// # fn expensive_visibility_check(_position: Vec3) -> f32 { 0.0 }
+/// struct VisibilityScore;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzScoreCachePlugin::<AiBehavior, VisibilityScore>::new(FixedUpdate, 10));
+///
+/// fn suggest_hide(
+///     mut query: Query<(
+///         &mut YoetzAdvisor<AiBehavior>,
+///         &mut YoetzCachedScore<VisibilityScore>,
+///         &GlobalTransform,
+///     )>,
+/// ) {
+///     for (mut advisor, mut cached_score, transform) in query.iter_mut() {
+///         let score = cached_score.get_or_compute(|| {
+///             expensive_visibility_check(transform.translation())
+///         });
+///         advisor.suggest(score, AiBehavior::Hide);
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzCachedScore<M: 'static, I: 'static + Send + Sync + PartialEq = ()> {
+    /// The cached value gets recomputed once every this number of ticks.
+    pub refresh_every: u32,
+    ticks_left: u32,
+    cached: Option<(I, f32)>,
+    _phantom: PhantomData<fn(M)>,
+}
+
+impl<M: 'static, I: 'static + Send + Sync + PartialEq> YoetzCachedScore<M, I> {
+    /// Create a cache that recomputes the score every `refresh_every` ticks.
+    pub fn new(refresh_every: u32) -> Self {
+        Self {
+            refresh_every,
+            ticks_left: 0,
+            cached: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the cached score, or compute it if it was not computed yet, or if its inputs have
+    /// changed, or if it is time to refresh it.
+    pub fn get_or_compute_with_inputs(
+        &mut self,
+        inputs: I,
+        compute: impl FnOnce(&I) -> f32,
+    ) -> f32 {
+        if 0 < self.ticks_left {
+            if let Some((cached_inputs, score)) = self.cached.as_ref() {
+                if *cached_inputs == inputs {
+                    return *score;
+                }
+            }
+        }
+        let score = compute(&inputs);
+        self.cached = Some((inputs, score));
+        self.ticks_left = self.refresh_every;
+        score
+    }
+
+    /// Get the cached score without computing it.
+    pub fn get(&self) -> Option<f32> {
+        self.cached.as_ref().map(|(_, score)| *score)
+    }
+
+    /// Force the score to be recomputed the next time it is requested.
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+
+    fn tick(&mut self) {
+        self.ticks_left = self.ticks_left.saturating_sub(1);
+    }
+}
+
+impl<M: 'static> YoetzCachedScore<M> {
+    /// Get the cached score, or compute it if it was not computed yet or if it is time to refresh
+    /// it.
+    pub fn get_or_compute(&mut self, compute: impl FnOnce() -> f32) -> f32 {
+        self.get_or_compute_with_inputs((), |()| compute())
+    }
+}
+
+/// Manages the [`YoetzCachedScore`]s with the marker `M` for entities that have a
+/// [`YoetzAdvisor<S>`](YoetzAdvisor).
+pub struct YoetzScoreCachePlugin<S, M: 'static, I: 'static + Send + Sync + PartialEq = ()> {
+    schedule: InternedScheduleLabel,
+    refresh_every: u32,
+    _phantom: PhantomData<fn(S, M, I)>,
+}
+
+impl<S, M: 'static, I: 'static + Send + Sync + PartialEq> YoetzScoreCachePlugin<S, M, I> {
+    /// Create a plugin that ticks the caches in the given schedule (which should be the same
+    /// schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with) and adds caches that
+    /// refresh every `refresh_every` ticks to advisors that don't have them.
+    pub fn new(schedule: impl ScheduleLabel, refresh_every: u32) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            refresh_every,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, M, I> Plugin for YoetzScoreCachePlugin<S, M, I>
+where
+    S: YoetzSuggestion,
+    M: 'static,
+    I: 'static + Send + Sync + PartialEq,
+{
+    fn build(&self, app: &mut App) {
+        app.insert_resource(YoetzScoreCacheRefreshRate::<M, I> {
+            refresh_every: self.refresh_every,
+            _phantom: PhantomData,
+        });
+        app.add_systems(
+            self.schedule,
+            (
+                add_missing_cached_scores::<S, M, I>,
+                tick_cached_scores::<M, I>,
+            )
+                .chain()
+                .before(YoetzSystemSet::Suggest),
+        );
+    }
+}
+
+#[derive(Resource)]
+struct YoetzScoreCacheRefreshRate<M: 'static, I: 'static> {
+    refresh_every: u32,
+    _phantom: PhantomData<fn(M, I)>,
+}
+
+fn add_missing_cached_scores<S, M, I>(
+    query: Query<Entity, (With<YoetzAdvisor<S>>, Without<YoetzCachedScore<M, I>>)>,
+    refresh_rate: Res<YoetzScoreCacheRefreshRate<M, I>>,
+    mut commands: Commands,
+) where
+    S: YoetzSuggestion,
+    M: 'static,
+    I: 'static + Send + Sync + PartialEq,
+{
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(YoetzCachedScore::<M, I>::new(refresh_rate.refresh_every));
+    }
+}
+
+fn tick_cached_scores<M: 'static, I: 'static + Send + Sync + PartialEq>(
+    mut query: Query<&mut YoetzCachedScore<M, I>>,
+) {
+    for mut cached_score in query.iter_mut() {
+        cached_score.tick();
+    }
+}
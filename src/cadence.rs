@@ -0,0 +1,61 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+/// Makes the advisors of `S` think less frequently than the schedule of the
+/// [`YoetzPlugin`](crate::YoetzPlugin) runs.
+///
+/// Between the decisions, the suggestions accumulate in the advisors - combined according to their
+/// [`aggregation`](crate::advisor::YoetzAdvisor::aggregation) - so expensive suggestion systems
+/// can be staggered over multiple runs of the schedule (e.g. each one handling a different subset
+/// of the entities) and the advisors still see all their suggestions when they think. Use
+/// [`YoetzAggregation::Max`](crate::config::YoetzAggregation::Max) or
+/// [`YoetzAggregation::Average`](crate::config::YoetzAggregation::Average) if the same
+/// suggestions are made on every run, because [`Sum`](crate::config::YoetzAggregation::Sum)
+/// would count them once per run.
+///
+/// An advisor that [requested a rethink](crate::advisor::YoetzAdvisor::request_rethink) does not
+/// wait for the timer - all the advisors of `S` think on the next run of the schedule.
+///
+/// Usually added with [`YoetzPlugin::think_every`](crate::YoetzPlugin::think_every), but it can
+/// also be inserted, modified or removed at runtime. Without this resource, the advisors think on
+/// every run of the schedule.
+#[derive(Resource)]
+pub struct YoetzThinkCadence<S: YoetzSuggestion> {
+    /// The advisors think every time this timer finishes.
+    pub timer: Timer,
+    ready: bool,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzThinkCadence<S> {
+    /// Make the advisors think once per period.
+    pub fn new(period: Duration) -> Self {
+        Self {
+            timer: Timer::new(period, TimerMode::Repeating),
+            ready: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn tick_think_cadence<S: YoetzSuggestion>(
+    cadence: Option<ResMut<YoetzThinkCadence<S>>>,
+    time: Res<Time>,
+) {
+    if let Some(mut cadence) = cadence {
+        cadence.ready = cadence.timer.tick(time.delta()).just_finished();
+    }
+}
+
+pub(crate) fn think_cadence_reached<S: YoetzSuggestion, N: YoetzScore>(
+    cadence: Option<Res<YoetzThinkCadence<S>>>,
+    advisors: Query<&YoetzAdvisor<S, N>>,
+) -> bool {
+    cadence.is_none_or(|cadence| cadence.ready)
+        || advisors.iter().any(YoetzAdvisor::is_rethink_requested)
+}
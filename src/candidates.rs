@@ -0,0 +1,81 @@
+//! Helpers for limiting the number of candidates a suggestion system scores.
+//!
+//! Scoring every possible target is often the most expensive part of the Suggest phase. When only
+//! the closest (or otherwise cheapest) few targets have a realistic chance of winning, use these
+//! helpers to pick them and only score them.
+
+use bevy::prelude::*;
+
+/// Pick the `k` candidates with the lowest cost, sorted from the cheapest.
+///
+/// ```
+/// # use bevy_yoetz::candidates::cheapest_k;
+/// let cheapest = cheapest_k(["aaa", "b", "cc", "dddd"], 2, |name| name.len() as f32);
+/// assert_eq!(cheapest, [(1.0, "b"), (2.0, "cc")]);
+/// ```
+pub fn cheapest_k<T>(
+    candidates: impl IntoIterator<Item = T>,
+    k: usize,
+    mut cost: impl FnMut(&T) -> f32,
+) -> Vec<(f32, T)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut result = candidates
+        .into_iter()
+        .map(|candidate| (cost(&candidate), candidate))
+        .collect::<Vec<_>>();
+    if k < result.len() {
+        result.select_nth_unstable_by(k - 1, |(a, _), (b, _)| a.total_cmp(b));
+        result.truncate(k);
+    }
+    result.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    result
+}
+
+/// Pick the `k` candidates nearest to `position`, sorted from the nearest, together with their
+/// distances.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # use bevy_yoetz::candidates::nearest_k;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Attack {
+/// #         #[yoetz(key)]
+/// #         target: Entity,
+/// #     },
+/// # }
+/// # #[derive(Component)] struct Attackable;
+/// # fn expensive_attack_score(_target: Entity, _distance: f32) -> f32 { 0.0 }
+/// fn suggest_attack(
+///     mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &GlobalTransform)>,
+///     targets_query: Query<(Entity, &GlobalTransform), With<Attackable>>,
+/// ) {
+///     for (mut advisor, transform) in query.iter_mut() {
+///         let targets = targets_query
+///             .iter()
+///             .map(|(entity, target_transform)| (entity, target_transform.translation()));
+///         // Only the three closest targets are worth the expensive scoring.
+///         for (distance, target) in nearest_k(transform.translation(), targets, 3) {
+///             advisor.suggest(
+///                 expensive_attack_score(target, distance),
+///                 AiBehavior::Attack { target },
+///             );
+///         }
+///     }
+/// }
+/// ```
+pub fn nearest_k<T>(
+    position: Vec3,
+    candidates: impl IntoIterator<Item = (T, Vec3)>,
+    k: usize,
+) -> Vec<(f32, T)> {
+    cheapest_k(candidates, k, |(_, candidate_position)| {
+        position.distance_squared(*candidate_position)
+    })
+    .into_iter()
+    .map(|(distance_squared, (candidate, _))| (distance_squared.sqrt(), candidate))
+    .collect()
+}
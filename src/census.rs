@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzStrategy, YoetzSuggestion};
+
+/// Counts of the active behaviors of all the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor)s of a
+/// [`YoetzSuggestion`].
+///
+/// [`YoetzPlugin`](crate::YoetzPlugin) adds this resource and updates it every time the advisors
+/// think, so systems in [`YoetzSystemSet::Suggest`](crate::YoetzSystemSet::Suggest) can use it to
+/// implement global constraints:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Flee,
+/// # }
+/// fn suggest_flee(
+///     mut query: Query<&mut YoetzAdvisor<AiBehavior>>,
+///     census: Res<YoetzBehaviorCensus<AiBehavior>>,
+/// ) {
+///     // Don't let more than 5 enemies flee at once.
+///     if 5 <= census.variant_count_by_name("Flee") {
+///         return;
+///     }
+///     for mut advisor in query.iter_mut() {
+///         advisor.suggest(10.0, AiBehavior::Flee);
+///     }
+/// }
+/// ```
+#[derive(Resource)]
+pub struct YoetzBehaviorCensus<S: YoetzSuggestion> {
+    /// Count the active behaviors per [`Key`](YoetzSuggestion::Key), and not only per variant.
+    ///
+    /// This is off by default, because keys can only be compared for equality and counting them
+    /// is quadratic in the number of distinct active keys.
+    pub track_keys: bool,
+    variant_counts: Vec<usize>,
+    key_counts: Vec<(S::Key, usize)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzBehaviorCensus<S> {
+    fn default() -> Self {
+        Self {
+            track_keys: false,
+            variant_counts: vec![0; S::VARIANT_NAMES.len()],
+            key_counts: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzBehaviorCensus<S> {
+    pub(crate) fn reset(&mut self) {
+        self.variant_counts.clear();
+        self.variant_counts.resize(S::VARIANT_NAMES.len(), 0);
+        self.key_counts.clear();
+    }
+
+    pub(crate) fn count(&mut self, key: &S::Key) {
+        self.variant_counts[S::variant_index(key)] += 1;
+        if !self.track_keys {
+            return;
+        }
+        if let Some((_, count)) = self
+            .key_counts
+            .iter_mut()
+            .find(|(counted_key, _)| counted_key == key)
+        {
+            *count += 1;
+        } else {
+            self.key_counts.push((key.clone(), 1));
+        }
+    }
+
+    /// The number of advisors whose active behavior is the variant with the given index (see
+    /// [`YoetzSuggestion::VARIANT_NAMES`]).
+    pub fn variant_count(&self, variant_index: usize) -> usize {
+        self.variant_counts.get(variant_index).copied().unwrap_or(0)
+    }
+
+    /// The number of advisors whose active behavior uses the given strategy.
+    pub fn count<T: YoetzStrategy<Suggestion = S>>(&self) -> usize {
+        self.variant_count(T::VARIANT_INDEX)
+    }
+
+    /// The number of advisors whose active behavior is the variant with the given name.
+    pub fn variant_count_by_name(&self, variant_name: &str) -> usize {
+        S::VARIANT_NAMES
+            .iter()
+            .position(|name| *name == variant_name)
+            .map(|variant_index| self.variant_count(variant_index))
+            .unwrap_or(0)
+    }
+
+    /// The number of advisors whose active behavior has the given key.
+    ///
+    /// Always returns 0 unless [`track_keys`](Self::track_keys) is set.
+    pub fn key_count(&self, key: &S::Key) -> usize {
+        self.key_counts
+            .iter()
+            .find(|(counted_key, _)| counted_key == key)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    }
+
+    /// Iterate over the variant names together with the number of advisors currently using them.
+    pub fn iter_variants(&self) -> impl '_ + Iterator<Item = (&'static str, usize)> {
+        S::VARIANT_NAMES
+            .iter()
+            .copied()
+            .zip(self.variant_counts.iter().copied())
+    }
+
+    /// The total number of advisors with an active behavior.
+    pub fn total(&self) -> usize {
+        self.variant_counts.iter().sum()
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+/// Extension methods for controlling an entity's [`YoetzAdvisor`] from [`EntityCommands`].
+///
+/// These are useful in places that cannot access the advisor directly - most notably observers.
+///
+/// Every method targets the `YoetzAdvisor<S, N>` whose score type `N` is given explicitly (or
+/// inferred from the `score` argument). Note that an unsuffixed float literal is inferred as
+/// `f64`, so write `10.0_f32` (or use a turbofish) when the advisor uses the default `f32` scores.
+pub trait YoetzEntityCommandsExt {
+    /// Suggest a behavior for the entity's [`YoetzAdvisor<S, N>`] to consider.
+    ///
+    /// The suggestion is delivered when the commands are applied, and stays with the advisor
+    /// until the next time it thinks - so it does not matter when the command was issued:
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)]
+    /// # enum AiBehavior {
+    /// #     Flee,
+    /// # }
+    /// # #[derive(Component)] struct DamagedBy(Entity);
+    /// # let mut app = App::new();
+    /// app.add_observer(|trigger: Trigger<OnAdd, DamagedBy>, mut commands: Commands| {
+    ///     commands
+    ///         .entity(trigger.entity())
+    ///         .yoetz_suggest(10.0_f32, AiBehavior::Flee);
+    /// });
+    /// ```
+    ///
+    /// If the entity does not have a `YoetzAdvisor<S, N>` when the command is applied, the
+    /// suggestion is discarded.
+    fn yoetz_suggest<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        score: N,
+        suggestion: S,
+    ) -> &mut Self;
+
+    /// Suggest a behavior for the entity's [`YoetzAdvisor<S, N>`] that stays valid for `ttl` (see
+    /// [`YoetzAdvisor::suggest_for`]).
+    ///
+    /// Unlike [`yoetz_suggest`](Self::yoetz_suggest), the suggestion survives the next time the
+    /// advisor thinks - which makes it suitable for reacting to one-off events. The arguments are
+    /// in the same `(score, ttl, suggestion)` order as in `suggest_for`.
+    fn yoetz_suggest_for<S: YoetzSuggestion + Clone, N: YoetzScore>(
+        &mut self,
+        score: N,
+        ttl: Duration,
+        suggestion: S,
+    ) -> &mut Self;
+
+    /// Report that the active behavior of the entity's [`YoetzAdvisor<S, N>`] has finished (see
+    /// [`YoetzAdvisor::complete`]).
+    fn yoetz_complete<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self;
+
+    /// Report that the active behavior of the entity's [`YoetzAdvisor<S, N>`] has failed (see
+    /// [`YoetzAdvisor::fail`]).
+    fn yoetz_fail<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self;
+
+    /// Report that the active behavior of the entity's [`YoetzAdvisor<S, N>`] has failed, and
+    /// penalize its variant (see [`YoetzAdvisor::fail_with_penalty`]).
+    fn yoetz_fail_with_penalty<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        penalty: f32,
+        duration: Duration,
+    ) -> &mut Self;
+
+    /// Allow replacing the active behavior of the entity's [`YoetzAdvisor<S, N>`] even if it is not
+    /// interruptible (see [`YoetzAdvisor::release`]).
+    fn yoetz_release<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self;
+
+    /// Replace the active behavior of the entity's [`YoetzAdvisor<S, N>`] (see
+    /// [`YoetzAdvisor::set_active`]).
+    fn yoetz_set_active<S: YoetzSuggestion, N: YoetzScore>(&mut self, suggestion: S) -> &mut Self;
+
+    /// Remove the active behavior of the entity's [`YoetzAdvisor<S, N>`] (see
+    /// [`YoetzAdvisor::clear_active`]).
+    fn yoetz_clear_active<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self;
+}
+
+impl YoetzEntityCommandsExt for EntityCommands<'_> {
+    fn yoetz_suggest<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        score: N,
+        suggestion: S,
+    ) -> &mut Self {
+        self.queue(move |entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.suggest(score, suggestion);
+            }
+        })
+    }
+
+    fn yoetz_suggest_for<S: YoetzSuggestion + Clone, N: YoetzScore>(
+        &mut self,
+        score: N,
+        ttl: Duration,
+        suggestion: S,
+    ) -> &mut Self {
+        self.queue(move |entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.suggest_for(score, ttl, suggestion);
+            }
+        })
+    }
+
+    fn yoetz_complete<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self {
+        self.queue(|entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.complete();
+            }
+        })
+    }
+
+    fn yoetz_fail<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self {
+        self.queue(|entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.fail();
+            }
+        })
+    }
+
+    fn yoetz_fail_with_penalty<S: YoetzSuggestion, N: YoetzScore>(
+        &mut self,
+        penalty: f32,
+        duration: Duration,
+    ) -> &mut Self {
+        self.queue(move |entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.fail_with_penalty(penalty, duration);
+            }
+        })
+    }
+
+    fn yoetz_release<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self {
+        self.queue(|entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.release();
+            }
+        })
+    }
+
+    fn yoetz_set_active<S: YoetzSuggestion, N: YoetzScore>(&mut self, suggestion: S) -> &mut Self {
+        self.queue(move |entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.set_active(suggestion);
+            }
+        })
+    }
+
+    fn yoetz_clear_active<S: YoetzSuggestion, N: YoetzScore>(&mut self) -> &mut Self {
+        self.queue(|entity: Entity, world: &mut World| {
+            if let Some(mut advisor) = world.get_mut::<YoetzAdvisor<S, N>>(entity) {
+                advisor.clear_active();
+            }
+        })
+    }
+}
@@ -0,0 +1,257 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// Default settings for all the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor)s of a
+/// [`YoetzSuggestion`].
+///
+/// [`YoetzPlugin`](crate::YoetzPlugin) adds this resource with default values. Each setting can be
+/// overridden per advisor by setting the corresponding field of the advisor - settings that are
+/// `None` on the advisor are taken from this resource every time the advisor thinks, so changing
+/// it at runtime affects all the advisors that did not override it.
+#[derive(Resource)]
+pub struct YoetzConfig<S: YoetzSuggestion> {
+    /// Added to score of any suggestion that matches the currently active behavior.
+    pub consistency_bonus: f32,
+    /// How the [`consistency_bonus`](Self::consistency_bonus) turns into the bonus that is added
+    /// to the score.
+    pub consistency_bonus_mode: YoetzConsistencyBonusMode,
+    /// Suggestions with (aggregated) scores lower than this are ignored, even if there are no
+    /// other suggestions.
+    pub score_threshold: f32,
+    /// How to choose between suggestions with the exact same score.
+    pub tie_break: YoetzTieBreak,
+    /// How to combine the scores of multiple suggestions with the same key.
+    pub aggregation: YoetzAggregation,
+    /// For this long after an advisor starts thinking, its consistency bonus ramps up linearly
+    /// from zero to its full value.
+    ///
+    /// Newly spawned agents often make their first decision based on incomplete information, and
+    /// the warm-up prevents them from over-committing to it.
+    pub warmup: Duration,
+    /// A suggestion must win this many consecutive decisions before it replaces the active
+    /// behavior (as long as the active behavior is still suggested).
+    ///
+    /// Unlike the additive consistency bonus, this does not depend on the scale of the scores,
+    /// which makes it a better tool against noisy scores. `1` (the default) switches immediately.
+    pub switch_after_wins: u32,
+    /// What an advisor does when it does not receive any suggestion.
+    /// [`Keep`](YoetzStarvationPolicy::Keep) (the default) keeps its active behavior without
+    /// reporting anything.
+    pub starvation_policy: YoetzStarvationPolicy,
+    /// An advisor is starving - and its [`starvation_policy`](Self::starvation_policy) is
+    /// applied - once it did not receive any suggestion for this many consecutive thinks. `1`
+    /// (the default) applies the policy on the first think without suggestions.
+    pub starvation_frames: u32,
+    /// Round the final scores to multiples of this resolution before comparing them, so that
+    /// tiny floating point differences (e.g. between machines that compute transcendental
+    /// functions differently) do not change the decisions. Suggestions whose rounded scores are
+    /// equal are decided by the [`tie_break`](Self::tie_break). `None` (the default) compares the
+    /// scores as they are.
+    pub score_resolution: Option<f32>,
+    /// Make the decisions reproducible, for lockstep multiplayer and replays.
+    ///
+    /// The decisions of an advisor are always a deterministic function of its suggestions - they
+    /// are iterated in the order they were made, and the random
+    /// [arbitration](crate::arbitration::YoetzArbitration) policies are seeded with the advisor's
+    /// entity - but the advisors themselves are updated in parallel, so the order of the commands
+    /// and events they produce varies. In deterministic mode the advisors are updated serially,
+    /// in the order of the query.
+    ///
+    /// For bit-identical decisions across machines, the suggestions must also be made in the
+    /// same order, the [`tie_break`](Self::tie_break) should not depend on it (e.g.
+    /// [`PreferKeyOrder`](YoetzTieBreak::PreferKeyOrder)), and the scores should be rounded
+    /// with a [`score_resolution`](Self::score_resolution).
+    pub deterministic: bool,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzConfig<S> {
+    fn default() -> Self {
+        Self {
+            consistency_bonus: 0.0,
+            consistency_bonus_mode: Default::default(),
+            score_threshold: f32::NEG_INFINITY,
+            tie_break: Default::default(),
+            aggregation: Default::default(),
+            warmup: Duration::ZERO,
+            switch_after_wins: 1,
+            starvation_policy: Default::default(),
+            starvation_frames: 1,
+            score_resolution: None,
+            deterministic: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzConfig<S> {
+    /// Set the default [`consistency_bonus`](Self::consistency_bonus).
+    pub fn with_consistency_bonus(mut self, consistency_bonus: f32) -> Self {
+        self.consistency_bonus = consistency_bonus;
+        self
+    }
+
+    /// Set the default [`consistency_bonus_mode`](Self::consistency_bonus_mode).
+    pub fn with_consistency_bonus_mode(
+        mut self,
+        consistency_bonus_mode: YoetzConsistencyBonusMode,
+    ) -> Self {
+        self.consistency_bonus_mode = consistency_bonus_mode;
+        self
+    }
+
+    /// Set the default [`score_threshold`](Self::score_threshold).
+    pub fn with_score_threshold(mut self, score_threshold: f32) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    /// Set the default [`tie_break`](Self::tie_break).
+    pub fn with_tie_break(mut self, tie_break: YoetzTieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Set the default [`aggregation`](Self::aggregation).
+    pub fn with_aggregation(mut self, aggregation: YoetzAggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Set the default [`warmup`](Self::warmup).
+    pub fn with_warmup(mut self, warmup: Duration) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// Set the default [`switch_after_wins`](Self::switch_after_wins).
+    pub fn with_switch_after_wins(mut self, switch_after_wins: u32) -> Self {
+        self.switch_after_wins = switch_after_wins;
+        self
+    }
+
+    /// Set the default [`starvation_policy`](Self::starvation_policy).
+    pub fn with_starvation_policy(mut self, starvation_policy: YoetzStarvationPolicy) -> Self {
+        self.starvation_policy = starvation_policy;
+        self
+    }
+
+    /// Set the default [`starvation_frames`](Self::starvation_frames).
+    pub fn with_starvation_frames(mut self, starvation_frames: u32) -> Self {
+        self.starvation_frames = starvation_frames;
+        self
+    }
+
+    /// Set the default [`score_resolution`](Self::score_resolution).
+    pub fn with_score_resolution(mut self, score_resolution: f32) -> Self {
+        self.score_resolution = Some(score_resolution);
+        self
+    }
+
+    /// Enable the [`deterministic`](Self::deterministic) mode.
+    pub fn deterministic(mut self) -> Self {
+        self.deterministic = true;
+        self
+    }
+}
+
+/// How the consistency bonus of an advisor is added to the score of its active behavior.
+///
+/// A fixed bonus only makes sense when the scores have a known scale. When they span several
+/// orders of magnitude, a [`Proportional`](Self::Proportional) bonus keeps the same relative
+/// stickiness for all of them.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum YoetzConsistencyBonusMode {
+    /// Add the consistency bonus as is.
+    #[default]
+    Fixed,
+    /// Treat the consistency bonus as a fraction of the active behavior's score (e.g. `0.1` adds
+    /// 10% of the score).
+    Proportional,
+    /// Ramp the bonus up linearly from zero when the behavior becomes active, reaching the full
+    /// consistency bonus after this long - so the longer a behavior is active, the harder it is
+    /// to replace.
+    Ramp(Duration),
+    /// Compute the bonus with a function of the consistency bonus, the active behavior's score
+    /// and how long it has been active.
+    Custom(fn(f32, f32, Duration) -> f32),
+}
+
+impl YoetzConsistencyBonusMode {
+    /// The bonus to add to a score of the active behavior.
+    pub fn bonus(&self, consistency_bonus: f32, score: f32, time_active: Duration) -> f32 {
+        match self {
+            Self::Fixed => consistency_bonus,
+            Self::Proportional => consistency_bonus * score.abs(),
+            Self::Ramp(duration) => {
+                if duration.is_zero() {
+                    consistency_bonus
+                } else {
+                    consistency_bonus
+                        * (time_active.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+                }
+            }
+            Self::Custom(function) => function(consistency_bonus, score, time_active),
+        }
+    }
+}
+
+/// How to choose between suggestions with the exact same score (after applying the consistency
+/// bonus).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzTieBreak {
+    /// Choose the suggestion whose key was suggested last.
+    #[default]
+    PreferLatest,
+    /// Choose the suggestion whose key was suggested first.
+    PreferEarliest,
+    /// Choose the currently active behavior if it is one of the tied suggestions, and otherwise
+    /// fall back to [`PreferLatest`](Self::PreferLatest).
+    PreferActive,
+    /// Choose the suggestion whose variant is declared first in the suggestion `enum`, and among
+    /// suggestions of the same variant the one whose key was suggested first. Unlike the other
+    /// policies, this does not depend on the order of the suggestion systems (as long as the
+    /// suggestions of each variant are made in a consistent order).
+    PreferKeyOrder,
+}
+
+/// How to combine the scores of multiple suggestions with the same
+/// [`Key`](YoetzSuggestion::Key) that were made before the same decision.
+///
+/// Regardless of the aggregation, the data of the suggestion with the highest score is the one
+/// that gets used for the behavior components.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzAggregation {
+    /// Use the highest score.
+    #[default]
+    Max,
+    /// Sum all the scores.
+    Sum,
+    /// Average all the scores.
+    Average,
+}
+
+/// What an advisor does once it is starving - that is, once it did not receive any suggestion
+/// for [`starvation_frames`](YoetzConfig::starvation_frames) consecutive thinks.
+///
+/// Until then (and with [`Keep`](Self::Keep) or [`Report`](Self::Report) also afterwards) the
+/// advisor keeps its active behavior, including its components with their data from the last
+/// suggestion.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzStarvationPolicy {
+    /// Keep the active behavior.
+    #[default]
+    Keep,
+    /// Drop the active behavior, removing its components.
+    RemoveComponents,
+    /// Switch to the [`fallback`](YoetzSuggestion::fallback) behavior. If there is no fallback,
+    /// this is the same as [`Report`](Self::Report).
+    Fallback,
+    /// Keep the active behavior, and send a [`YoetzStarved`](crate::lifecycle::YoetzStarved)
+    /// event.
+    Report,
+}
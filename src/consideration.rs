@@ -0,0 +1,93 @@
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+/// How the considerations of a suggestion are combined into its score.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzConsiderationMode {
+    /// Multiply the values, each raised to the power of its weight - so any consideration with a
+    /// value of zero vetoes the suggestion.
+    #[default]
+    Product,
+    /// Average the values, weighted by their weights.
+    WeightedAverage,
+}
+
+/// Builds the score of a suggestion from weighted considerations. Created with
+/// [`YoetzAdvisor::consider`].
+///
+/// The considerations are kept in the advisor until it thinks, so multiple systems can add
+/// considerations for the same [`Key`](YoetzSuggestion::Key) and they are all combined into a
+/// single suggestion (with the data of the last one that [`finish`](Self::finish)ed).
+///
+/// The considerations are combined as `f32`s, and the combined score is converted to the score
+/// type of the advisor (see [`YoetzScore::from_f32`]).
+#[must_use = "considerations are only added to the advisor when `finish` is called"]
+pub struct YoetzConsideration<'a, S: YoetzSuggestion, N: YoetzScore = f32> {
+    advisor: &'a mut YoetzAdvisor<S, N>,
+    suggestion: S,
+    mode: Option<YoetzConsiderationMode>,
+    factors: Vec<(f32, f32)>,
+}
+
+impl<'a, S: YoetzSuggestion, N: YoetzScore> YoetzConsideration<'a, S, N> {
+    pub(crate) fn new(advisor: &'a mut YoetzAdvisor<S, N>, suggestion: S) -> Self {
+        Self {
+            advisor,
+            suggestion,
+            mode: None,
+            factors: Vec::new(),
+        }
+    }
+
+    /// Add a consideration - usually a value in `0.0..=1.0` (see the [`scorers`](crate::scorers)
+    /// module) - with its weight.
+    pub fn add(mut self, weight: f32, value: f32) -> Self {
+        self.factors.push((weight, value));
+        self
+    }
+
+    /// Set how the considerations of the suggestion are combined. This applies to all the
+    /// considerations of its key, including the ones added by other systems.
+    pub fn mode(mut self, mode: YoetzConsiderationMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Add the considerations to the advisor.
+    pub fn finish(self) {
+        self.advisor
+            .add_considerations(self.suggestion, self.mode, self.factors);
+    }
+}
+
+/// The considerations of a key, accumulated until the advisor thinks.
+pub(crate) struct YoetzConsiderations<S: YoetzSuggestion> {
+    pub key: S::Key,
+    pub suggestion: S,
+    pub mode: YoetzConsiderationMode,
+    pub factors: Vec<(f32, f32)>,
+}
+
+impl<S: YoetzSuggestion> YoetzConsiderations<S> {
+    pub fn score(&self) -> f32 {
+        match self.mode {
+            YoetzConsiderationMode::Product => self
+                .factors
+                .iter()
+                .map(|(weight, value)| value.powf(*weight))
+                .product(),
+            YoetzConsiderationMode::WeightedAverage => {
+                let total_weight: f32 = self.factors.iter().map(|(weight, _)| weight).sum();
+                if total_weight == 0.0 {
+                    return 0.0;
+                }
+                let total: f32 = self
+                    .factors
+                    .iter()
+                    .map(|(weight, value)| weight * value)
+                    .sum();
+                total / total_weight
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::advisor::{update_advisor, YoetzSuggestion};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::modifiers::{update_score_modifiers, YoetzScoreModifier, YoetzScoreModifiers};
+use crate::{YoetzInternalSystemSet, YoetzSystemSet};
+
+/// Makes an entity part of the crowd, and tracks how many other crowd members are near it.
+///
+/// The number of neighbors is updated by the [`YoetzCrowdPlugin`] before
+/// [`YoetzSystemSet::Suggest`], so suggestion systems can also use it directly.
+#[derive(Component)]
+pub struct YoetzCrowdDensity {
+    /// Other crowd members within this distance are considered neighbors.
+    pub radius: f32,
+    neighbors: usize,
+}
+
+impl YoetzCrowdDensity {
+    /// Track the crowd members within the specified radius.
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            neighbors: 0,
+        }
+    }
+
+    /// The number of other crowd members within the radius.
+    pub fn neighbors(&self) -> usize {
+        self.neighbors
+    }
+}
+
+/// Dampens the scores of some behaviors when the entity's [`YoetzCrowdDensity`] is too high.
+///
+/// The [`YoetzCrowdPlugin`] translates this into [`YoetzScoreModifiers`] (under the
+/// [`MODIFIER_SOURCE`](Self::MODIFIER_SOURCE) source) right before the advisor thinks, adding the
+/// `YoetzScoreModifiers` component if the entity does not have it.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Attack,
+/// # }
+/// # let mut commands: Commands = panic!();
+/// commands.spawn((
+///     YoetzAdvisor::<AiBehavior>::new(2.0),
+///     YoetzCrowdDensity::new(3.0),
+///     // Beyond 4 neighbors, each neighbor makes attacking less desirable.
+///     YoetzCrowdDampening::<AiBehavior>::default().with_rule(
+///         YoetzKeyPattern::variant::<AiBehaviorAttack>(),
+///         4,
+///         0.25,
+///     ),
+/// ));
+/// ```
+#[derive(Component)]
+pub struct YoetzCrowdDampening<S: YoetzSuggestion> {
+    rules: Vec<YoetzCrowdDampeningRule<S>>,
+}
+
+struct YoetzCrowdDampeningRule<S: YoetzSuggestion> {
+    pattern: YoetzKeyPattern<S>,
+    comfortable_neighbors: usize,
+    dampening_per_neighbor: f32,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzCrowdDampening<S> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzCrowdDampening<S> {
+    /// The source label of the modifiers this component generates.
+    pub const MODIFIER_SOURCE: &'static str = "crowd";
+
+    /// Dampen the scores of the keys that match the pattern when there are more than
+    /// `comfortable_neighbors` neighbors. The score is divided by `1 + dampening_per_neighbor *
+    /// excess_neighbors`.
+    pub fn with_rule(
+        mut self,
+        pattern: YoetzKeyPattern<S>,
+        comfortable_neighbors: usize,
+        dampening_per_neighbor: f32,
+    ) -> Self {
+        self.rules.push(YoetzCrowdDampeningRule {
+            pattern,
+            comfortable_neighbors,
+            dampening_per_neighbor,
+        });
+        self
+    }
+
+    fn apply_to(&self, density: &YoetzCrowdDensity, modifiers: &mut YoetzScoreModifiers<S>) {
+        modifiers.remove_source(Self::MODIFIER_SOURCE);
+        for rule in self.rules.iter() {
+            let excess = density.neighbors.saturating_sub(rule.comfortable_neighbors);
+            if excess == 0 {
+                continue;
+            }
+            modifiers.insert(
+                Self::MODIFIER_SOURCE,
+                rule.pattern.clone(),
+                YoetzScoreModifier::Multiply(
+                    1.0 / (1.0 + rule.dampening_per_neighbor * excess as f32),
+                ),
+            );
+        }
+    }
+}
+
+/// Maintains the [`YoetzCrowdDensity`] of the crowd members, and applies the
+/// [`YoetzCrowdDampening`] of the advisors of `S`.
+pub struct YoetzCrowdPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzCrowdPlugin<S> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzCrowdPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<YoetzCrowdDensityPlugin>() {
+            app.add_plugins(YoetzCrowdDensityPlugin {
+                schedule: self.schedule,
+            });
+        }
+        app.add_systems(
+            self.schedule,
+            apply_crowd_dampening::<S>
+                .in_set(YoetzInternalSystemSet::Think)
+                .before(update_advisor::<S, f32>),
+        );
+    }
+}
+
+/// The part of [`YoetzCrowdPlugin`] that does not depend on the suggestion type, so that it only
+/// gets added once.
+struct YoetzCrowdDensityPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl Plugin for YoetzCrowdDensityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            update_crowd_density.before(YoetzSystemSet::Suggest),
+        );
+    }
+}
+
+fn update_crowd_density(mut query: Query<(&mut YoetzCrowdDensity, &GlobalTransform)>) {
+    let cell_size = query
+        .iter()
+        .map(|(density, _)| density.radius)
+        .fold(0.0, f32::max);
+    if cell_size <= 0.0 {
+        for (mut density, _) in query.iter_mut() {
+            density.neighbors = 0;
+        }
+        return;
+    }
+    let cell_of = |position: Vec3| (position / cell_size).floor().as_ivec3();
+
+    let positions: Vec<Vec3> = query
+        .iter()
+        .map(|(_, transform)| transform.translation())
+        .collect();
+    let mut grid = HashMap::<IVec3, Vec<usize>>::default();
+    for (index, position) in positions.iter().enumerate() {
+        grid.entry(cell_of(*position)).or_default().push(index);
+    }
+
+    // Since the cell size is the largest radius, all the neighbors are in adjacent cells.
+    for (index, (mut density, _)) in query.iter_mut().enumerate() {
+        let position = positions[index];
+        let radius_squared = density.radius * density.radius;
+        let cell = cell_of(position);
+        let mut neighbors = 0;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(indices) = grid.get(&(cell + IVec3::new(dx, dy, dz))) else {
+                        continue;
+                    };
+                    neighbors += indices
+                        .iter()
+                        .filter(|&&other| {
+                            other != index
+                                && positions[other].distance_squared(position) <= radius_squared
+                        })
+                        .count();
+                }
+            }
+        }
+        density.neighbors = neighbors;
+    }
+}
+
+fn apply_crowd_dampening<S: YoetzSuggestion>(
+    mut query: Query<(
+        Entity,
+        &YoetzCrowdDensity,
+        &YoetzCrowdDampening<S>,
+        Option<&mut YoetzScoreModifiers<S>>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, density, dampening, modifiers) in query.iter_mut() {
+        update_score_modifiers(entity, modifiers, &mut commands, |modifiers| {
+            dampening.apply_to(density, modifiers);
+        });
+    }
+}
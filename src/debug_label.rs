@@ -0,0 +1,206 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+
+/// Settings for the world-space labels the [`YoetzDebugLabelPlugin`] shows above the advisors of
+/// `S`.
+///
+/// Changes to the settings take effect on the next frame, so this resource can also be used to
+/// toggle the labels at runtime.
+#[derive(Resource)]
+pub struct YoetzDebugLabels<S: YoetzSuggestion> {
+    /// Show the labels.
+    pub visible: bool,
+    /// Show the score of the active behavior.
+    pub show_score: bool,
+    /// Show how long the active behavior has been active.
+    pub show_time_in_behavior: bool,
+    /// The size of the font. The label is scaled by [`scale`](Self::scale), so this affects its
+    /// resolution more than its size.
+    pub font_size: f32,
+    /// The color of the text.
+    pub color: Color,
+    /// The position of the label relative to the advisor's entity.
+    pub offset: Vec3,
+    /// The scale of the label.
+    pub scale: f32,
+    /// Describes the active behavior. Defaults to the name of its variant.
+    pub key_text: fn(&S::Key) -> String,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzDebugLabels<S> {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            show_score: false,
+            show_time_in_behavior: false,
+            font_size: 72.0,
+            color: Color::WHITE,
+            offset: Vec3::new(0.0, 1.0, 1.0),
+            scale: 0.015,
+            key_text: |key| S::VARIANT_NAMES[S::variant_index(key)].to_owned(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzDebugLabels<S> {
+    fn text(&self, advisor: &YoetzAdvisor<S>) -> String {
+        let snapshot = advisor.snapshot();
+        let Some(active_key) = snapshot.active_key.as_ref() else {
+            return "-".to_owned();
+        };
+        let mut text = (self.key_text)(active_key);
+        if let (true, Some(score)) = (self.show_score, snapshot.active_score) {
+            text.push_str(&format!("\n{score:.2}"));
+        }
+        if self.show_time_in_behavior {
+            text.push_str(&format!(
+                "\n{:.1}s",
+                snapshot.time_in_behavior.as_secs_f32()
+            ));
+        }
+        text
+    }
+}
+
+/// Marks the label entity the [`YoetzDebugLabelPlugin`] spawns as a child of each advisor of `S`.
+#[derive(Component)]
+pub struct YoetzDebugLabel<S: YoetzSuggestion>(PhantomData<fn(S)>);
+
+/// Shows a world-space text label with the active behavior above each advisor of `S`.
+///
+/// The labels are configured with the [`YoetzDebugLabels`] resource.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// # let mut app = App::new();
+/// app.add_plugins(
+///     YoetzDebugLabelPlugin::<AiBehavior>::default()
+///         .with_score()
+///         .with_time_in_behavior(),
+/// );
+/// ```
+pub struct YoetzDebugLabelPlugin<S: YoetzSuggestion> {
+    show_score: bool,
+    show_time_in_behavior: bool,
+    key_text: Option<fn(&S::Key) -> String>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzDebugLabelPlugin<S> {
+    fn default() -> Self {
+        Self {
+            show_score: false,
+            show_time_in_behavior: false,
+            key_text: None,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzDebugLabelPlugin<S> {
+    /// Show the score of the active behavior in the labels.
+    pub fn with_score(mut self) -> Self {
+        self.show_score = true;
+        self
+    }
+
+    /// Show how long the active behavior has been active in the labels.
+    pub fn with_time_in_behavior(mut self) -> Self {
+        self.show_time_in_behavior = true;
+        self
+    }
+
+    /// Describe the active behavior with a custom function instead of the name of its variant.
+    ///
+    /// If the key enum derives `Debug` (see the `key_enum` attribute of the
+    /// [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) macro), `|key| format!("{key:?}")`
+    /// shows the key fields as well.
+    pub fn with_key_text(mut self, key_text: fn(&S::Key) -> String) -> Self {
+        self.key_text = Some(key_text);
+        self
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzDebugLabelPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let mut labels = YoetzDebugLabels::<S> {
+            show_score: self.show_score,
+            show_time_in_behavior: self.show_time_in_behavior,
+            ..Default::default()
+        };
+        if let Some(key_text) = self.key_text {
+            labels.key_text = key_text;
+        }
+        app.insert_resource(labels);
+        app.add_systems(
+            Update,
+            (add_missing_labels::<S>, update_labels::<S>).chain(),
+        );
+    }
+}
+
+fn add_missing_labels<S: YoetzSuggestion>(
+    query: Query<Entity, Added<YoetzAdvisor<S>>>,
+    labels: Res<YoetzDebugLabels<S>>,
+    mut commands: Commands,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).with_child((
+            YoetzDebugLabel::<S>(PhantomData),
+            Text2d::default(),
+            TextFont {
+                font_size: labels.font_size,
+                ..Default::default()
+            },
+            TextColor(labels.color),
+            Anchor::BottomCenter,
+            Transform::from_translation(labels.offset).with_scale(labels.scale * Vec3::ONE),
+        ));
+    }
+}
+
+fn update_labels<S: YoetzSuggestion>(
+    mut label_query: Query<
+        (
+            &mut Text2d,
+            &mut TextFont,
+            &mut TextColor,
+            &mut Transform,
+            &mut Visibility,
+            &Parent,
+        ),
+        With<YoetzDebugLabel<S>>,
+    >,
+    advisor_query: Query<&YoetzAdvisor<S>>,
+    labels: Res<YoetzDebugLabels<S>>,
+) {
+    for (mut text, mut font, mut color, mut transform, mut visibility, parent) in
+        label_query.iter_mut()
+    {
+        let Ok(advisor) = advisor_query.get(parent.get()) else {
+            continue;
+        };
+        visibility.set_if_neq(if labels.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+        if !labels.visible {
+            continue;
+        }
+        let new_text = labels.text(advisor);
+        if text.0 != new_text {
+            text.0 = new_text;
+        }
+        if labels.is_changed() {
+            font.font_size = labels.font_size;
+            color.0 = labels.color;
+            transform.translation = labels.offset;
+            transform.scale = labels.scale * Vec3::ONE;
+        }
+    }
+}
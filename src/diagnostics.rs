@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+
+/// The breakdown of the score of a single suggestion in a decision, recorded in
+/// [`YoetzDiagnostics`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct YoetzSuggestionDiagnostics<K> {
+    /// The [`Key`](YoetzSuggestion::Key) of the suggestion.
+    pub key: K,
+    /// The score of the suggestion (aggregated, if it was suggested multiple times).
+    pub score: f32,
+    /// The score after applying the [`YoetzScoreModifiers`](crate::modifiers::YoetzScoreModifiers),
+    /// or `None` if the suggestion was disabled (by the modifiers, by an interaction lock or
+    /// because the entity could not afford its costs).
+    pub modified_score: Option<f32>,
+    /// The bonus the suggestion got for being the active behavior.
+    pub bonus: f32,
+    /// The score the suggestion competed with, or `None` if it was rejected.
+    pub final_score: Option<f32>,
+    /// The label of the suggestion (see
+    /// [`YoetzAdvisor::suggest_labeled`](crate::advisor::YoetzAdvisor::suggest_labeled)).
+    pub source: Option<&'static str>,
+}
+
+/// A record of all the suggestions an advisor considered in its last decision - including the
+/// rejected ones - and of the decision itself.
+///
+/// The [`YoetzDiagnosticsPlugin`] adds this component to all the advisors of `S`. It can also be
+/// added manually to specific advisors.
+#[derive(Component)]
+pub struct YoetzDiagnostics<S: YoetzSuggestion> {
+    time: Duration,
+    suggestions: Vec<YoetzSuggestionDiagnostics<S::Key>>,
+    active_key: Option<S::Key>,
+    switched: bool,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzDiagnostics<S> {
+    fn default() -> Self {
+        Self {
+            time: Duration::ZERO,
+            suggestions: Vec::new(),
+            active_key: None,
+            switched: false,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzDiagnostics<S> {
+    /// When the decision was made.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// All the suggestions in the decision, in the order they were first made.
+    pub fn suggestions(&self) -> &[YoetzSuggestionDiagnostics<S::Key>] {
+        &self.suggestions
+    }
+
+    /// The rejected suggestions in the decision.
+    pub fn rejected(&self) -> impl '_ + Iterator<Item = &YoetzSuggestionDiagnostics<S::Key>> {
+        self.suggestions
+            .iter()
+            .filter(|suggestion| suggestion.final_score.is_none())
+    }
+
+    /// The [`Key`](YoetzSuggestion::Key) of the behavior that was active after the decision.
+    pub fn active_key(&self) -> Option<&S::Key> {
+        self.active_key.as_ref()
+    }
+
+    /// Whether the decision switched to a new behavior.
+    pub fn switched(&self) -> bool {
+        self.switched
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        time: Duration,
+        suggestions: impl Iterator<Item = YoetzSuggestionDiagnostics<S::Key>>,
+    ) {
+        self.time = time;
+        self.suggestions.clear();
+        self.suggestions.extend(suggestions);
+    }
+
+    pub(crate) fn record_decision(&mut self, active_key: Option<S::Key>, switched: bool) {
+        self.active_key = active_key;
+        self.switched = switched;
+    }
+}
+
+/// Adds [`YoetzDiagnostics`] to all the advisors of `S`.
+pub struct YoetzDiagnosticsPlugin<S: YoetzSuggestion>(PhantomData<fn(S)>);
+
+impl<S: YoetzSuggestion> Default for YoetzDiagnosticsPlugin<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzDiagnosticsPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(add_diagnostics::<S>);
+    }
+}
+
+fn add_diagnostics<S: YoetzSuggestion>(
+    trigger: Trigger<OnAdd, YoetzAdvisor<S>>,
+    mut commands: Commands,
+) {
+    commands
+        .entity(trigger.entity())
+        .insert(YoetzDiagnostics::<S>::default());
+}
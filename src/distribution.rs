@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// The normalized scores of all the suggestions in the last decision of a
+/// [`YoetzAdvisor`](crate::advisor::YoetzAdvisor).
+///
+/// Add this component next to the advisor to have it filled every time the advisor thinks. The
+/// winner-takes-all decision hides how close the other suggestions were - this component exposes
+/// it, for things like animation blending or telegraphing the AI's intent to the player.
+///
+/// The scores (including the consistency bonus) are normalized with a softmax, so they are all
+/// positive and add up to 1.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Attack,
+/// # }
+/// # #[derive(Component)] struct AttackAnimationWeight(f32);
+/// fn blend_attack_animation(
+///     mut query: Query<(&YoetzScoreDistribution<AiBehavior>, &mut AttackAnimationWeight)>,
+/// ) {
+///     for (distribution, mut weight) in query.iter_mut() {
+///         weight.0 = distribution.probability(&AiBehaviorKey::Attack);
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzScoreDistribution<S: YoetzSuggestion> {
+    /// Controls how sharp the distribution is. Lower temperatures make the highest score dominate
+    /// the distribution, and higher temperatures make it more uniform. A temperature of zero (or
+    /// less) gives the entire probability to the highest score.
+    pub temperature: f32,
+    entries: Vec<(S::Key, f32)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzScoreDistribution<S> {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzScoreDistribution<S> {
+    /// Create an empty distribution with the specified [`temperature`](Self::temperature).
+    pub fn new(temperature: f32) -> Self {
+        Self {
+            temperature,
+            entries: Vec::new(),
+        }
+    }
+
+    /// The [`Key`](YoetzSuggestion::Key)s of all the eligible suggestions in the last decision,
+    /// with their normalized scores.
+    pub fn entries(&self) -> &[(S::Key, f32)] {
+        &self.entries
+    }
+
+    /// The normalized score of a key, or `0.0` if it was not suggested in the last decision.
+    pub fn probability(&self, key: &S::Key) -> f32 {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, probability)| *probability)
+            .unwrap_or(0.0)
+    }
+
+    pub(crate) fn fill<'a>(&mut self, scores: impl Iterator<Item = (&'a S::Key, f32)>) {
+        self.entries.clear();
+        self.entries
+            .extend(scores.map(|(key, score)| (key.clone(), score)));
+        let Some(max) = self
+            .entries
+            .iter()
+            .map(|(_, score)| *score)
+            .max_by(f32::total_cmp)
+        else {
+            return;
+        };
+        for (_, score) in self.entries.iter_mut() {
+            *score = if 0.0 < self.temperature {
+                ((*score - max) / self.temperature).exp()
+            } else if *score == max {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        let total: f32 = self.entries.iter().map(|(_, weight)| *weight).sum();
+        for (_, score) in self.entries.iter_mut() {
+            *score /= total;
+        }
+    }
+}
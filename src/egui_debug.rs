@@ -0,0 +1,291 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::diagnostics::{YoetzDiagnostics, YoetzDiagnosticsPlugin};
+
+/// Settings and state of the window the [`YoetzEguiDebugPlugin`] shows for the advisors of `S`.
+#[derive(Resource)]
+pub struct YoetzEguiDebug<S: YoetzSuggestion> {
+    /// Show the window.
+    pub visible: bool,
+    /// The entity whose advisor is shown. Can also be selected in the window.
+    pub selected: Option<Entity>,
+    /// How many decisions to keep in the score history graph.
+    pub history_length: usize,
+    /// Describes the behaviors. Defaults to the name of their variant.
+    pub key_text: fn(&S::Key) -> String,
+    /// The time of each decision in the history, and the final scores of the suggestions in it.
+    history: VecDeque<(Duration, Vec<(String, Option<f32>)>)>,
+    history_entity: Option<Entity>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzEguiDebug<S> {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            selected: None,
+            history_length: 200,
+            key_text: |key| S::VARIANT_NAMES[S::variant_index(key)].to_owned(),
+            history: VecDeque::new(),
+            history_entity: None,
+        }
+    }
+}
+
+/// Shows an egui window for inspecting the decisions of a selected advisor of `S` - the
+/// suggestions with their scores and bonuses, the active behavior and a graph of the scores over
+/// the recent decisions.
+///
+/// The window is configured with the [`YoetzEguiDebug`] resource. This plugin adds the
+/// [`YoetzDiagnosticsPlugin`] (which it reads the decisions from) and bevy_egui's `EguiPlugin` if
+/// they were not added already.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzEguiDebugPlugin::<AiBehavior>::default());
+/// ```
+pub struct YoetzEguiDebugPlugin<S: YoetzSuggestion> {
+    key_text: Option<fn(&S::Key) -> String>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzEguiDebugPlugin<S> {
+    fn default() -> Self {
+        Self { key_text: None }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzEguiDebugPlugin<S> {
+    /// Describe the behaviors with a custom function instead of the name of their variant.
+    ///
+    /// If the key enum derives `Debug` (see the `key_enum` attribute of the
+    /// [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) macro), `|key| format!("{key:?}")`
+    /// shows the key fields as well.
+    pub fn with_key_text(mut self, key_text: fn(&S::Key) -> String) -> Self {
+        self.key_text = Some(key_text);
+        self
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzEguiDebugPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<YoetzDiagnosticsPlugin<S>>() {
+            app.add_plugins(YoetzDiagnosticsPlugin::<S>::default());
+        }
+        let mut debug = YoetzEguiDebug::<S>::default();
+        if let Some(key_text) = self.key_text {
+            debug.key_text = key_text;
+        }
+        app.insert_resource(debug);
+        app.add_systems(Update, (record_history::<S>, show_window::<S>).chain());
+    }
+}
+
+fn record_history<S: YoetzSuggestion>(
+    mut debug: ResMut<YoetzEguiDebug<S>>,
+    query: Query<&YoetzDiagnostics<S>>,
+) {
+    let debug = debug.as_mut();
+    if debug.history_entity != debug.selected {
+        debug.history_entity = debug.selected;
+        debug.history.clear();
+    }
+    let Some(diagnostics) = debug.selected.and_then(|entity| query.get(entity).ok()) else {
+        return;
+    };
+    if debug
+        .history
+        .back()
+        .is_some_and(|(time, _)| *time == diagnostics.time())
+    {
+        return;
+    }
+    let scores = diagnostics
+        .suggestions()
+        .iter()
+        .map(|suggestion| ((debug.key_text)(&suggestion.key), suggestion.final_score))
+        .collect();
+    debug.history.push_back((diagnostics.time(), scores));
+    while debug.history_length < debug.history.len() {
+        debug.history.pop_front();
+    }
+}
+
+fn show_window<S: YoetzSuggestion>(
+    mut contexts: EguiContexts,
+    mut debug: ResMut<YoetzEguiDebug<S>>,
+    query: Query<(
+        Entity,
+        &YoetzAdvisor<S>,
+        Option<&YoetzDiagnostics<S>>,
+        Option<&Name>,
+    )>,
+) {
+    if !debug.visible {
+        return;
+    }
+    let debug = debug.as_mut();
+    let entity_text = |entity: Entity, name: Option<&Name>| match name {
+        Some(name) => format!("{name} ({entity})"),
+        None => entity.to_string(),
+    };
+    let title = format!("Yoetz - {}", std::any::type_name::<S>());
+    egui::Window::new(title)
+        .open(&mut debug.visible)
+        .show(contexts.ctx_mut(), |ui| {
+            let selected_text = debug
+                .selected
+                .and_then(|entity| query.get(entity).ok())
+                .map(|(entity, _, _, name)| entity_text(entity, name))
+                .unwrap_or_else(|| "-".to_owned());
+            egui::ComboBox::from_label("Entity")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for (entity, _, _, name) in query.iter() {
+                        ui.selectable_value(
+                            &mut debug.selected,
+                            Some(entity),
+                            entity_text(entity, name),
+                        );
+                    }
+                });
+
+            let Some((_, advisor, diagnostics, _)) =
+                debug.selected.and_then(|entity| query.get(entity).ok())
+            else {
+                return;
+            };
+            let snapshot = advisor.snapshot();
+            ui.separator();
+            ui.label(format!(
+                "Active: {}",
+                snapshot
+                    .active_key
+                    .as_ref()
+                    .map(debug.key_text)
+                    .unwrap_or_else(|| "-".to_owned())
+            ));
+            if let Some(score) = snapshot.active_score {
+                ui.label(format!("Score: {score:.2}"));
+            }
+            ui.label(format!(
+                "Time in behavior: {:.1}s",
+                snapshot.time_in_behavior.as_secs_f32()
+            ));
+
+            let Some(diagnostics) = diagnostics else {
+                ui.label("No YoetzDiagnostics on this entity");
+                return;
+            };
+            ui.separator();
+            egui::Grid::new("suggestions").striped(true).show(ui, |ui| {
+                for header in [
+                    "Suggestion",
+                    "Score",
+                    "Modified",
+                    "Bonus",
+                    "Final",
+                    "Source",
+                ] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+                let format_score = |score: Option<f32>| match score {
+                    Some(score) => format!("{score:.2}"),
+                    None => "rejected".to_owned(),
+                };
+                for suggestion in diagnostics.suggestions() {
+                    let mut text = (debug.key_text)(&suggestion.key);
+                    if diagnostics.active_key() == Some(&suggestion.key) {
+                        text.push_str(" *");
+                    }
+                    ui.label(text);
+                    ui.label(format!("{:.2}", suggestion.score));
+                    ui.label(format_score(suggestion.modified_score));
+                    ui.label(format!("{:.2}", suggestion.bonus));
+                    ui.label(format_score(suggestion.final_score));
+                    ui.label(suggestion.source.unwrap_or(""));
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            show_history_graph(ui, &debug.history);
+        });
+}
+
+/// Draw the final scores of the suggestions in the history, with a line for each suggestion.
+fn show_history_graph(
+    ui: &mut egui::Ui,
+    history: &VecDeque<(Duration, Vec<(String, Option<f32>)>)>,
+) {
+    let mut keys = Vec::<&str>::new();
+    let (mut min_score, mut max_score) = (f32::INFINITY, f32::NEG_INFINITY);
+    for (_, scores) in history.iter() {
+        for (key, score) in scores.iter() {
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+            if let Some(score) = score {
+                min_score = min_score.min(*score);
+                max_score = max_score.max(*score);
+            }
+        }
+    }
+    if keys.is_empty() || max_score < min_score {
+        ui.label("No score history yet");
+        return;
+    }
+    if max_score - min_score < f32::EPSILON {
+        min_score -= 1.0;
+        max_score += 1.0;
+    }
+
+    let (response, painter) = ui.allocate_painter(
+        egui::vec2(ui.available_width(), 150.0),
+        egui::Sense::hover(),
+    );
+    let rect = response.rect;
+    painter.rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    let step = rect.width() / (history.len().max(2) - 1) as f32;
+    let point = |index: usize, score: f32| {
+        egui::pos2(
+            rect.left() + index as f32 * step,
+            rect.bottom() - (score - min_score) / (max_score - min_score) * rect.height(),
+        )
+    };
+    let color_of = |key_index: usize| -> egui::Color32 {
+        egui::ecolor::Hsva::new(key_index as f32 / keys.len() as f32, 0.8, 0.9, 1.0).into()
+    };
+    let score_of = |scores: &[(String, Option<f32>)], key: &str| {
+        scores
+            .iter()
+            .find(|(scored_key, _)| scored_key == key)
+            .and_then(|(_, score)| *score)
+    };
+    for (key_index, key) in keys.iter().enumerate() {
+        let stroke = egui::Stroke::new(1.5, color_of(key_index));
+        for (index, window) in history.iter().collect::<Vec<_>>().windows(2).enumerate() {
+            if let (Some(from), Some(to)) =
+                (score_of(&window[0].1, key), score_of(&window[1].1, key))
+            {
+                painter.line_segment([point(index, from), point(index + 1, to)], stroke);
+            }
+        }
+    }
+    ui.horizontal_wrapped(|ui| {
+        for (key_index, key) in keys.iter().enumerate() {
+            ui.colored_label(color_of(key_index), *key);
+        }
+    });
+    ui.label(format!("{min_score:.2} - {max_score:.2}"));
+}
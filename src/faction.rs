@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+/// The faction an entity belongs to.
+///
+/// How factions relate to each other is defined in the [`YoetzFactionRelations`] resource.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+pub struct YoetzFaction(pub u32);
+
+/// How one faction treats another, as decided by [`YoetzFactionRelations::relation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YoetzRelation {
+    /// The weight is lower than [`YoetzFactionRelations::hostile_below`].
+    Hostile,
+    /// The weight is between the hostile and the friendly thresholds.
+    Neutral,
+    /// The weight is higher than [`YoetzFactionRelations::friendly_above`].
+    Friendly,
+}
+
+/// The relations between [`YoetzFaction`]s.
+///
+/// Each relation is a numeric weight - negative for hostility and positive for friendliness - so
+/// that it can be used directly in scores. Relations that were not set have a weight of zero,
+/// except for the relation of a faction with itself which defaults to
+/// [`own_faction_weight`](Self::own_faction_weight).
+///
+/// Use [`YoetzPlugin::rethink_on_faction_relations_change`](crate::YoetzPlugin::rethink_on_faction_relations_change)
+/// to make advisors re-decide when the relations change.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Attack {
+/// #         #[yoetz(key)]
+/// #         target: Entity,
+/// #     },
+/// # }
+/// const PLAYER: YoetzFaction = YoetzFaction(0);
+/// const BANDITS: YoetzFaction = YoetzFaction(1);
+///
+/// # let mut app = App::new();
+/// app.insert_resource(YoetzFactionRelations::default().with(PLAYER, BANDITS, -1.0));
+///
+/// fn suggest_attack(
+///     mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &YoetzFaction)>,
+///     targets_query: Query<(Entity, &YoetzFaction)>,
+///     relations: Res<YoetzFactionRelations>,
+/// ) {
+///     for (mut advisor, faction) in query.iter_mut() {
+///         for target in relations.filter_hostile(*faction, targets_query.iter()) {
+///             advisor.suggest(
+///                 relations.hostility(*faction, *targets_query.get(target).unwrap().1),
+///                 AiBehavior::Attack { target },
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct YoetzFactionRelations {
+    /// Relations with a weight lower than this are [`Hostile`](YoetzRelation::Hostile).
+    pub hostile_below: f32,
+    /// Relations with a weight higher than this are [`Friendly`](YoetzRelation::Friendly).
+    pub friendly_above: f32,
+    /// The weight of the relation of a faction with itself, unless explicitly set.
+    pub own_faction_weight: f32,
+    weights: HashMap<(YoetzFaction, YoetzFaction), f32>,
+}
+
+impl Default for YoetzFactionRelations {
+    fn default() -> Self {
+        Self {
+            hostile_below: 0.0,
+            friendly_above: 0.0,
+            own_faction_weight: 1.0,
+            weights: Default::default(),
+        }
+    }
+}
+
+impl YoetzFactionRelations {
+    /// Set the relation between two factions, in both directions.
+    pub fn with(mut self, a: YoetzFaction, b: YoetzFaction, weight: f32) -> Self {
+        self.set(a, b, weight);
+        self
+    }
+
+    /// Set the relation between two factions, in both directions.
+    pub fn set(&mut self, a: YoetzFaction, b: YoetzFaction, weight: f32) {
+        self.set_one_way(a, b, weight);
+        self.set_one_way(b, a, weight);
+    }
+
+    /// Set how the faction `from` treats the faction `to`, without changing how `to` treats
+    /// `from`.
+    pub fn set_one_way(&mut self, from: YoetzFaction, to: YoetzFaction, weight: f32) {
+        self.weights.insert((from, to), weight);
+    }
+
+    /// The weight of how the faction `from` treats the faction `to`.
+    pub fn weight(&self, from: YoetzFaction, to: YoetzFaction) -> f32 {
+        if let Some(weight) = self.weights.get(&(from, to)) {
+            *weight
+        } else if from == to {
+            self.own_faction_weight
+        } else {
+            0.0
+        }
+    }
+
+    /// How the faction `from` treats the faction `to`.
+    pub fn relation(&self, from: YoetzFaction, to: YoetzFaction) -> YoetzRelation {
+        let weight = self.weight(from, to);
+        if weight < self.hostile_below {
+            YoetzRelation::Hostile
+        } else if self.friendly_above < weight {
+            YoetzRelation::Friendly
+        } else {
+            YoetzRelation::Neutral
+        }
+    }
+
+    /// How hostile the faction `from` is toward the faction `to`, as a non-negative number that
+    /// can be used as a score.
+    pub fn hostility(&self, from: YoetzFaction, to: YoetzFaction) -> f32 {
+        (-self.weight(from, to)).max(0.0)
+    }
+
+    /// How friendly the faction `from` is toward the faction `to`, as a non-negative number that
+    /// can be used as a score.
+    pub fn friendliness(&self, from: YoetzFaction, to: YoetzFaction) -> f32 {
+        self.weight(from, to).max(0.0)
+    }
+
+    /// Filter the candidates whose faction `from` has the specified relation toward.
+    pub fn filter<'a, T: 'a>(
+        &'a self,
+        from: YoetzFaction,
+        relation: YoetzRelation,
+        candidates: impl 'a + IntoIterator<Item = (T, &'a YoetzFaction)>,
+    ) -> impl 'a + Iterator<Item = T> {
+        candidates.into_iter().filter_map(move |(candidate, to)| {
+            (self.relation(from, *to) == relation).then_some(candidate)
+        })
+    }
+
+    /// Filter the candidates the faction `from` is hostile toward.
+    pub fn filter_hostile<'a, T: 'a>(
+        &'a self,
+        from: YoetzFaction,
+        candidates: impl 'a + IntoIterator<Item = (T, &'a YoetzFaction)>,
+    ) -> impl 'a + Iterator<Item = T> {
+        self.filter(from, YoetzRelation::Hostile, candidates)
+    }
+
+    /// Filter the candidates the faction `from` is friendly toward.
+    pub fn filter_friendly<'a, T: 'a>(
+        &'a self,
+        from: YoetzFaction,
+        candidates: impl 'a + IntoIterator<Item = (T, &'a YoetzFaction)>,
+    ) -> impl 'a + Iterator<Item = T> {
+        self.filter(from, YoetzRelation::Friendly, candidates)
+    }
+}
+
+pub fn rethink_on_faction_relations_change<S: YoetzSuggestion, N: YoetzScore>(
+    relations: Option<Res<YoetzFactionRelations>>,
+    mut query: Query<&mut YoetzAdvisor<S, N>, With<YoetzFaction>>,
+) {
+    if !relations.is_some_and(|relations| relations.is_changed()) {
+        return;
+    }
+    for mut advisor in query.iter_mut() {
+        advisor.request_rethink();
+    }
+}
@@ -0,0 +1,169 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::{YoetzInternalSystemSet, YoetzSystemSet};
+
+/// The plane the [`YoetzHeatMap`] cells are laid on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzHeatMapPlane {
+    /// For 2D games.
+    #[default]
+    XY,
+    /// For 3D games where Y is up.
+    XZ,
+}
+
+/// Aggregates the active behaviors of the advisors of `S` spatially, for seeing at a glance what
+/// the AI population is doing across the map.
+///
+/// The [`YoetzHeatMapPlugin`] adds this resource, fills it every time the advisors think, and
+/// (while [`draw`](Self::draw) is `true`) draws the cells with gizmos, colored by their dominant
+/// variant. The cells can also be read with [`iter_cells`](Self::iter_cells) for rendering them
+/// some other way.
+#[derive(Resource)]
+pub struct YoetzHeatMap<S: YoetzSuggestion> {
+    /// The size of each cell.
+    pub cell_size: f32,
+    /// The plane the cells are laid on.
+    pub plane: YoetzHeatMapPlane,
+    /// Draw the cells with gizmos.
+    pub draw: bool,
+    /// The color of each variant (by [`variant_index`](YoetzSuggestion::variant_index)). Variants
+    /// without a color get one automatically.
+    pub variant_colors: Vec<Color>,
+    cells: HashMap<IVec2, Vec<usize>>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzHeatMap<S> {
+    /// Iterate over the non-empty cells, with the index of the dominant variant in each cell and
+    /// the number of advisors in the cell with that variant.
+    pub fn iter_cells(&self) -> impl '_ + Iterator<Item = (IVec2, usize, usize)> {
+        self.cells.iter().filter_map(|(cell, counts)| {
+            let (variant_index, count) = counts
+                .iter()
+                .copied()
+                .enumerate()
+                .max_by_key(|(_, count)| *count)?;
+            Some((*cell, variant_index, count))
+        })
+    }
+
+    /// The counts of the active behaviors in a cell, indexed by variant.
+    pub fn cell_counts(&self, cell: IVec2) -> &[usize] {
+        self.cells.get(&cell).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// The cell a position falls in.
+    pub fn cell_of(&self, position: Vec3) -> IVec2 {
+        let position = match self.plane {
+            YoetzHeatMapPlane::XY => position.xy(),
+            YoetzHeatMapPlane::XZ => position.xz(),
+        };
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// The color used for a variant.
+    pub fn variant_color(&self, variant_index: usize) -> Color {
+        self.variant_colors
+            .get(variant_index)
+            .copied()
+            .unwrap_or_else(|| {
+                let hue = 360.0 * variant_index as f32 / S::VARIANT_NAMES.len().max(1) as f32;
+                Color::hsl(hue, 0.8, 0.5)
+            })
+    }
+
+    fn cell_corners(&self, cell: IVec2) -> [Vec3; 5] {
+        let min = cell.as_vec2() * self.cell_size;
+        let max = min + Vec2::splat(self.cell_size);
+        [
+            Vec2::new(min.x, min.y),
+            Vec2::new(max.x, min.y),
+            Vec2::new(max.x, max.y),
+            Vec2::new(min.x, max.y),
+            Vec2::new(min.x, min.y),
+        ]
+        .map(|corner| match self.plane {
+            YoetzHeatMapPlane::XY => corner.extend(0.0),
+            YoetzHeatMapPlane::XZ => Vec3::new(corner.x, 0.0, corner.y),
+        })
+    }
+}
+
+/// Maintains and draws a [`YoetzHeatMap`] of the advisors of `S`.
+pub struct YoetzHeatMapPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    cell_size: f32,
+    plane: YoetzHeatMapPlane,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzHeatMapPlugin<S> {
+    /// Create a plugin that aggregates the behaviors in the given schedule (which should be the
+    /// same schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with) into cells of the
+    /// specified size.
+    pub fn new(schedule: impl ScheduleLabel, cell_size: f32, plane: YoetzHeatMapPlane) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            cell_size,
+            plane,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzHeatMapPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(YoetzHeatMap::<S> {
+            cell_size: self.cell_size,
+            plane: self.plane,
+            draw: true,
+            variant_colors: Vec::new(),
+            cells: Default::default(),
+            _phantom: PhantomData,
+        });
+        app.add_systems(
+            self.schedule,
+            update_heat_map::<S>
+                .after(YoetzInternalSystemSet::Think)
+                .before(YoetzSystemSet::Act),
+        );
+        app.add_systems(Update, draw_heat_map::<S>);
+    }
+}
+
+fn update_heat_map<S: YoetzSuggestion>(
+    query: Query<(&YoetzAdvisor<S>, &GlobalTransform)>,
+    mut heat_map: ResMut<YoetzHeatMap<S>>,
+) {
+    let heat_map = heat_map.as_mut();
+    heat_map.cells.clear();
+    for (advisor, transform) in query.iter() {
+        let Some(active_key) = advisor.active_key() else {
+            continue;
+        };
+        let cell = heat_map.cell_of(transform.translation());
+        let counts = heat_map
+            .cells
+            .entry(cell)
+            .or_insert_with(|| vec![0; S::VARIANT_NAMES.len()]);
+        counts[S::variant_index(active_key)] += 1;
+    }
+}
+
+fn draw_heat_map<S: YoetzSuggestion>(heat_map: Res<YoetzHeatMap<S>>, mut gizmos: Gizmos) {
+    if !heat_map.draw {
+        return;
+    }
+    for (cell, variant_index, _) in heat_map.iter_cells() {
+        gizmos.linestrip(
+            heat_map.cell_corners(cell),
+            heat_map.variant_color(variant_index),
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::marker::PhantomData;
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::utils::Instant;
+
+use crate::advisor::{update_advisor, YoetzAdvisor, YoetzSuggestion};
+use crate::lifecycle::YoetzBehaviorStarted;
+use crate::{YoetzInternalSystemSet, YoetzPluginSet};
+
+/// Registers [`Diagnostic`]s for the advisors of `S`, so that AI thrashing and performance
+/// regressions show up in the output of the `LogDiagnosticsPlugin` (or any other diagnostics
+/// consumer):
+///
+/// * [`suggestions_per_advisor`](Self::suggestions_per_advisor) - the average number of distinct
+///   suggestions each advisor has right before it thinks.
+/// * [`switches_per_second`](Self::switches_per_second) - how many times per second advisors
+///   switch to a new behavior (summed over all the advisors).
+/// * [`think_duration`](Self::think_duration) - how long it takes the advisors to think, in
+///   milliseconds.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::diagnostic::LogDiagnosticsPlugin;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// # let mut app = App::new();
+/// app.add_plugins((
+///     YoetzPlugin::<AiBehavior>::new(FixedUpdate),
+///     YoetzInstrumentationPlugin::<AiBehavior>::new(FixedUpdate),
+///     LogDiagnosticsPlugin::default(),
+/// ));
+/// ```
+pub struct YoetzInstrumentationPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzInstrumentationPlugin<S> {
+    /// Create a plugin that measures the advisors in the given schedule (which should be the same
+    /// schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The path of the diagnostic of the average number of suggestions per advisor.
+    pub fn suggestions_per_advisor() -> DiagnosticPath {
+        Self::path("suggestions_per_advisor")
+    }
+
+    /// The path of the diagnostic of the behavior switches per second.
+    pub fn switches_per_second() -> DiagnosticPath {
+        Self::path("switches_per_second")
+    }
+
+    /// The path of the diagnostic of the time it takes the advisors to think.
+    pub fn think_duration() -> DiagnosticPath {
+        Self::path("think_duration")
+    }
+
+    fn path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("yoetz/{}/{name}", std::any::type_name::<S>()))
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzInstrumentationPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let paths = YoetzInstrumentationPaths::<S> {
+            suggestions_per_advisor: Self::suggestions_per_advisor(),
+            switches_per_second: Self::switches_per_second(),
+            think_duration: Self::think_duration(),
+            think_started: None,
+            _phantom: PhantomData,
+        };
+        app.register_diagnostic(Diagnostic::new(paths.suggestions_per_advisor.clone()));
+        app.register_diagnostic(Diagnostic::new(paths.switches_per_second.clone()));
+        app.register_diagnostic(Diagnostic::new(paths.think_duration.clone()).with_suffix("ms"));
+        app.insert_resource(paths);
+        app.add_systems(
+            self.schedule,
+            (
+                measure_before_think::<S>.before(update_advisor::<S, f32>),
+                measure_after_think::<S>.after(update_advisor::<S, f32>),
+            )
+                .in_set(YoetzInternalSystemSet::Think)
+                .in_set(YoetzPluginSet::<S>::default()),
+        );
+    }
+}
+
+#[derive(Resource)]
+struct YoetzInstrumentationPaths<S: YoetzSuggestion> {
+    suggestions_per_advisor: DiagnosticPath,
+    switches_per_second: DiagnosticPath,
+    think_duration: DiagnosticPath,
+    think_started: Option<Instant>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+fn measure_before_think<S: YoetzSuggestion>(
+    mut paths: ResMut<YoetzInstrumentationPaths<S>>,
+    query: Query<&YoetzAdvisor<S>>,
+    mut diagnostics: Diagnostics,
+) {
+    let (advisors, suggestions) = query
+        .iter()
+        .fold((0, 0), |(advisors, suggestions), advisor| {
+            (advisors + 1, suggestions + advisor.num_candidates())
+        });
+    if 0 < advisors {
+        diagnostics.add_measurement(&paths.suggestions_per_advisor, || {
+            suggestions as f64 / advisors as f64
+        });
+    }
+    paths.think_started = Some(Instant::now());
+}
+
+fn measure_after_think<S: YoetzSuggestion>(
+    mut paths: ResMut<YoetzInstrumentationPaths<S>>,
+    mut started_events: EventReader<YoetzBehaviorStarted<S>>,
+    time: Res<Time>,
+    mut diagnostics: Diagnostics,
+) {
+    if let Some(think_started) = paths.think_started.take() {
+        diagnostics.add_measurement(&paths.think_duration, || {
+            think_started.elapsed().as_secs_f64() * 1000.0
+        });
+    }
+    let switches = started_events.read().count();
+    let delta = time.delta_secs_f64();
+    if 0.0 < delta {
+        diagnostics.add_measurement(&paths.switches_per_second, || switches as f64 / delta);
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::score::YoetzScore;
+
+/// Suspends or restricts a [`YoetzAdvisor`] while its entity is in a conversation or some other
+/// interaction with the player.
+///
+/// While this component is present, a fully locked advisor does not change its active behavior at
+/// all, and a restricted advisor only considers suggestions that match the allowed pattern. When
+/// the component is removed, the advisor re-decides immediately (see
+/// [`YoetzAdvisor::request_rethink`]).
+///
+/// To lock advisors while a component from a dialogue crate (or any other component) is present,
+/// use [`YoetzPlugin::lock_while`](crate::YoetzPlugin::lock_while).
+#[derive(Component)]
+pub struct YoetzInteractionLock<S: YoetzSuggestion> {
+    allowed: Option<YoetzKeyPattern<S>>,
+}
+
+impl<S: YoetzSuggestion> YoetzInteractionLock<S> {
+    /// Suspend the advisor entirely, keeping its active behavior.
+    pub fn full() -> Self {
+        Self { allowed: None }
+    }
+
+    /// Only allow suggestions whose keys match the pattern.
+    pub fn restricted_to(allowed: YoetzKeyPattern<S>) -> Self {
+        Self {
+            allowed: Some(allowed),
+        }
+    }
+
+    /// The pattern of the suggestions the advisor may still consider, or `None` if it is fully
+    /// suspended.
+    pub fn allowed(&self) -> Option<&YoetzKeyPattern<S>> {
+        self.allowed.as_ref()
+    }
+}
+
+pub fn lock_while<S: YoetzSuggestion, N: YoetzScore, C: Component>(
+    added: Query<Entity, (Added<C>, With<YoetzAdvisor<S, N>>)>,
+    mut removed: RemovedComponents<C>,
+    mut commands: Commands,
+) {
+    for entity in added.iter() {
+        commands
+            .entity(entity)
+            .insert(YoetzInteractionLock::<S>::full());
+    }
+    for entity in removed.read() {
+        if let Some(mut cmd) = commands.get_entity(entity) {
+            cmd.remove::<YoetzInteractionLock<S>>();
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::advisor::{YoetzStrategy, YoetzSuggestion};
+
+/// Selects a subset of the [`Key`](YoetzSuggestion::Key)s of a [`YoetzSuggestion`].
+///
+/// Used by features that need to affect some of the behaviors but not others - e.g. score
+/// modifiers that only apply to certain variants.
+pub enum YoetzKeyPattern<S: YoetzSuggestion> {
+    /// Match all the keys.
+    Any,
+    /// Match all the keys of the variant with the specified index (see
+    /// [`YoetzSuggestion::variant_index`]).
+    Variant(usize),
+    /// Match only the specified key.
+    Key(S::Key),
+    /// Match the keys for which the predicate returns `true`.
+    Predicate(fn(&S::Key) -> bool),
+}
+
+impl<S: YoetzSuggestion> Clone for YoetzKeyPattern<S> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Any => Self::Any,
+            Self::Variant(index) => Self::Variant(*index),
+            Self::Key(key) => Self::Key(key.clone()),
+            Self::Predicate(predicate) => Self::Predicate(*predicate),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzKeyPattern<S> {
+    /// Match all the keys of the variant that generated the strategy `T`.
+    pub fn variant<T: YoetzStrategy<Suggestion = S>>() -> Self {
+        Self::Variant(T::VARIANT_INDEX)
+    }
+
+    /// Check if the pattern matches the key.
+    pub fn matches(&self, key: &S::Key) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Variant(index) => S::variant_index(key) == *index,
+            Self::Key(pattern_key) => pattern_key == key,
+            Self::Predicate(predicate) => predicate(key),
+        }
+    }
+}
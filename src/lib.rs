@@ -140,32 +140,297 @@
 //!         attacker.attack(attack_behavior.target_to_attack);
 //!     }
 //! }
+//! ```
+//!
+//! # Suggesting and acting in different schedules
+//!
+//! The schedule given to [`YoetzPlugin::new`] is where the advisors think and where the
+//! [`YoetzSystemSet::Act`] systems should run - e.g. `FixedUpdate` for physics-driven actions. If
+//! the suggestion systems need to run in another schedule (e.g. perception systems in `Update`),
+//! use [`YoetzPlugin::suggest_in`] and put them in [`YoetzSystemSet::Suggest`] of that schedule.
+//! The advisors buffer the suggestions across the schedule boundary, so each decision sees the
+//! suggestions of the last run of the suggestion schedule - no matter how many times each
+//! schedule ran in between:
+//!
+//! ```no_run
+//! # use bevy::prelude::*;
+//! # use bevy_yoetz::prelude::*;
+//! # #[derive(YoetzSuggestion, Clone)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+//! # fn perceive_and_suggest() {}
+//! # fn move_bodies() {}
+//! # let mut app = App::new();
+//! app.add_plugins(YoetzPlugin::<AiBehavior>::new(FixedUpdate).suggest_in(Update));
+//! app.add_systems(Update, perceive_and_suggest.in_set(YoetzSystemSet::Suggest));
+//! app.add_systems(FixedUpdate, move_bodies.in_set(YoetzSystemSet::Act));
+//! ```
+//!
+//! To go the other way - staggering expensive suggestion systems over multiple frames and only
+//! deciding once in a while - see [`YoetzPlugin::think_every`].
+//!
+//! # Multiple decision layers
+//!
+//! An entity can have multiple advisors, as long as each one uses a different
+//! [`YoetzSuggestion`] type. Each such type gets its own [`YoetzPlugin`], its own
+//! [`YoetzConfig`](prelude::YoetzConfig) and its own behavior components, so the layers don't
+//! interfere with each other and can be tuned and scheduled independently - for example, a
+//! character can decide where to move and whom to shoot separately:
+//!
+//! ```no_run
+//! # use bevy::prelude::*;
+//! # use bevy_yoetz::prelude::*;
+//! #[derive(YoetzSuggestion)]
+//! enum Movement {
+//!     Idle,
+//!     TakeCover {
+//!         #[yoetz(key)]
+//!         cover: Entity,
+//!     },
+//! }
+//!
+//! #[derive(YoetzSuggestion)]
+//! enum Combat {
+//!     HoldFire,
+//!     Shoot {
+//!         #[yoetz(key)]
+//!         target: Entity,
+//!     },
+//! }
+//!
+//! # let mut app = App::new();
+//! app.add_plugins((
+//!     // Movement decisions are expensive, so they are made less frequently.
+//!     YoetzPlugin::<Movement>::new(FixedUpdate),
+//!     YoetzPlugin::<Combat>::new(Update),
+//! ));
+//!
+//! fn spawn_soldier(mut commands: Commands) {
+//!     commands.spawn((
+//!         YoetzAdvisor::<Movement>::new(2.0),
+//!         YoetzAdvisor::<Combat>::new(0.5),
+//!     ));
+//! }
+//! ```
+//!
+//! Use [`YoetzPluginSet`] to order the layers relative to each other, and
+//! [`YoetzPlugin::nested_in`] if one layer should only think while a behavior of another layer is
+//! active.
+mod active_since;
 mod advisor;
+mod alert;
+mod arbitration;
+mod behavior_resources;
+#[cfg(feature = "yoetz_assets")]
+mod behavior_set;
+mod behavior_tree;
+mod blackboard;
+mod bundle;
+mod cached_score;
+mod cadence;
+pub mod candidates;
+mod census;
+mod commands;
+mod config;
+mod consideration;
+mod crowd;
+#[cfg(feature = "debug_labels")]
+mod debug_label;
+mod diagnostics;
+mod distribution;
+#[cfg(feature = "bevy_egui")]
+mod egui_debug;
+mod faction;
+#[cfg(feature = "debug_gizmos")]
+mod heat_map;
+mod instrumentation;
+mod interaction_lock;
+mod key_pattern;
+mod lifecycle;
+mod modifiers;
+mod morale;
+mod nested;
+mod oscillation;
+mod pathfind;
+mod patrol;
+mod pause;
+mod planner;
+#[cfg(feature = "yoetz_replicon")]
+mod replication;
+mod rethink;
+mod routine;
+mod score;
+#[cfg(feature = "debug_gizmos")]
+mod score_gizmos;
+mod score_history;
+pub mod scorers;
+mod sequence;
+mod shared_evaluation;
+mod simple;
+mod squad;
+mod suggestion_event;
+mod switch_rate;
+mod templates;
+mod think_rate;
+mod transition_gate;
+mod transitions;
 
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 
 use self::advisor::update_advisor;
-use self::prelude::YoetzSuggestion;
+use self::prelude::{
+    YoetzAdvisor, YoetzBehaviorCensus, YoetzBehaviorEnded, YoetzBehaviorStarted, YoetzConfig,
+    YoetzGlobalScoreModifiers, YoetzInteractionLock, YoetzOscillationDetected, YoetzScore,
+    YoetzStarved, YoetzStrategy, YoetzSuggestion, YoetzSuggestionEvent, YoetzTemplates,
+    YoetzThinkCadence,
+};
 
 pub use bevy;
 
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
 pub mod prelude {
     #[doc(inline)]
-    pub use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+    pub use crate::active_since::YoetzActiveSince;
+    #[doc(inline)]
+    pub use crate::advisor::{YoetzAdvisor, YoetzAdvisorSnapshot, YoetzStrategy, YoetzSuggestion};
+    #[doc(inline)]
+    pub use crate::alert::{
+        YoetzAlert, YoetzAlertEffects, YoetzAlertLevel, YoetzAlertLevelChanged, YoetzAlertPlugin,
+    };
+    #[doc(inline)]
+    pub use crate::arbitration::YoetzArbitration;
+    #[doc(inline)]
+    pub use crate::behavior_resources::YoetzBehaviorResources;
+    #[cfg(feature = "yoetz_assets")]
+    #[doc(inline)]
+    pub use crate::behavior_set::{
+        YoetzBehaviorDefinition, YoetzBehaviorSet, YoetzBehaviorSetLoaderError,
+        YoetzBehaviorSetPlugin, YoetzConsiderationDefinition, YoetzCurve, YoetzDataSuggestion,
+    };
+    #[doc(inline)]
+    pub use crate::behavior_tree::{
+        YoetzBehaviorTree, YoetzBehaviorTreeOf, YoetzBehaviorTreePlugin,
+    };
+    #[doc(inline)]
+    pub use crate::blackboard::{YoetzBlackboard, YoetzBlackboardPlugin};
+    #[doc(inline)]
+    pub use crate::bundle::{YoetzBundle, YoetzCommandsExt};
+    #[doc(inline)]
+    pub use crate::cached_score::{YoetzCachedScore, YoetzScoreCachePlugin};
+    #[doc(inline)]
+    pub use crate::cadence::YoetzThinkCadence;
+    #[doc(inline)]
+    pub use crate::census::YoetzBehaviorCensus;
+    #[doc(inline)]
+    pub use crate::commands::YoetzEntityCommandsExt;
+    #[doc(inline)]
+    pub use crate::config::{
+        YoetzAggregation, YoetzConfig, YoetzConsistencyBonusMode, YoetzStarvationPolicy,
+        YoetzTieBreak,
+    };
+    #[doc(inline)]
+    pub use crate::consideration::{YoetzConsideration, YoetzConsiderationMode};
+    #[doc(inline)]
+    pub use crate::crowd::{YoetzCrowdDampening, YoetzCrowdDensity, YoetzCrowdPlugin};
+    #[cfg(feature = "debug_labels")]
+    #[doc(inline)]
+    pub use crate::debug_label::{YoetzDebugLabel, YoetzDebugLabelPlugin, YoetzDebugLabels};
+    #[doc(inline)]
+    pub use crate::diagnostics::{
+        YoetzDiagnostics, YoetzDiagnosticsPlugin, YoetzSuggestionDiagnostics,
+    };
+    #[doc(inline)]
+    pub use crate::distribution::YoetzScoreDistribution;
+    #[cfg(feature = "bevy_egui")]
+    #[doc(inline)]
+    pub use crate::egui_debug::{YoetzEguiDebug, YoetzEguiDebugPlugin};
+    #[doc(inline)]
+    pub use crate::faction::{YoetzFaction, YoetzFactionRelations, YoetzRelation};
+    #[cfg(feature = "debug_gizmos")]
+    #[doc(inline)]
+    pub use crate::heat_map::{YoetzHeatMap, YoetzHeatMapPlane, YoetzHeatMapPlugin};
+    #[doc(inline)]
+    pub use crate::instrumentation::YoetzInstrumentationPlugin;
+    #[doc(inline)]
+    pub use crate::interaction_lock::YoetzInteractionLock;
+    #[doc(inline)]
+    pub use crate::key_pattern::YoetzKeyPattern;
+    #[doc(inline)]
+    pub use crate::lifecycle::{
+        YoetzBehaviorEnded, YoetzBehaviorStarted, YoetzStarved, YoetzTransition,
+    };
+    #[doc(inline)]
+    pub use crate::modifiers::{
+        YoetzGlobalScoreModifiers, YoetzScoreModifier, YoetzScoreModifiers,
+    };
+    #[doc(inline)]
+    pub use crate::morale::{YoetzMorale, YoetzMoraleEffects, YoetzMoralePlugin};
+    #[doc(inline)]
+    pub use crate::oscillation::{YoetzOscillationDamping, YoetzOscillationDetected};
+    #[doc(inline)]
+    pub use crate::pathfind::{
+        YoetzPath, YoetzPathStatus, YoetzPathfindPlugin, YoetzPathfindStrategy, YoetzPathfinder,
+    };
+    #[doc(inline)]
+    pub use crate::patrol::{
+        YoetzPatrolCompleted, YoetzPatrolMode, YoetzPatrolPlugin, YoetzPatrolRoute,
+        YoetzPatrolStep, YoetzWaypointReached,
+    };
+    #[doc(inline)]
+    pub use crate::pause::YoetzPaused;
+    #[doc(inline)]
+    pub use crate::planner::{YoetzFacts, YoetzPlanner, YoetzPlannerPlugin};
+    #[cfg(feature = "yoetz_replicon")]
+    #[doc(inline)]
+    pub use crate::replication::{YoetzReplicatedBehavior, YoetzReplicationPlugin};
+    #[doc(inline)]
+    pub use crate::routine::{YoetzGameClock, YoetzRoutine, YoetzRoutinePlugin, YoetzTimetable};
+    #[doc(inline)]
+    pub use crate::score::YoetzScore;
+    #[cfg(feature = "debug_gizmos")]
     #[doc(inline)]
-    pub use crate::{YoetzPlugin, YoetzSystemSet};
+    pub use crate::score_gizmos::{YoetzScoreGizmos, YoetzScoreGizmosPlugin};
+    #[doc(inline)]
+    pub use crate::score_history::{YoetzScoreHistory, YoetzScoreHistoryFrame};
+    #[doc(inline)]
+    pub use crate::sequence::YoetzSequence;
+    #[doc(inline)]
+    pub use crate::shared_evaluation::{YoetzApproxEq, YoetzSharedEvaluation};
+    #[doc(inline)]
+    pub use crate::simple::{YoetzSimpleBehavior, YoetzSimpleSuggestion};
+    #[doc(inline)]
+    pub use crate::squad::{YoetzSquadAdvisor, YoetzSquadPlugin};
+    #[doc(inline)]
+    pub use crate::suggestion_event::YoetzSuggestionEvent;
+    #[doc(inline)]
+    pub use crate::switch_rate::{YoetzSwitchGroup, YoetzSwitchRateLimit};
+    #[doc(inline)]
+    pub use crate::templates::{YoetzAdvisorTemplate, YoetzTemplates};
+    #[doc(inline)]
+    pub use crate::think_rate::YoetzThinkRate;
+    #[doc(inline)]
+    pub use crate::transition_gate::YoetzTransitionGate;
+    #[doc(inline)]
+    pub use crate::transitions::YoetzTransitions;
+    #[doc(inline)]
+    pub use crate::{YoetzPlugin, YoetzPluginSet, YoetzSystemSet};
 }
 
 /// Add systems for processing a [`YoetzSuggestion`].
-pub struct YoetzPlugin<S: YoetzSuggestion> {
+///
+/// The plugin processes the advisors of `S` whose scores are `N` (see [`YoetzScore`]).
+pub struct YoetzPlugin<S: YoetzSuggestion, N: YoetzScore = f32> {
     schedule: InternedScheduleLabel,
-    _phantom: PhantomData<fn(S)>,
+    extensions: Vec<Box<dyn Fn(&mut App, InternedScheduleLabel) + Send + Sync>>,
+    _phantom: PhantomData<fn(S, N)>,
 }
 
-impl<S: YoetzSuggestion> YoetzPlugin<S> {
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzPlugin<S, N> {
     /// Create a `YoetzPlugin` that cranks the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) in
     /// the given schedule.
     ///
@@ -174,13 +439,266 @@ impl<S: YoetzSuggestion> YoetzPlugin<S> {
     pub fn new(schedule: impl ScheduleLabel) -> Self {
         Self {
             schedule: schedule.intern(),
+            extensions: Vec::new(),
             _phantom: PhantomData,
         }
     }
+
+    /// Gather the suggestions in a different schedule than the one the advisor thinks in.
+    ///
+    /// The suggestion systems should still be put in [`YoetzSystemSet::Suggest`], but in the
+    /// given schedule. The advisors will buffer the suggestions made there:
+    ///
+    /// * Each time the suggestion schedule runs, the suggestions from its previous run are
+    ///   discarded - so running it multiple times before the advisor thinks does not make the same
+    ///   suggestion count multiple times.
+    /// * If the advisor thinks multiple times without the suggestion schedule running in between,
+    ///   it reuses the buffered suggestions instead of seeing no suggestions at all.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion, Clone)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+    /// # let mut app = App::new();
+    /// app.add_plugins(YoetzPlugin::<AiBehavior>::new(FixedUpdate).suggest_in(Update));
+    /// ```
+    pub fn suggest_in(self, schedule: impl ScheduleLabel) -> Self
+    where
+        S: Clone,
+    {
+        let suggest_schedule = schedule.intern();
+        self.extend(move |app, _| {
+            app.add_systems(
+                suggest_schedule,
+                advisor::begin_suggestion_round::<S, N>.before(YoetzSystemSet::Suggest),
+            );
+        })
+    }
+
+    /// Make the advisors think once per `period` instead of on every run of the schedule, with
+    /// the suggestions accumulating between the decisions (see [`YoetzThinkCadence`]).
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     YoetzPlugin::<AiBehavior>::new(Update).think_every(Duration::from_secs_f32(0.25)),
+    /// );
+    /// app.insert_resource(
+    ///     YoetzConfig::<AiBehavior>::default().with_aggregation(YoetzAggregation::Average),
+    /// );
+    /// ```
+    pub fn think_every(self, period: Duration) -> Self {
+        self.extend(move |app, _| {
+            app.insert_resource(YoetzThinkCadence::<S>::new(period));
+        })
+    }
+
+    /// Only run the advisor update (and the other systems of this plugin) when the condition
+    /// holds.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+    /// #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Loading,
+    ///     Playing,
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     YoetzPlugin::<AiBehavior>::new(FixedUpdate).run_if(in_state(GameState::Playing)),
+    /// );
+    /// ```
+    pub fn run_if<M: 'static>(
+        self,
+        condition: impl Condition<M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        self.extend(move |app, schedule| {
+            app.configure_sets(
+                schedule,
+                YoetzPluginSet::<S>::default().run_if(condition.clone()),
+            );
+        })
+    }
+
+    /// Put the advisor update (and the other systems of this plugin) in a system set, so that
+    /// they can be ordered relative to other systems and be configured together with them.
+    pub fn in_set(self, set: impl SystemSet) -> Self {
+        let set = set.intern();
+        self.extend(move |app, schedule| {
+            app.configure_sets(schedule, YoetzPluginSet::<S>::default().in_set(set));
+        })
+    }
+
+    fn extend(
+        mut self,
+        extension: impl 'static + Fn(&mut App, InternedScheduleLabel) + Send + Sync,
+    ) -> Self {
+        self.extensions.push(Box::new(extension));
+        self
+    }
+
+    /// Make advisors re-decide (see
+    /// [`YoetzAdvisor::request_rethink`](crate::advisor::YoetzAdvisor::request_rethink)) whenever
+    /// the component `C` of their entity changes.
+    pub fn rethink_on_change<C: Component>(self) -> Self {
+        self.extend(|app, schedule| {
+            app.add_systems(
+                schedule,
+                rethink::rethink_on_change::<S, N, C>
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
+
+    /// Make advisors re-decide (see
+    /// [`YoetzAdvisor::request_rethink`](crate::advisor::YoetzAdvisor::request_rethink)) whenever
+    /// the component `C` is removed from their entity.
+    pub fn rethink_on_removal<C: Component>(self) -> Self {
+        self.extend(|app, schedule| {
+            app.add_systems(
+                schedule,
+                rethink::rethink_on_removal::<S, N, C>
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
+
+    /// Make advisors re-decide (see
+    /// [`YoetzAdvisor::request_rethink`](crate::advisor::YoetzAdvisor::request_rethink)) whenever
+    /// the component `C` of their entity changes and the condition holds for its new value.
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+    /// #[derive(Component)]
+    /// struct Health {
+    ///     current: f32,
+    ///     max: f32,
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(
+    ///     YoetzPlugin::<AiBehavior>::new(FixedUpdate)
+    ///         .rethink_when(|health: &Health| health.current < 0.3 * health.max),
+    /// );
+    /// ```
+    pub fn rethink_when<C: Component>(self, condition: fn(&C) -> bool) -> Self {
+        self.extend(move |app, schedule| {
+            app.add_systems(
+                schedule,
+                rethink::rethink_when::<S, N, C>(condition)
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
+
+    /// Fully lock advisors (see
+    /// [`YoetzInteractionLock`](crate::interaction_lock::YoetzInteractionLock)) while their
+    /// entity has the component `C`.
+    ///
+    /// This can be used to integrate with dialogue crates, by passing the component they add to
+    /// entities that are in a conversation.
+    pub fn lock_while<C: Component>(self) -> Self {
+        self.extend(|app, schedule| {
+            app.add_systems(
+                schedule,
+                interaction_lock::lock_while::<S, N, C>
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
+
+    /// Nest the advisors in a behavior of another advisor, so that they only think while their
+    /// entity has the parent strategy `P`.
+    ///
+    /// While `P` is not active, the advisors ignore all suggestions, and when it ends their
+    /// active behavior is dropped (and its components removed).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_yoetz::prelude::*;
+    /// #[derive(YoetzSuggestion)]
+    /// enum AiBehavior {
+    ///     Idle,
+    ///     Fight,
+    /// }
+    ///
+    /// #[derive(YoetzSuggestion)]
+    /// enum FightBehavior {
+    ///     Melee,
+    ///     Ranged,
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// app.add_plugins(YoetzPlugin::<AiBehavior>::new(FixedUpdate));
+    /// app.add_plugins(
+    ///     YoetzPlugin::<FightBehavior>::new(FixedUpdate).nested_in::<AiBehaviorFight>(),
+    /// );
+    /// ```
+    ///
+    /// Entities need both a `YoetzAdvisor<AiBehavior>` and a `YoetzAdvisor<FightBehavior>`. Both
+    /// plugins must use the same schedule - the nested advisors think after the systems of the
+    /// parent's plugin.
+    pub fn nested_in<P: YoetzStrategy>(self) -> Self {
+        self.extend(|app, schedule| {
+            app.add_systems(
+                schedule,
+                nested::gate_nested_advisor::<S, N, P>
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .after(YoetzPluginSet::<P::Suggestion>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
+
+    /// Make advisors of entities with a [`YoetzFaction`](crate::faction::YoetzFaction) re-decide
+    /// (see [`YoetzAdvisor::request_rethink`](crate::advisor::YoetzAdvisor::request_rethink))
+    /// whenever the [`YoetzFactionRelations`](crate::faction::YoetzFactionRelations) resource
+    /// changes.
+    pub fn rethink_on_faction_relations_change(self) -> Self {
+        self.extend(|app, schedule| {
+            app.add_systems(
+                schedule,
+                faction::rethink_on_faction_relations_change::<S, N>
+                    .in_set(YoetzInternalSystemSet::Think)
+                    .in_set(YoetzPluginSet::<S>::default())
+                    .before(update_advisor::<S, N>),
+            );
+        })
+    }
 }
 
-impl<S: 'static + YoetzSuggestion> Plugin for YoetzPlugin<S> {
+impl<S: 'static + YoetzSuggestion, N: YoetzScore> Plugin for YoetzPlugin<S, N> {
     fn build(&self, app: &mut App) {
+        app.init_resource::<YoetzConfig<S>>();
+        app.init_resource::<YoetzBehaviorCensus<S>>();
+        app.init_resource::<YoetzTemplates<S>>();
+        app.init_resource::<YoetzGlobalScoreModifiers<S>>();
+        app.add_event::<YoetzOscillationDetected<S>>();
+        app.add_event::<YoetzBehaviorStarted<S>>();
+        app.add_event::<YoetzBehaviorEnded<S>>();
+        app.add_event::<YoetzStarved<S>>();
+        app.add_event::<YoetzSuggestionEvent<S>>();
+        S::register_types(app);
+        app.add_observer(advisor::remove_behavior_on_advisor_removal::<S, N>);
         app.configure_sets(
             self.schedule,
             (
@@ -192,8 +710,20 @@ impl<S: 'static + YoetzSuggestion> Plugin for YoetzPlugin<S> {
         );
         app.add_systems(
             self.schedule,
-            update_advisor::<S>.in_set(YoetzInternalSystemSet::Think),
+            (
+                rethink::rethink_on_removal::<S, N, YoetzInteractionLock<S>>,
+                templates::apply_templates::<S, N>,
+                suggestion_event::apply_suggestion_events::<S, N>,
+                cadence::tick_think_cadence::<S>,
+                update_advisor::<S, N>.run_if(cadence::think_cadence_reached::<S, N>),
+            )
+                .chain()
+                .in_set(YoetzInternalSystemSet::Think)
+                .in_set(YoetzPluginSet::<S>::default()),
         );
+        for extension in self.extensions.iter() {
+            extension(app, self.schedule);
+        }
     }
 }
 
@@ -213,3 +743,55 @@ pub enum YoetzSystemSet {
 pub enum YoetzInternalSystemSet {
     Think,
 }
+
+/// The systems of the [`YoetzPlugin`] of `S`.
+///
+/// When an entity has multiple advisors (see
+/// [Multiple decision layers](crate#multiple-decision-layers)), this set can be used for ordering
+/// their updates relative to each other:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum Movement { VariantSoThatItWontBeEmpty }
+/// # #[derive(YoetzSuggestion)] enum Combat { VariantSoThatItWontBeEmpty }
+/// # let mut app = App::new();
+/// app.configure_sets(
+///     FixedUpdate,
+///     YoetzPluginSet::<Combat>::default().before(YoetzPluginSet::<Movement>::default()),
+/// );
+/// ```
+#[derive(SystemSet)]
+pub struct YoetzPluginSet<S: YoetzSuggestion>(PhantomData<fn(S)>);
+
+impl<S: YoetzSuggestion> Default for YoetzPluginSet<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: YoetzSuggestion> Clone for YoetzPluginSet<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: YoetzSuggestion> Copy for YoetzPluginSet<S> {}
+
+impl<S: YoetzSuggestion> PartialEq for YoetzPluginSet<S> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<S: YoetzSuggestion> Eq for YoetzPluginSet<S> {}
+
+impl<S: YoetzSuggestion> std::hash::Hash for YoetzPluginSet<S> {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+impl<S: YoetzSuggestion> std::fmt::Debug for YoetzPluginSet<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "YoetzPluginSet<{}>", std::any::type_name::<S>())
+    }
+}
@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// Sent when an advisor switches to a new behavior.
+///
+/// When switching from another behavior, this is sent after the [`YoetzBehaviorEnded`] event of
+/// the previous behavior.
+#[derive(Event)]
+pub struct YoetzBehaviorStarted<S: YoetzSuggestion> {
+    /// The entity of the advisor.
+    pub entity: Entity,
+    /// The [`Key`](YoetzSuggestion::Key) of the new behavior.
+    pub key: S::Key,
+}
+
+/// Sent when an advisor stops a behavior, either because it switched to another behavior or
+/// because it dropped it.
+#[derive(Event)]
+pub struct YoetzBehaviorEnded<S: YoetzSuggestion> {
+    /// The entity of the advisor.
+    pub entity: Entity,
+    /// The [`Key`](YoetzSuggestion::Key) of the behavior that ended.
+    pub key: S::Key,
+}
+
+/// Sent when an advisor starts starving - that is, when it did not receive any suggestion for
+/// [`starvation_frames`](crate::config::YoetzConfig::starvation_frames) consecutive thinks - and
+/// its [`starvation_policy`](crate::config::YoetzConfig::starvation_policy) is
+/// [`Report`](crate::config::YoetzStarvationPolicy::Report) (or
+/// [`Fallback`](crate::config::YoetzStarvationPolicy::Fallback) without a
+/// [`fallback`](YoetzSuggestion::fallback)).
+///
+/// This is sent once when the advisor starts starving - not on every frame it keeps starving. Use
+/// [`YoetzAdvisor::is_starving`](crate::advisor::YoetzAdvisor::is_starving) to check if it still
+/// is.
+#[derive(Event)]
+pub struct YoetzStarved<S: YoetzSuggestion> {
+    /// The entity of the advisor.
+    pub entity: Entity,
+    pub(crate) _phantom: PhantomData<fn(S)>,
+}
+
+/// Triggered on the entity of an advisor when its active behavior changes, for reacting to
+/// behavior transitions of specific entities with observers.
+///
+/// Unlike [`YoetzBehaviorStarted`] and [`YoetzBehaviorEnded`], a switch between two behaviors is
+/// a single [`Replaced`](YoetzTransition::Replaced) transition.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// fn spawn_ai(mut commands: Commands) {
+///     commands
+///         .spawn(YoetzAdvisor::<AiBehavior>::new(2.0))
+///         .observe(|trigger: Trigger<YoetzTransition<AiBehavior>>| {
+///             if let YoetzTransition::Removed(_) = trigger.event() {
+///                 info!("{} is idle", trigger.entity());
+///             }
+///         });
+/// }
+/// ```
+#[derive(Event)]
+pub enum YoetzTransition<S: YoetzSuggestion> {
+    /// A behavior became active while no behavior was active.
+    Added(S::Key),
+    /// The active behavior was replaced by another behavior.
+    Replaced {
+        /// The [`Key`](YoetzSuggestion::Key) of the behavior that ended.
+        from: S::Key,
+        /// The [`Key`](YoetzSuggestion::Key) of the new behavior.
+        to: S::Key,
+    },
+    /// The active behavior ended, and no other behavior replaced it.
+    Removed(S::Key),
+}
+
+impl<S: YoetzSuggestion> YoetzTransition<S> {
+    pub(crate) fn new(from: Option<S::Key>, to: Option<S::Key>) -> Option<Self> {
+        match (from, to) {
+            (None, None) => None,
+            (None, Some(to)) => Some(Self::Added(to)),
+            (Some(from), Some(to)) => Some(Self::Replaced { from, to }),
+            (Some(from), None) => Some(Self::Removed(from)),
+        }
+    }
+
+    /// The [`Key`](YoetzSuggestion::Key) of the behavior that ended, if any.
+    pub fn from(&self) -> Option<&S::Key> {
+        match self {
+            Self::Added(_) => None,
+            Self::Replaced { from, .. } | Self::Removed(from) => Some(from),
+        }
+    }
+
+    /// The [`Key`](YoetzSuggestion::Key) of the behavior that started, if any.
+    pub fn to(&self) -> Option<&S::Key> {
+        match self {
+            Self::Added(to) | Self::Replaced { to, .. } => Some(to),
+            Self::Removed(_) => None,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+use crate::key_pattern::YoetzKeyPattern;
+use crate::score::YoetzScore;
+
+/// A change to the score of suggestions, applied when the advisor thinks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum YoetzScoreModifier {
+    /// Multiply the score by a factor.
+    Multiply(f32),
+    /// Add a value to the score.
+    Add(f32),
+    /// Ignore the suggestion entirely.
+    Disable,
+}
+
+/// Modifiers applied to the scores of an entity's suggestions when its
+/// [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) thinks.
+///
+/// Cross-cutting concerns (like crowd density or morale) can use this to affect all the relevant
+/// suggestions without having to change every suggestion system. Each modifier is registered under
+/// a source label, so that its source can replace it later without touching the modifiers of other
+/// sources.
+///
+/// The modifiers are applied, in the order they were inserted, to the aggregated score of each
+/// suggestion whose key they match - before the [score
+/// threshold](crate::advisor::YoetzAdvisor::score_threshold) and the consistency bonus. The
+/// [`YoetzGlobalScoreModifiers`] of all the advisors are applied before them.
+#[derive(Component)]
+pub struct YoetzScoreModifiers<S: YoetzSuggestion> {
+    entries: Vec<(&'static str, YoetzKeyPattern<S>, YoetzScoreModifier)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzScoreModifiers<S> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzScoreModifiers<S> {
+    /// Add a modifier from the specified source.
+    pub fn insert(
+        &mut self,
+        source: &'static str,
+        pattern: YoetzKeyPattern<S>,
+        modifier: YoetzScoreModifier,
+    ) {
+        self.entries.push((source, pattern, modifier));
+    }
+
+    /// Remove all the modifiers from the specified source.
+    pub fn remove_source(&mut self, source: &'static str) {
+        self.entries
+            .retain(|(entry_source, _, _)| *entry_source != source);
+    }
+
+    /// Remove all the modifiers.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Check if there are no modifiers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the modifiers and their sources.
+    pub fn iter(
+        &self,
+    ) -> impl '_ + Iterator<Item = (&'static str, &YoetzKeyPattern<S>, YoetzScoreModifier)> {
+        self.entries
+            .iter()
+            .map(|(source, pattern, modifier)| (*source, pattern, *modifier))
+    }
+
+    /// Apply the matching modifiers to a score.
+    ///
+    /// Returns `None` if the suggestion is disabled.
+    pub fn apply<N: YoetzScore>(&self, key: &S::Key, mut score: N) -> Option<N> {
+        for (_, pattern, modifier) in self.entries.iter() {
+            if !pattern.matches(key) {
+                continue;
+            }
+            match modifier {
+                YoetzScoreModifier::Multiply(factor) => score = score.mul_f32(*factor),
+                YoetzScoreModifier::Add(value) => score = score.add_f32(*value),
+                YoetzScoreModifier::Disable => return None,
+            }
+        }
+        Some(score)
+    }
+}
+
+/// Modifiers applied to the scores of the suggestions of all the advisors of `S`, before the
+/// per-entity [`YoetzScoreModifiers`].
+///
+/// Use this for global effects - difficulty settings, weather, game phases - that should affect
+/// all the agents.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { Attack, Flee }
+/// fn apply_difficulty(mut modifiers: ResMut<YoetzGlobalScoreModifiers<AiBehavior>>) {
+///     modifiers.remove_source("difficulty");
+///     modifiers.insert(
+///         "difficulty",
+///         YoetzKeyPattern::variant::<AiBehaviorAttack>(),
+///         YoetzScoreModifier::Multiply(0.5),
+///     );
+/// }
+/// ```
+#[derive(Resource, Deref, DerefMut)]
+pub struct YoetzGlobalScoreModifiers<S: YoetzSuggestion>(pub YoetzScoreModifiers<S>);
+
+impl<S: YoetzSuggestion> Default for YoetzGlobalScoreModifiers<S> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+/// Update the modifiers of an entity, inserting the component if it does not have it yet.
+pub(crate) fn update_score_modifiers<S: YoetzSuggestion>(
+    entity: Entity,
+    modifiers: Option<Mut<YoetzScoreModifiers<S>>>,
+    commands: &mut Commands,
+    dlg: impl FnOnce(&mut YoetzScoreModifiers<S>),
+) {
+    if let Some(mut modifiers) = modifiers {
+        dlg(&mut modifiers);
+    } else {
+        let mut modifiers = YoetzScoreModifiers::<S>::default();
+        dlg(&mut modifiers);
+        commands.entity(entity).insert(modifiers);
+    }
+}
@@ -0,0 +1,248 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{update_advisor, YoetzSuggestion};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::modifiers::{update_score_modifiers, YoetzScoreModifier, YoetzScoreModifiers};
+use crate::{YoetzInternalSystemSet, YoetzSystemSet};
+
+/// The morale of an AI controlled entity.
+///
+/// Morale is changed by events registered with [`YoetzMoralePlugin::on_event`] (or by calling
+/// [`adjust`](Self::adjust) directly), and decays back to the [`baseline`](Self::baseline) over
+/// time. Use [`YoetzMoraleEffects`] to make it affect the entity's behaviors.
+#[derive(Component, Debug, Clone)]
+pub struct YoetzMorale {
+    /// The current morale.
+    pub value: f32,
+    /// The value the morale decays back to.
+    pub baseline: f32,
+    /// How much the morale moves toward the baseline every second.
+    pub decay_rate: f32,
+}
+
+impl YoetzMorale {
+    /// Create a morale that starts at the baseline and does not decay.
+    pub fn new(baseline: f32) -> Self {
+        Self {
+            value: baseline,
+            baseline,
+            decay_rate: 0.0,
+        }
+    }
+
+    /// Set the [`decay_rate`](Self::decay_rate).
+    pub fn with_decay_rate(mut self, decay_rate: f32) -> Self {
+        self.decay_rate = decay_rate;
+        self
+    }
+
+    /// Change the morale by the specified amount.
+    pub fn adjust(&mut self, delta: f32) {
+        self.value += delta;
+    }
+
+    fn decay(&mut self, delta_secs: f32) {
+        let max_change = self.decay_rate * delta_secs;
+        self.value += (self.baseline - self.value).clamp(-max_change, max_change);
+    }
+}
+
+/// How an entity's [`YoetzMorale`] affects its behaviors.
+///
+/// The [`YoetzMoralePlugin`] translates this into [`YoetzScoreModifiers`] (under the
+/// [`MODIFIER_SOURCE`](Self::MODIFIER_SOURCE) source) right before the advisor thinks, adding the
+/// `YoetzScoreModifiers` component if the entity does not have it.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Attack,
+/// #     Rout,
+/// # }
+/// # let mut commands: Commands = panic!();
+/// commands.spawn((
+///     YoetzAdvisor::<AiBehavior>::new(2.0),
+///     YoetzMorale::new(1.0).with_decay_rate(0.05),
+///     YoetzMoraleEffects::<AiBehavior>::default()
+///         // Low morale makes attacking less desirable.
+///         .with_multiplier(YoetzKeyPattern::variant::<AiBehaviorAttack>(), |morale| {
+///             morale.clamp(0.1, 1.0)
+///         })
+///         // Only rout when the morale is broken.
+///         .with_max_morale(YoetzKeyPattern::variant::<AiBehaviorRout>(), 0.2),
+/// ));
+/// ```
+#[derive(Component)]
+pub struct YoetzMoraleEffects<S: YoetzSuggestion> {
+    effects: Vec<(YoetzKeyPattern<S>, YoetzMoraleEffect)>,
+}
+
+enum YoetzMoraleEffect {
+    Multiplier(fn(f32) -> f32),
+    MinMorale(f32),
+    MaxMorale(f32),
+}
+
+impl<S: YoetzSuggestion> Default for YoetzMoraleEffects<S> {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzMoraleEffects<S> {
+    /// The source label of the modifiers this component generates.
+    pub const MODIFIER_SOURCE: &'static str = "morale";
+
+    /// Multiply the scores of the keys that match the pattern by a factor computed from the
+    /// morale.
+    pub fn with_multiplier(mut self, pattern: YoetzKeyPattern<S>, factor: fn(f32) -> f32) -> Self {
+        self.effects
+            .push((pattern, YoetzMoraleEffect::Multiplier(factor)));
+        self
+    }
+
+    /// Disable the keys that match the pattern while the morale is below the threshold.
+    pub fn with_min_morale(mut self, pattern: YoetzKeyPattern<S>, threshold: f32) -> Self {
+        self.effects
+            .push((pattern, YoetzMoraleEffect::MinMorale(threshold)));
+        self
+    }
+
+    /// Disable the keys that match the pattern while the morale is above the threshold.
+    pub fn with_max_morale(mut self, pattern: YoetzKeyPattern<S>, threshold: f32) -> Self {
+        self.effects
+            .push((pattern, YoetzMoraleEffect::MaxMorale(threshold)));
+        self
+    }
+
+    fn apply_to(&self, morale: &YoetzMorale, modifiers: &mut YoetzScoreModifiers<S>) {
+        modifiers.remove_source(Self::MODIFIER_SOURCE);
+        for (pattern, effect) in self.effects.iter() {
+            let modifier = match effect {
+                YoetzMoraleEffect::Multiplier(factor) => {
+                    YoetzScoreModifier::Multiply(factor(morale.value))
+                }
+                YoetzMoraleEffect::MinMorale(threshold) if morale.value < *threshold => {
+                    YoetzScoreModifier::Disable
+                }
+                YoetzMoraleEffect::MaxMorale(threshold) if *threshold < morale.value => {
+                    YoetzScoreModifier::Disable
+                }
+                _ => continue,
+            };
+            modifiers.insert(Self::MODIFIER_SOURCE, pattern.clone(), modifier);
+        }
+    }
+}
+
+/// Decays the [`YoetzMorale`]s, applies the registered morale events, and applies the
+/// [`YoetzMoraleEffects`] of the advisors of `S`.
+pub struct YoetzMoralePlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    event_handlers: Vec<Box<dyn Fn(&mut App, InternedScheduleLabel) + Send + Sync>>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzMoralePlugin<S> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            event_handlers: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Change the morale of entities when an event is sent.
+    ///
+    /// The handler is called for every entity with [`YoetzMorale`] with every event of type `E`,
+    /// and returns the amount to change that entity's morale by.
+    ///
+    /// Morale is not specific to a suggestion type - when there are multiple
+    /// `YoetzMoralePlugin`s, only register each event handler in one of them.
+    pub fn on_event<E: Event>(mut self, handler: fn(&E, Entity) -> f32) -> Self {
+        self.event_handlers.push(Box::new(move |app, schedule| {
+            app.add_systems(
+                schedule,
+                apply_morale_event(handler)
+                    .after(decay_morale)
+                    .before(YoetzSystemSet::Suggest),
+            );
+        }));
+        self
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzMoralePlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<YoetzMoraleDecayPlugin>() {
+            app.add_plugins(YoetzMoraleDecayPlugin {
+                schedule: self.schedule,
+            });
+        }
+        for event_handler in self.event_handlers.iter() {
+            event_handler(app, self.schedule);
+        }
+        app.add_systems(
+            self.schedule,
+            apply_morale_effects::<S>
+                .in_set(YoetzInternalSystemSet::Think)
+                .before(update_advisor::<S, f32>),
+        );
+    }
+}
+
+/// The part of [`YoetzMoralePlugin`] that does not depend on the suggestion type, so that it only
+/// gets added once.
+struct YoetzMoraleDecayPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl Plugin for YoetzMoraleDecayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, decay_morale.before(YoetzSystemSet::Suggest));
+    }
+}
+
+fn decay_morale(mut query: Query<&mut YoetzMorale>, time: Res<Time>) {
+    let delta_secs = time.delta_secs();
+    for mut morale in query.iter_mut() {
+        morale.decay(delta_secs);
+    }
+}
+
+fn apply_morale_event<E: Event>(
+    handler: fn(&E, Entity) -> f32,
+) -> impl FnMut(EventReader<E>, Query<(Entity, &mut YoetzMorale)>) {
+    move |mut events, mut query| {
+        for event in events.read() {
+            for (entity, mut morale) in query.iter_mut() {
+                morale.adjust(handler(event, entity));
+            }
+        }
+    }
+}
+
+fn apply_morale_effects<S: YoetzSuggestion>(
+    mut query: Query<(
+        Entity,
+        &YoetzMorale,
+        &YoetzMoraleEffects<S>,
+        Option<&mut YoetzScoreModifiers<S>>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, morale, effects, modifiers) in query.iter_mut() {
+        update_score_modifiers(entity, modifiers, &mut commands, |modifiers| {
+            effects.apply_to(morale, modifiers);
+        });
+    }
+}
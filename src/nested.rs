@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzStrategy, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+pub fn gate_nested_advisor<S: YoetzSuggestion, N: YoetzScore, P: YoetzStrategy>(
+    mut query: Query<(&mut YoetzAdvisor<S, N>, Has<P>)>,
+) {
+    for (mut advisor, parent_is_active) in query.iter_mut() {
+        if advisor.is_gated() == parent_is_active {
+            advisor.set_gated(!parent_is_active);
+        }
+    }
+}
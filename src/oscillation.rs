@@ -0,0 +1,118 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// Detects when a [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) keeps flip-flopping between two
+/// behaviors, and optionally damps it.
+///
+/// Add this component next to the advisor. When the advisor alternates between the same two keys
+/// more than [`max_switches`](Self::max_switches) times within [`window`](Self::window), a
+/// [`YoetzOscillationDetected`] event is sent, and for the following
+/// [`damping_duration`](Self::damping_duration) the active behavior gets an extra
+/// [`damping_bonus`](Self::damping_bonus) (on top of the consistency bonus) to help it hold on.
+#[derive(Component)]
+pub struct YoetzOscillationDamping<S: YoetzSuggestion> {
+    /// The number of alternating switches that can happen within [`window`](Self::window) before
+    /// it is considered an oscillation.
+    pub max_switches: usize,
+    /// Only switches that happened within this time are considered.
+    pub window: Duration,
+    /// Extra bonus for the active behavior while damping an oscillation.
+    pub damping_bonus: f32,
+    /// How long to apply the [`damping_bonus`](Self::damping_bonus) after an oscillation was
+    /// detected.
+    pub damping_duration: Duration,
+    switches: VecDeque<(Duration, S::Key)>,
+    damped_until: Option<Duration>,
+}
+
+impl<S: YoetzSuggestion> YoetzOscillationDamping<S> {
+    /// Detect oscillations of more than `max_switches` alternating switches within `window`,
+    /// without damping them.
+    pub fn new(max_switches: usize, window: Duration) -> Self {
+        Self {
+            max_switches,
+            window,
+            damping_bonus: 0.0,
+            damping_duration: Duration::ZERO,
+            switches: VecDeque::new(),
+            damped_until: None,
+        }
+    }
+
+    /// Damp detected oscillations by giving the active behavior an extra bonus for the specified
+    /// duration.
+    pub fn with_damping(mut self, bonus: f32, duration: Duration) -> Self {
+        self.damping_bonus = bonus;
+        self.damping_duration = duration;
+        self
+    }
+
+    /// The time (as in [`Time::elapsed`]) until which the last detected oscillation is damped.
+    pub fn damped_until(&self) -> Option<Duration> {
+        self.damped_until
+    }
+
+    pub(crate) fn bonus(&self, now: Duration) -> f32 {
+        match self.damped_until {
+            Some(damped_until) if now < damped_until => self.damping_bonus,
+            _ => 0.0,
+        }
+    }
+
+    /// Record that the active behavior switched to `key`, and return the two keys and the number
+    /// of switches if that made it an oscillation.
+    pub(crate) fn record_switch(
+        &mut self,
+        now: Duration,
+        key: &S::Key,
+    ) -> Option<([S::Key; 2], usize)> {
+        while let Some((time, _)) = self.switches.front() {
+            if self.window < now.saturating_sub(*time) {
+                self.switches.pop_front();
+            } else {
+                break;
+            }
+        }
+        self.switches.push_back((now, key.clone()));
+
+        // Every switch is to a different key than the one before it, so it's enough to check that
+        // each key is the same as the one two switches before it.
+        let len = self.switches.len();
+        let mut start = len.saturating_sub(2);
+        for i in (2..len).rev() {
+            if self.switches[i].1 == self.switches[i - 2].1 {
+                start = i - 2;
+            } else {
+                break;
+            }
+        }
+        let switches = len - start;
+        if switches <= self.max_switches {
+            return None;
+        }
+        let keys = [
+            self.switches[len - 2].1.clone(),
+            self.switches[len - 1].1.clone(),
+        ];
+        self.switches.clear();
+        self.damped_until = Some(now + self.damping_duration);
+        Some((keys, switches))
+    }
+}
+
+/// Sent when a [`YoetzOscillationDamping`] detects that an advisor is oscillating between two
+/// behaviors.
+#[derive(Event)]
+pub struct YoetzOscillationDetected<S: YoetzSuggestion> {
+    /// The entity of the oscillating advisor.
+    pub entity: Entity,
+    /// The [`Key`](YoetzSuggestion::Key)s of the two behaviors the advisor oscillates between.
+    /// The second one is the currently active behavior.
+    pub keys: [S::Key; 2],
+    /// The number of alternating switches within the detection window.
+    pub switches: usize,
+}
@@ -0,0 +1,249 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy::tasks::futures_lite::future;
+use bevy::tasks::{block_on, AsyncComputeTaskPool, Task};
+
+use crate::advisor::YoetzStrategy;
+use crate::YoetzSystemSet;
+
+/// A navigation backend for the [`YoetzPathfindPlugin`].
+///
+/// Implement this on the resource of your navigation crate (or on a resource wrapping it). The
+/// paths are found in the [`AsyncComputeTaskPool`], so the resource hands a
+/// [`Snapshot`](Self::Snapshot) of the navigation data to each task instead of being borrowed by
+/// it:
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # struct NavMeshData;
+/// # impl NavMeshData {
+/// #     fn path(&self, from: Vec3, to: Vec3) -> Option<Vec<Vec3>> { None }
+/// # }
+/// #[derive(Resource)]
+/// struct MyNavMesh(Arc<NavMeshData>);
+///
+/// impl YoetzPathfinder for MyNavMesh {
+///     type Snapshot = Arc<NavMeshData>;
+///
+///     fn snapshot(&self) -> Self::Snapshot {
+///         self.0.clone()
+///     }
+///
+///     fn find_path(snapshot: &Self::Snapshot, from: Vec3, to: Vec3) -> Option<Vec<Vec3>> {
+///         snapshot.path(from, to)
+///     }
+/// }
+/// ```
+pub trait YoetzPathfinder: Resource {
+    /// The navigation data that is moved into the task that finds a path.
+    type Snapshot: 'static + Send;
+
+    /// Capture the navigation data for a path request.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Find a path between two points. Returns the waypoints (not including `from`), or `None` if
+    /// there is no path.
+    fn find_path(snapshot: &Self::Snapshot, from: Vec3, to: Vec3) -> Option<Vec<Vec3>>;
+}
+
+/// The status of a [`YoetzPath`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzPathStatus {
+    /// The path was not found yet.
+    #[default]
+    Pending,
+    /// The path was found.
+    Found,
+    /// There is no path to the target.
+    NotFound,
+}
+
+/// A path found by the [`YoetzPathfindPlugin`], stored in a `#[yoetz(path_to(<target>))]` field
+/// of a strategy `struct`.
+///
+/// Suggestions should usually set this field to `YoetzPath::default()`. The field is a state
+/// field, so it is not updated from later suggestions of the same behavior.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct YoetzPath {
+    waypoints: Vec<Vec3>,
+    target: Option<Vec3>,
+    status: YoetzPathStatus,
+}
+
+impl YoetzPath {
+    /// The waypoints that were not reached yet.
+    pub fn waypoints(&self) -> &[Vec3] {
+        &self.waypoints
+    }
+
+    /// The target the path was found to - which may be different from the target field of the
+    /// strategy if it changed and the new path was not found yet.
+    pub fn target(&self) -> Option<Vec3> {
+        self.target
+    }
+
+    /// The status of the path.
+    pub fn status(&self) -> YoetzPathStatus {
+        self.status
+    }
+
+    /// The next waypoint to move to from `position`, after dropping the waypoints within
+    /// `arrival_distance` from it. `None` if the path was not found, or if all its waypoints were
+    /// reached.
+    pub fn next_waypoint(&mut self, position: Vec3, arrival_distance: f32) -> Option<Vec3> {
+        let reached = self
+            .waypoints
+            .iter()
+            .take_while(|waypoint| waypoint.distance(position) <= arrival_distance)
+            .count();
+        self.waypoints.drain(..reached);
+        self.waypoints.first().copied()
+    }
+
+    fn set(&mut self, target: Vec3, waypoints: Option<Vec<Vec3>>) {
+        self.target = Some(target);
+        if let Some(waypoints) = waypoints {
+            self.waypoints = waypoints;
+            self.status = YoetzPathStatus::Found;
+        } else {
+            self.waypoints.clear();
+            self.status = YoetzPathStatus::NotFound;
+        }
+    }
+}
+
+/// A strategy `struct` with a [`YoetzPath`] field.
+///
+/// The [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro implements this for
+/// the strategies of variants with a `#[yoetz(path_to(<target>))]` field, where `<target>` is the
+/// name of a [`Vec3`] field of the variant.
+pub trait YoetzPathfindStrategy: YoetzStrategy {
+    /// The position the path should lead to.
+    fn path_target(&self) -> Vec3;
+
+    /// The path field.
+    fn path(&self) -> &YoetzPath;
+
+    /// The path field, for updating it.
+    fn path_mut(&mut self) -> &mut YoetzPath;
+}
+
+/// Finds paths for the strategy `T` using the navigation backend `P`.
+///
+/// When an entity starts enacting `T` - and whenever the target field of `T` changes - the plugin
+/// requests a path from the entity's [`GlobalTransform`] to the target in the background, and
+/// writes it to the path field of `T` once it is found. Action systems only need to follow
+/// [`YoetzPath::next_waypoint`]:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(Resource)] struct MyNavMesh;
+/// # impl YoetzPathfinder for MyNavMesh {
+/// #     type Snapshot = ();
+/// #     fn snapshot(&self) {}
+/// #     fn find_path(_: &(), _: Vec3, _: Vec3) -> Option<Vec<Vec3>> { None }
+/// # }
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Idle,
+///     GoTo {
+///         #[yoetz(input)]
+///         destination: Vec3,
+///         #[yoetz(path_to(destination))]
+///         path: YoetzPath,
+///     },
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzPathfindPlugin::<AiBehaviorGoTo, MyNavMesh>::new(Update));
+///
+/// fn go_to(mut query: Query<(&mut AiBehaviorGoTo, &mut Transform)>, time: Res<Time>) {
+///     for (mut go_to, mut transform) in query.iter_mut() {
+///         if let Some(waypoint) = go_to.path.next_waypoint(transform.translation, 0.1) {
+///             let direction = (waypoint - transform.translation).normalize_or_zero();
+///             transform.translation += direction * 2.0 * time.delta_secs();
+///         }
+///     }
+/// }
+/// ```
+pub struct YoetzPathfindPlugin<T: YoetzPathfindStrategy, P: YoetzPathfinder> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(T, P)>,
+}
+
+impl<T: YoetzPathfindStrategy, P: YoetzPathfinder> YoetzPathfindPlugin<T, P> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: YoetzPathfindStrategy, P: YoetzPathfinder> Plugin for YoetzPathfindPlugin<T, P> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            (receive_paths::<T>, request_paths::<T, P>)
+                .chain()
+                .in_set(YoetzSystemSet::Act),
+        );
+    }
+}
+
+#[derive(Component)]
+struct YoetzPendingPath<T: YoetzPathfindStrategy> {
+    target: Vec3,
+    task: Task<Option<Vec<Vec3>>>,
+    _phantom: PhantomData<fn(T)>,
+}
+
+fn request_paths<T: YoetzPathfindStrategy, P: YoetzPathfinder>(
+    query: Query<(Entity, &T, &GlobalTransform), Without<YoetzPendingPath<T>>>,
+    pathfinder: Option<Res<P>>,
+    mut commands: Commands,
+) {
+    let Some(pathfinder) = pathfinder else {
+        return;
+    };
+    for (entity, strategy, transform) in query.iter() {
+        let target = strategy.path_target();
+        if strategy.path().target() == Some(target) {
+            continue;
+        }
+        let snapshot = pathfinder.snapshot();
+        let from = transform.translation();
+        let task =
+            AsyncComputeTaskPool::get().spawn(async move { P::find_path(&snapshot, from, target) });
+        commands.entity(entity).insert(YoetzPendingPath::<T> {
+            target,
+            task,
+            _phantom: PhantomData,
+        });
+    }
+}
+
+fn receive_paths<T: YoetzPathfindStrategy>(
+    mut query: Query<(Entity, Option<&mut T>, &mut YoetzPendingPath<T>)>,
+    mut commands: Commands,
+) {
+    for (entity, strategy, mut pending) in query.iter_mut() {
+        let Some(mut strategy) = strategy else {
+            // The behavior ended before the path was found. Dropping the task cancels it.
+            commands.entity(entity).remove::<YoetzPendingPath<T>>();
+            continue;
+        };
+        let Some(waypoints) = block_on(future::poll_once(&mut pending.task)) else {
+            continue;
+        };
+        strategy.path_mut().set(pending.target, waypoints);
+        commands.entity(entity).remove::<YoetzPendingPath<T>>();
+    }
+}
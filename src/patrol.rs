@@ -0,0 +1,269 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzStrategy, YoetzSuggestion};
+use crate::YoetzSystemSet;
+
+/// What a [`YoetzPatrolRoute`] does after reaching its last waypoint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YoetzPatrolMode {
+    /// Go back to the first waypoint.
+    #[default]
+    Loop,
+    /// Go through the waypoints in reverse order, and then forward again.
+    PingPong,
+    /// Stop patrolling, and send a [`YoetzPatrolCompleted`] event.
+    Once,
+}
+
+/// A patrol route for an AI controlled entity.
+///
+/// The [`YoetzPatrolPlugin`] suggests patrolling to the current waypoint, and advances the route
+/// when the entity reaches it (and waits there for the waypoint's pause time).
+#[derive(Component, Debug, Clone)]
+pub struct YoetzPatrolRoute {
+    /// The positions to patrol between, and how long to pause at each of them.
+    pub waypoints: Vec<(Vec3, Duration)>,
+    /// What to do after reaching the last waypoint.
+    pub mode: YoetzPatrolMode,
+    /// A waypoint is considered reached when the entity is within this distance from it.
+    pub arrival_distance: f32,
+    current: usize,
+    backwards: bool,
+    pause_left: Option<Duration>,
+    completed: bool,
+}
+
+impl YoetzPatrolRoute {
+    /// Create a looping route without pauses.
+    pub fn new(waypoints: impl IntoIterator<Item = Vec3>) -> Self {
+        Self::with_pauses(
+            waypoints
+                .into_iter()
+                .map(|waypoint| (waypoint, Duration::ZERO)),
+        )
+    }
+
+    /// Create a looping route with a pause time for each waypoint.
+    pub fn with_pauses(waypoints: impl IntoIterator<Item = (Vec3, Duration)>) -> Self {
+        Self {
+            waypoints: waypoints.into_iter().collect(),
+            mode: YoetzPatrolMode::Loop,
+            arrival_distance: 0.5,
+            current: 0,
+            backwards: false,
+            pause_left: None,
+            completed: false,
+        }
+    }
+
+    /// Set the [`mode`](Self::mode).
+    pub fn with_mode(mut self, mode: YoetzPatrolMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the [`arrival_distance`](Self::arrival_distance).
+    pub fn with_arrival_distance(mut self, arrival_distance: f32) -> Self {
+        self.arrival_distance = arrival_distance;
+        self
+    }
+
+    /// The current step of the patrol, or `None` if the route is empty or completed.
+    pub fn current_step(&self) -> Option<YoetzPatrolStep> {
+        if self.completed {
+            return None;
+        }
+        let (waypoint, _) = self.waypoints.get(self.current)?;
+        Some(YoetzPatrolStep {
+            index: self.current,
+            waypoint: *waypoint,
+            pausing: self.pause_left.is_some(),
+        })
+    }
+
+    /// Check if the route was completed (only possible with [`YoetzPatrolMode::Once`]).
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Start the route again from the first waypoint.
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.backwards = false;
+        self.pause_left = None;
+        self.completed = false;
+    }
+
+    fn advance(&mut self) {
+        let last = self.waypoints.len().saturating_sub(1);
+        match self.mode {
+            YoetzPatrolMode::Loop => {
+                self.current = if self.current < last {
+                    self.current + 1
+                } else {
+                    0
+                };
+            }
+            YoetzPatrolMode::PingPong => {
+                if self.backwards && self.current == 0 || !self.backwards && self.current == last {
+                    self.backwards = !self.backwards;
+                }
+                if self.backwards {
+                    self.current = self.current.saturating_sub(1);
+                } else {
+                    self.current = (self.current + 1).min(last);
+                }
+            }
+            YoetzPatrolMode::Once => {
+                if self.current < last {
+                    self.current += 1;
+                } else {
+                    self.completed = true;
+                }
+            }
+        }
+    }
+}
+
+/// The current step of a [`YoetzPatrolRoute`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YoetzPatrolStep {
+    /// The index of the waypoint in the route.
+    pub index: usize,
+    /// The position of the waypoint.
+    pub waypoint: Vec3,
+    /// `true` if the entity already reached the waypoint and is pausing there.
+    pub pausing: bool,
+}
+
+/// Sent when an entity reaches a waypoint of its [`YoetzPatrolRoute`].
+#[derive(Event, Debug, Clone)]
+pub struct YoetzWaypointReached {
+    /// The patrolling entity.
+    pub entity: Entity,
+    /// The index of the waypoint in the route.
+    pub index: usize,
+}
+
+/// Sent when an entity completes a [`YoetzPatrolMode::Once`] route.
+#[derive(Event, Debug, Clone)]
+pub struct YoetzPatrolCompleted {
+    /// The patrolling entity.
+    pub entity: Entity,
+}
+
+/// Suggests patrolling along the [`YoetzPatrolRoute`]s, and advances the routes of entities that
+/// are enacting the patrol strategy `T`.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Patrol {
+///         #[yoetz(input)]
+///         target: Vec3,
+///         #[yoetz(input)]
+///         pausing: bool,
+///     },
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzPatrolPlugin::<AiBehaviorPatrol>::new(
+///     FixedUpdate,
+///     1.0,
+///     |step| AiBehavior::Patrol {
+///         target: step.waypoint,
+///         pausing: step.pausing,
+///     },
+/// ));
+/// ```
+pub struct YoetzPatrolPlugin<T: YoetzStrategy> {
+    schedule: InternedScheduleLabel,
+    score: f32,
+    make_suggestion: fn(YoetzPatrolStep) -> T::Suggestion,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T: YoetzStrategy> YoetzPatrolPlugin<T> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with), and suggests the suggestions created
+    /// by `make_suggestion` with the specified score.
+    ///
+    /// `make_suggestion` must create suggestions of the variant that generated the strategy `T`.
+    pub fn new(
+        schedule: impl ScheduleLabel,
+        score: f32,
+        make_suggestion: fn(YoetzPatrolStep) -> T::Suggestion,
+    ) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            score,
+            make_suggestion,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: YoetzStrategy> Plugin for YoetzPatrolPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<YoetzWaypointReached>();
+        app.add_event::<YoetzPatrolCompleted>();
+        app.add_systems(
+            self.schedule,
+            (
+                suggest_patrol::<T::Suggestion>(self.score, self.make_suggestion)
+                    .in_set(YoetzSystemSet::Suggest),
+                advance_patrol_routes::<T>.after(YoetzSystemSet::Act),
+            ),
+        );
+    }
+}
+
+fn suggest_patrol<S: YoetzSuggestion>(
+    score: f32,
+    make_suggestion: fn(YoetzPatrolStep) -> S,
+) -> impl FnMut(Query<(&mut YoetzAdvisor<S>, &YoetzPatrolRoute)>) {
+    move |mut query| {
+        for (mut advisor, route) in query.iter_mut() {
+            if let Some(step) = route.current_step() {
+                advisor.suggest(score, make_suggestion(step));
+            }
+        }
+    }
+}
+
+fn advance_patrol_routes<T: YoetzStrategy>(
+    mut query: Query<(Entity, &mut YoetzPatrolRoute, &GlobalTransform), With<T>>,
+    time: Res<Time>,
+    mut reached_events: EventWriter<YoetzWaypointReached>,
+    mut completed_events: EventWriter<YoetzPatrolCompleted>,
+) {
+    for (entity, mut route, transform) in query.iter_mut() {
+        let Some(step) = route.current_step() else {
+            continue;
+        };
+        if let Some(pause_left) = route.pause_left {
+            let pause_left = pause_left.saturating_sub(time.delta());
+            if pause_left.is_zero() {
+                route.pause_left = None;
+                route.advance();
+                if route.completed {
+                    completed_events.send(YoetzPatrolCompleted { entity });
+                }
+            } else {
+                route.pause_left = Some(pause_left);
+            }
+        } else if transform.translation().distance(step.waypoint) <= route.arrival_distance {
+            reached_events.send(YoetzWaypointReached {
+                entity,
+                index: step.index,
+            });
+            route.pause_left = Some(route.waypoints[step.index].1);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+/// Pauses all the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor)s of the entity.
+///
+/// Paused advisors ignore all suggestions and do not think. This can be used for stunning or
+/// possessing AI agents without removing their advisors. The variant decides what happens to the
+/// active behavior while paused:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(Component)] struct Stunned;
+/// # let mut app = App::new();
+/// app.add_observer(|trigger: Trigger<OnAdd, Stunned>, mut commands: Commands| {
+///     commands.entity(trigger.entity()).insert(YoetzPaused::Clear);
+/// });
+/// app.add_observer(|trigger: Trigger<OnRemove, Stunned>, mut commands: Commands| {
+///     commands.entity(trigger.entity()).remove::<YoetzPaused>();
+/// });
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum YoetzPaused {
+    /// Keep the active behavior (and its components) as is until the advisor is unpaused.
+    #[default]
+    Freeze,
+    /// Drop the active behavior (and remove its components).
+    Clear,
+}
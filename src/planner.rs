@@ -0,0 +1,253 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{update_advisor, YoetzAdvisor, YoetzSuggestion};
+use crate::{YoetzInternalSystemSet, YoetzPluginSet};
+
+/// Named boolean facts about the world of an agent, for planning with a [`YoetzPlanner`]. Facts
+/// that were never set are `false`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct YoetzFacts(BTreeSet<&'static str>);
+
+impl YoetzFacts {
+    /// Set a fact to `true` or `false`.
+    pub fn set(&mut self, fact: &'static str, value: bool) {
+        if value {
+            self.0.insert(fact);
+        } else {
+            self.0.remove(fact);
+        }
+    }
+
+    /// Check if a fact is `true`.
+    pub fn get(&self, fact: &str) -> bool {
+        self.0.contains(fact)
+    }
+
+    /// Check if all the facts have the given values.
+    pub fn satisfies(&self, facts: &[(&'static str, bool)]) -> bool {
+        facts.iter().all(|(fact, value)| self.get(fact) == *value)
+    }
+
+    /// Set all the facts to the given values.
+    pub fn apply(&mut self, facts: &[(&'static str, bool)]) {
+        for (fact, value) in facts {
+            self.set(fact, *value);
+        }
+    }
+}
+
+/// Plans a sequence of behaviors that reach a goal, and suggests the first one to the entity's
+/// [`YoetzAdvisor`].
+///
+/// The preconditions and the effects of the behaviors come from the `#[yoetz(precondition(...))]`
+/// and `#[yoetz(effect(...))]` attributes of their variants (see
+/// [`YoetzSuggestion::preconditions`] and [`YoetzSuggestion::effects`]). Suggestion systems
+/// [`offer`](Self::offer) the behaviors the planner may use - with a cost instead of a score -
+/// and the [`YoetzPlannerPlugin`] finds the cheapest plan that reaches the [`goal`](Self::goal)
+/// from the current [`facts`](Self::facts). The first step of the plan is suggested with
+/// [`score`](Self::score), so it still competes with the suggestions made directly to the
+/// advisor.
+///
+/// The plan is recalculated every time the advisor thinks, from the behaviors offered since the
+/// last time. Systems that sense the world should keep the facts up to date, so that once a step
+/// is done its effects hold and the planner moves on to the next one.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     #[yoetz(effect(has_ammo))]
+///     Reload,
+///     #[yoetz(precondition(!in_cover), effect(in_cover))]
+///     TakeCover,
+///     #[yoetz(precondition(has_ammo, in_cover), effect(enemy_dead))]
+///     Shoot {
+///         #[yoetz(key)]
+///         target: Entity,
+///     },
+/// }
+///
+/// fn offer_behaviors(mut query: Query<(&mut YoetzPlanner<AiBehavior>, &Ammo, &Cover)>) {
+///     for (mut planner, ammo, cover) in query.iter_mut() {
+///         planner.facts.set("has_ammo", 0 < ammo.0);
+///         planner.facts.set("in_cover", cover.0);
+///         planner.set_goal([("enemy_dead", true)]);
+///         planner.offer(2.0, AiBehavior::Reload);
+///         planner.offer(1.0, AiBehavior::TakeCover);
+///         # let target = Entity::PLACEHOLDER;
+///         planner.offer(1.0, AiBehavior::Shoot { target });
+///     }
+/// }
+/// # #[derive(Component)] struct Ammo(usize);
+/// # #[derive(Component)] struct Cover(bool);
+/// ```
+#[derive(Component)]
+pub struct YoetzPlanner<S: YoetzSuggestion> {
+    /// The current state of the world, as the agent knows it.
+    pub facts: YoetzFacts,
+    /// The facts the plan should reach. When they already hold, nothing is suggested.
+    pub goal: Vec<(&'static str, bool)>,
+    /// The score the first step of the plan is suggested with.
+    pub score: f32,
+    /// Plans with more steps than this are not considered.
+    pub max_plan_length: usize,
+    offers: Vec<(f32, S)>,
+    plan: Vec<S::Key>,
+}
+
+impl<S: YoetzSuggestion> YoetzPlanner<S> {
+    /// Create a planner that suggests the first step of its plans with the given score.
+    pub fn new(score: f32) -> Self {
+        Self {
+            facts: Default::default(),
+            goal: Vec::new(),
+            score,
+            max_plan_length: 8,
+            offers: Vec::new(),
+            plan: Vec::new(),
+        }
+    }
+
+    /// Replace the goal.
+    pub fn set_goal(&mut self, goal: impl IntoIterator<Item = (&'static str, bool)>) {
+        self.goal.clear();
+        self.goal.extend(goal);
+    }
+
+    /// Offer a behavior for the planner to use in the next plan, with the cost of performing it.
+    ///
+    /// Negative costs are treated as zero.
+    pub fn offer(&mut self, cost: f32, suggestion: S) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        self.offers.push((cost.max(0.0), suggestion));
+    }
+
+    /// The keys of the steps of the last plan. Empty if there was no plan (or if the goal already
+    /// held).
+    pub fn plan(&self) -> &[S::Key] {
+        &self.plan
+    }
+
+    /// Find the cheapest plan that reaches the goal, as indices into `keys`.
+    fn find_plan(&self, keys: &[(f32, S::Key)]) -> Option<Vec<usize>> {
+        struct Node {
+            facts: YoetzFacts,
+            /// The node this one was reached from, and the index of the step that reached it.
+            parent: Option<(usize, usize)>,
+            length: usize,
+        }
+
+        let mut nodes = vec![Node {
+            facts: self.facts.clone(),
+            parent: None,
+            length: 0,
+        }];
+        let mut best_costs = HashMap::from([(self.facts.clone(), 0.0)]);
+        // The bits of non-negative floats are ordered like their values.
+        let mut open = BinaryHeap::from([Reverse((0f32.to_bits(), 0))]);
+        while let Some(Reverse((cost, node_index))) = open.pop() {
+            let cost = f32::from_bits(cost);
+            let node = &nodes[node_index];
+            if best_costs[&node.facts] < cost {
+                continue;
+            }
+            if node.facts.satisfies(&self.goal) {
+                let mut plan = Vec::with_capacity(node.length);
+                let mut parent = node.parent;
+                while let Some((node_index, step)) = parent {
+                    plan.push(step);
+                    parent = nodes[node_index].parent;
+                }
+                plan.reverse();
+                return Some(plan);
+            }
+            if self.max_plan_length <= node.length {
+                continue;
+            }
+            let length = node.length + 1;
+            for (step, (step_cost, key)) in keys.iter().enumerate() {
+                let node = &nodes[node_index];
+                if !node.facts.satisfies(S::preconditions(key)) {
+                    continue;
+                }
+                let mut facts = node.facts.clone();
+                facts.apply(S::effects(key));
+                let cost = cost + step_cost;
+                if best_costs
+                    .get(&facts)
+                    .is_some_and(|best_cost| *best_cost <= cost)
+                {
+                    continue;
+                }
+                best_costs.insert(facts.clone(), cost);
+                open.push(Reverse((cost.to_bits(), nodes.len())));
+                nodes.push(Node {
+                    facts,
+                    parent: Some((node_index, step)),
+                    length,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Plans with the [`YoetzPlanner`]s of `S`, and suggests their first steps to the advisors.
+pub struct YoetzPlannerPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzPlannerPlugin<S> {
+    /// Create a plugin that plans in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzPlannerPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            plan_behaviors::<S>
+                .in_set(YoetzInternalSystemSet::Think)
+                .in_set(YoetzPluginSet::<S>::default())
+                .before(update_advisor::<S, f32>),
+        );
+    }
+}
+
+fn plan_behaviors<S: YoetzSuggestion>(
+    mut query: Query<(&mut YoetzPlanner<S>, &mut YoetzAdvisor<S>)>,
+) {
+    for (mut planner, mut advisor) in query.iter_mut() {
+        let mut offers = std::mem::take(&mut planner.offers);
+        let keys = offers
+            .iter()
+            .map(|(cost, suggestion)| (*cost, suggestion.key()))
+            .collect::<Vec<_>>();
+        let plan = planner.find_plan(&keys).unwrap_or_default();
+        planner.plan.clear();
+        planner
+            .plan
+            .extend(plan.iter().map(|step| keys[*step].1.clone()));
+        if let Some(first_step) = plan.first() {
+            let (_, suggestion) = offers.swap_remove(*first_step);
+            advisor.suggest(planner.score, suggestion);
+        }
+        offers.clear();
+        planner.offers = offers;
+    }
+}
@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::advisor::{update_advisor, YoetzAdvisor, YoetzSuggestion};
+use crate::{YoetzInternalSystemSet, YoetzPluginSet};
+
+/// The active behavior of an advisor of `S`, replicated to the clients by the
+/// [`YoetzReplicationPlugin`].
+///
+/// On the server, the plugin keeps this component up to date next to each advisor. On the
+/// clients - which do not run the advisors - presentation code can query it (with [`Ref`] or
+/// [`Changed`] to react to behavior changes):
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # #[yoetz(key_enum(serde))]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Attack,
+/// # }
+/// type ReplicatedBehavior = YoetzReplicatedBehavior<AiBehavior>;
+///
+/// fn play_attack_animations(query: Query<&ReplicatedBehavior, Changed<ReplicatedBehavior>>) {
+///     for behavior in query.iter() {
+///         if let Some(AiBehaviorKey::Attack) = behavior.key() {
+///             // Start the attack animation...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "S::Key: Serialize",
+    deserialize = "S::Key: DeserializeOwned"
+))]
+pub struct YoetzReplicatedBehavior<S: YoetzSuggestion> {
+    key: Option<S::Key>,
+}
+
+impl<S: YoetzSuggestion> YoetzReplicatedBehavior<S> {
+    /// The [`Key`](YoetzSuggestion::Key) of the active behavior.
+    pub fn key(&self) -> Option<&S::Key> {
+        self.key.as_ref()
+    }
+}
+
+/// Replicates the active behaviors of the advisors of `S` to the clients with `bevy_replicon`, as
+/// [`YoetzReplicatedBehavior`] components.
+///
+/// Only the keys of the active behaviors are replicated - not the strategy `struct`s, and not the
+/// suggestions - so the clients do not need to run the suggestion systems. The key `enum` must be
+/// serializable, so the suggestion `enum` should be annotated with `#[yoetz(key_enum(serde))]`
+/// (see the [`YoetzSuggestion`](bevy_yoetz_macros::YoetzSuggestion) derive macro). Entities in
+/// the key fields are sent as they are on the server, without being mapped to the client's
+/// entities.
+///
+/// As with any other replicated component, the entities of the advisors must be marked as
+/// `Replicated` for the components to be sent.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// #[yoetz(key_enum(serde))]
+/// enum AiBehavior {
+///     Idle,
+///     Attack {
+///         #[yoetz(key)]
+///         target: Entity,
+///     },
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins((
+///     YoetzPlugin::<AiBehavior>::new(FixedUpdate),
+///     YoetzReplicationPlugin::<AiBehavior>::new(FixedUpdate),
+/// ));
+/// ```
+pub struct YoetzReplicationPlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzReplicationPlugin<S> {
+    /// Create a plugin that replicates the advisors updated in the given schedule (which should
+    /// be the same schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzReplicationPlugin<S>
+where
+    S::Key: Serialize + DeserializeOwned,
+{
+    fn build(&self, app: &mut App) {
+        app.replicate::<YoetzReplicatedBehavior<S>>();
+        app.add_systems(
+            self.schedule,
+            update_replicated_behaviors::<S>
+                .run_if(server_or_singleplayer)
+                .in_set(YoetzInternalSystemSet::Think)
+                .in_set(YoetzPluginSet::<S>::default())
+                .after(update_advisor::<S, f32>),
+        );
+    }
+}
+
+fn update_replicated_behaviors<S: YoetzSuggestion>(
+    mut query: Query<(
+        Entity,
+        &YoetzAdvisor<S>,
+        Option<&mut YoetzReplicatedBehavior<S>>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, advisor, replicated) in query.iter_mut() {
+        if let Some(mut replicated) = replicated {
+            // Only touch the component when the behavior changes, so that it only gets sent (and
+            // only triggers the clients' change detection) when it needs to.
+            if replicated.key != *advisor.active_key() {
+                replicated.key = advisor.active_key().clone();
+            }
+        } else {
+            commands
+                .entity(entity)
+                .insert(YoetzReplicatedBehavior::<S> {
+                    key: advisor.active_key().clone(),
+                });
+        }
+    }
+}
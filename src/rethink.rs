@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+pub fn rethink_on_change<S: YoetzSuggestion, N: YoetzScore, C: Component>(
+    mut query: Query<&mut YoetzAdvisor<S, N>, Changed<C>>,
+) {
+    for mut advisor in query.iter_mut() {
+        advisor.request_rethink();
+    }
+}
+
+pub fn rethink_on_removal<S: YoetzSuggestion, N: YoetzScore, C: Component>(
+    mut removed: RemovedComponents<C>,
+    mut query: Query<&mut YoetzAdvisor<S, N>>,
+) {
+    for entity in removed.read() {
+        if let Ok(mut advisor) = query.get_mut(entity) {
+            advisor.request_rethink();
+        }
+    }
+}
+
+pub fn rethink_when<S: YoetzSuggestion, N: YoetzScore, C: Component>(
+    condition: fn(&C) -> bool,
+) -> impl FnMut(Query<(&mut YoetzAdvisor<S, N>, &C), Changed<C>>) {
+    move |mut query| {
+        for (mut advisor, component) in query.iter_mut() {
+            if condition(component) {
+                advisor.request_rethink();
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::YoetzSystemSet;
+
+/// The in-game time of day, used by [`YoetzRoutine`]s.
+///
+/// The [`YoetzRoutinePlugin`] adds this resource and advances it every tick.
+#[derive(Resource, Debug, Clone)]
+pub struct YoetzGameClock {
+    /// The current time of day, in hours (`0.0..24.0`).
+    pub hour: f32,
+    /// How many in-game hours pass in every real second. Set to zero to control the clock
+    /// manually.
+    pub hours_per_second: f32,
+}
+
+impl Default for YoetzGameClock {
+    fn default() -> Self {
+        Self {
+            hour: 0.0,
+            hours_per_second: 0.0,
+        }
+    }
+}
+
+impl YoetzGameClock {
+    /// Check if the current time of day is in a window. Windows where `end < start` wrap around
+    /// midnight.
+    pub fn is_within(&self, start: f32, end: f32) -> bool {
+        if start <= end {
+            start <= self.hour && self.hour < end
+        } else {
+            start <= self.hour || self.hour < end
+        }
+    }
+}
+
+/// A daily timetable of baseline suggestions.
+///
+/// Timetables are meant to be shared between many entities (see [`YoetzRoutine`]), and the
+/// suggestions they make are regular suggestions - so reactive behaviors can override them by
+/// suggesting with higher scores.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Sleep,
+/// #     GoTo {
+/// #         #[yoetz(key)]
+/// #         place: Entity,
+/// #     },
+/// # }
+/// # let mut commands: Commands = panic!();
+/// # let market: Entity = panic!();
+/// let timetable = Arc::new(
+///     YoetzTimetable::<AiBehavior>::default()
+///         .with_entry(22.0, 6.0, 1.0, || AiBehavior::Sleep)
+///         .with_entry(9.0, 12.0, 1.0, move || AiBehavior::GoTo { place: market }),
+/// );
+/// commands.spawn((
+///     YoetzAdvisor::<AiBehavior>::new(2.0),
+///     YoetzRoutine(timetable.clone()),
+/// ));
+/// ```
+pub struct YoetzTimetable<S: YoetzSuggestion> {
+    entries: Vec<YoetzTimetableEntry<S>>,
+}
+
+struct YoetzTimetableEntry<S: YoetzSuggestion> {
+    start: f32,
+    end: f32,
+    score: f32,
+    suggestion: Box<dyn Fn() -> S + Send + Sync>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzTimetable<S> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzTimetable<S> {
+    /// Suggest the behavior created by `suggestion` with the specified score between the hours
+    /// `start` and `end`. If `end < start` the window wraps around midnight.
+    pub fn with_entry(
+        mut self,
+        start: f32,
+        end: f32,
+        score: f32,
+        suggestion: impl 'static + Fn() -> S + Send + Sync,
+    ) -> Self {
+        self.entries.push(YoetzTimetableEntry {
+            start,
+            end,
+            score,
+            suggestion: Box::new(suggestion),
+        });
+        self
+    }
+
+    /// Suggest all the entries that are active at the clock's time of day.
+    pub fn suggest_into(&self, clock: &YoetzGameClock, advisor: &mut YoetzAdvisor<S>) {
+        for entry in self.entries.iter() {
+            if clock.is_within(entry.start, entry.end) {
+                advisor.suggest(entry.score, (entry.suggestion)());
+            }
+        }
+    }
+}
+
+/// Makes the [`YoetzRoutinePlugin`] suggest the entries of a [`YoetzTimetable`] to the entity's
+/// advisor.
+#[derive(Component)]
+pub struct YoetzRoutine<S: YoetzSuggestion>(pub Arc<YoetzTimetable<S>>);
+
+/// Advances the [`YoetzGameClock`] and suggests the [`YoetzRoutine`]s of the advisors of `S`.
+pub struct YoetzRoutinePlugin<S: YoetzSuggestion> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzRoutinePlugin<S> {
+    /// Create a plugin that works in the given schedule (which should be the same schedule the
+    /// [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzRoutinePlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<YoetzGameClockPlugin>() {
+            app.add_plugins(YoetzGameClockPlugin {
+                schedule: self.schedule,
+            });
+        }
+        app.add_systems(
+            self.schedule,
+            suggest_routines::<S>.in_set(YoetzSystemSet::Suggest),
+        );
+    }
+}
+
+/// The part of [`YoetzRoutinePlugin`] that does not depend on the suggestion type, so that it only
+/// gets added once.
+struct YoetzGameClockPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl Plugin for YoetzGameClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<YoetzGameClock>();
+        app.add_systems(
+            self.schedule,
+            advance_game_clock.before(YoetzSystemSet::Suggest),
+        );
+    }
+}
+
+fn advance_game_clock(mut clock: ResMut<YoetzGameClock>, time: Res<Time>) {
+    if clock.hours_per_second == 0.0 {
+        return;
+    }
+    let hour = clock.hour + clock.hours_per_second * time.delta_secs();
+    clock.hour = hour.rem_euclid(24.0);
+}
+
+fn suggest_routines<S: YoetzSuggestion>(
+    mut query: Query<(&mut YoetzAdvisor<S>, &YoetzRoutine<S>)>,
+    clock: Res<YoetzGameClock>,
+) {
+    for (mut advisor, routine) in query.iter_mut() {
+        routine.0.suggest_into(&clock, &mut advisor);
+    }
+}
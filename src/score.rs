@@ -0,0 +1,148 @@
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+/// A type the scores of a [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) can be represented by.
+///
+/// Advisors score suggestions with `f32` by default. Games that need bit-exact decisions on all
+/// machines (e.g. for rollback netcode) can use an integer or a fixed-point type instead, by
+/// setting the second generic parameter of the advisor and of the
+/// [`YoetzPlugin`](crate::YoetzPlugin):
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Attack,
+/// # }
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzPlugin::<AiBehavior, i64>::new(FixedUpdate));
+///
+/// fn suggest_attack(mut query: Query<&mut YoetzAdvisor<AiBehavior, i64>>) {
+///     for mut advisor in query.iter_mut() {
+///         advisor.suggest(1500, AiBehavior::Attack);
+///     }
+/// }
+/// ```
+///
+/// The suggested scores are aggregated, compared, penalized, modified, rounded and snapshotted in
+/// the score type - settings that are configured with `f32` values (e.g. the
+/// [`consistency_bonus`](crate::advisor::YoetzAdvisor::consistency_bonus) or the
+/// [`YoetzScoreModifiers`](crate::modifiers::YoetzScoreModifiers)) are applied to scores with
+/// [`add_f32`](Self::add_f32), [`mul_f32`](Self::mul_f32) and [`round_to`](Self::round_to), so
+/// the scores themselves never go through an `f32`. Only reports (e.g. the
+/// [`YoetzDiagnostics`](crate::diagnostics::YoetzDiagnostics)), the
+/// [`YoetzArbitration`](crate::arbitration::YoetzArbitration) policies that are not
+/// [`HighestScore`](crate::arbitration::YoetzArbitration::HighestScore) and
+/// [`YoetzConsistencyBonusMode::Custom`](crate::config::YoetzConsistencyBonusMode::Custom) use
+/// [`to_f32`](Self::to_f32) - so for fully deterministic decisions, avoid these and only use
+/// settings whose values the score type represents exactly.
+///
+/// Besides the [`YoetzThinkRate`](crate::think_rate::YoetzThinkRate) and the
+/// [`YoetzSquadPlugin`](crate::squad::YoetzSquadPlugin), the other plugins of this crate only work
+/// with `f32` advisors.
+pub trait YoetzScore:
+    'static + Send + Sync + Copy + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self>
+{
+    /// The score of nothing.
+    const ZERO: Self;
+
+    /// Convert an `f32` setting to the score type.
+    fn from_f32(value: f32) -> Self;
+
+    /// Convert the score to an `f32`, for reporting it.
+    fn to_f32(self) -> f32;
+
+    /// Add an `f32` setting (e.g. a bonus or a penalty) to the score.
+    fn add_f32(self, value: f32) -> Self;
+
+    /// Multiply the score by an `f32` factor (e.g. for decaying a penalty).
+    fn mul_f32(self, factor: f32) -> Self;
+
+    /// Round the score to the nearest multiple of `resolution`.
+    fn round_to(self, resolution: f32) -> Self;
+
+    /// Divide the score by a number of suggestions (for averaging them).
+    fn average(self, count: usize) -> Self;
+}
+
+macro_rules! impl_float_score {
+    ($($type:ty),*) => {
+        $(
+            #[allow(clippy::unnecessary_cast)]
+            impl YoetzScore for $type {
+                const ZERO: Self = 0.0;
+
+                fn from_f32(value: f32) -> Self {
+                    value as Self
+                }
+
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn add_f32(self, value: f32) -> Self {
+                    self + value as Self
+                }
+
+                fn mul_f32(self, factor: f32) -> Self {
+                    self * factor as Self
+                }
+
+                fn round_to(self, resolution: f32) -> Self {
+                    let resolution = resolution as Self;
+                    (self / resolution).round() * resolution
+                }
+
+                fn average(self, count: usize) -> Self {
+                    self / count as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_float_score!(f32, f64);
+
+macro_rules! impl_integer_score {
+    ($($type:ty),*) => {
+        $(
+            impl YoetzScore for $type {
+                const ZERO: Self = 0;
+
+                fn from_f32(value: f32) -> Self {
+                    value.round() as Self
+                }
+
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn add_f32(self, value: f32) -> Self {
+                    self.saturating_add(Self::from_f32(value))
+                }
+
+                fn mul_f32(self, factor: f32) -> Self {
+                    (self as f64 * factor as f64).round() as Self
+                }
+
+                fn round_to(self, resolution: f32) -> Self {
+                    let resolution = Self::from_f32(resolution);
+                    if resolution <= 1 {
+                        return self;
+                    }
+                    self.saturating_add(resolution / 2)
+                        .div_euclid(resolution)
+                        .saturating_mul(resolution)
+                }
+
+                fn average(self, count: usize) -> Self {
+                    self / count.max(1) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_score!(i32, i64);
@@ -0,0 +1,176 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::diagnostics::{YoetzDiagnostics, YoetzDiagnosticsPlugin};
+
+/// Settings for the overlays the [`YoetzScoreGizmosPlugin`] draws over the advisors of `S`.
+///
+/// Changes to the settings take effect on the next frame, so this resource can also be used to
+/// toggle the overlays at runtime.
+#[derive(Resource)]
+pub struct YoetzScoreGizmos<S: YoetzSuggestion> {
+    /// Draw the overlays.
+    pub visible: bool,
+    /// How many of the highest scoring suggestions get a score bar.
+    pub top: usize,
+    /// The position of the bottom of the first score bar relative to the advisor's entity.
+    pub offset: Vec3,
+    /// The length of a score bar per point of score.
+    pub bar_scale: f32,
+    /// The distance between the score bars.
+    pub bar_spacing: f32,
+    /// The color of the line to the target of the active behavior, and of the frame around its
+    /// score bar.
+    pub active_color: Color,
+    /// The colors of the score bars, indexed by the variant index. Variants without a color here
+    /// get an automatically picked one.
+    pub variant_colors: Vec<Color>,
+    /// Extracts the entity the behavior of a key is directed at (e.g. its target), to draw a line
+    /// to it from the advisor.
+    pub target: Option<fn(&S::Key) -> Option<Entity>>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzScoreGizmos<S> {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            top: 3,
+            offset: Vec3::new(-0.5, 1.5, 0.0),
+            bar_scale: 0.1,
+            bar_spacing: 0.2,
+            active_color: Color::WHITE,
+            variant_colors: Vec::new(),
+            target: None,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzScoreGizmos<S> {
+    /// The color used for a variant.
+    pub fn variant_color(&self, variant_index: usize) -> Color {
+        self.variant_colors
+            .get(variant_index)
+            .copied()
+            .unwrap_or_else(|| {
+                let hue = 360.0 * variant_index as f32 / S::VARIANT_NAMES.len().max(1) as f32;
+                Color::hsl(hue, 0.8, 0.5)
+            })
+    }
+}
+
+/// Draws gizmo overlays over each advisor of `S`: bars with the final scores of the highest
+/// scoring suggestions of its last decision (colored by variant, with a frame around the active
+/// behavior's bar) and a line to the entity the active behavior is directed at.
+///
+/// Gizmos cannot draw text - use the `YoetzDebugLabelPlugin` (with the `debug_labels` feature) to
+/// also show the name of the active behavior.
+///
+/// The overlays are configured with the [`YoetzScoreGizmos`] resource. The plugin also adds the
+/// [`YoetzDiagnosticsPlugin`] of `S`, because the scores are taken from the [`YoetzDiagnostics`].
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Idle,
+///     Chase {
+///         #[yoetz(key)]
+///         target: Entity,
+///     },
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(
+///     YoetzScoreGizmosPlugin::<AiBehavior>::default().with_target(|key| match key {
+///         AiBehaviorKey::Chase { target } => Some(*target),
+///         _ => None,
+///     }),
+/// );
+/// ```
+pub struct YoetzScoreGizmosPlugin<S: YoetzSuggestion> {
+    target: Option<fn(&S::Key) -> Option<Entity>>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzScoreGizmosPlugin<S> {
+    fn default() -> Self {
+        Self {
+            target: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzScoreGizmosPlugin<S> {
+    /// Draw a line from each advisor to the entity its active behavior is directed at, as
+    /// extracted from the key by `target`.
+    pub fn with_target(mut self, target: fn(&S::Key) -> Option<Entity>) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+
+impl<S: YoetzSuggestion> Plugin for YoetzScoreGizmosPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<YoetzDiagnosticsPlugin<S>>() {
+            app.add_plugins(YoetzDiagnosticsPlugin::<S>::default());
+        }
+        app.insert_resource(YoetzScoreGizmos::<S> {
+            target: self.target,
+            ..Default::default()
+        });
+        app.add_systems(Update, draw_score_gizmos::<S>);
+    }
+}
+
+fn draw_score_gizmos<S: YoetzSuggestion>(
+    settings: Res<YoetzScoreGizmos<S>>,
+    query: Query<(&YoetzAdvisor<S>, &YoetzDiagnostics<S>, &GlobalTransform)>,
+    transforms_query: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+) {
+    if !settings.visible {
+        return;
+    }
+    for (advisor, diagnostics, transform) in query.iter() {
+        let position = transform.translation();
+        let active_key = advisor.active_key().as_ref();
+
+        let mut scores = diagnostics
+            .suggestions()
+            .iter()
+            .filter_map(|suggestion| Some((&suggestion.key, suggestion.final_score?)))
+            .collect::<Vec<_>>();
+        scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        for (i, (key, score)) in scores.into_iter().take(settings.top).enumerate() {
+            let bottom = position + settings.offset + Vec3::X * settings.bar_spacing * i as f32;
+            let top = bottom + Vec3::Y * settings.bar_scale * score.max(0.0);
+            gizmos.line(bottom, top, settings.variant_color(S::variant_index(key)));
+            if Some(key) == active_key {
+                let half_width = Vec3::X * settings.bar_spacing * 0.4;
+                gizmos.linestrip(
+                    [
+                        bottom - half_width,
+                        top - half_width,
+                        top + half_width,
+                        bottom + half_width,
+                        bottom - half_width,
+                    ],
+                    settings.active_color,
+                );
+            }
+        }
+
+        let target = settings
+            .target
+            .zip(active_key)
+            .and_then(|(target, active_key)| target(active_key))
+            .and_then(|target| transforms_query.get(target).ok());
+        if let Some(target) = target {
+            gizmos.line(position, target.translation(), settings.active_color);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+use crate::key_pattern::YoetzKeyPattern;
+
+/// The winners and the near-misses of the last decisions of a
+/// [`YoetzAdvisor`](crate::advisor::YoetzAdvisor).
+///
+/// Add this component next to the advisor to have a frame recorded every time the advisor thinks.
+/// Once there are [`capacity`](Self::capacity) frames, the oldest ones are dropped.
+///
+/// Gameplay code can use it to check what the agent was considering recently, and the frames can
+/// be dumped for offline analysis of the AI.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Flee,
+/// # }
+/// # #[derive(Component)] struct Panting;
+/// fn show_nervousness(
+///     query: Query<(Entity, &YoetzScoreHistory<AiBehavior>)>,
+///     mut commands: Commands,
+/// ) {
+///     for (entity, history) in query.iter() {
+///         if history.considered(&YoetzKeyPattern::variant::<AiBehaviorFlee>()) {
+///             commands.entity(entity).insert(Panting);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzScoreHistory<S: YoetzSuggestion> {
+    /// How many frames to keep.
+    pub capacity: usize,
+    /// How many of the suggestions that lost each decision to keep in its frame.
+    pub near_misses: usize,
+    frames: VecDeque<YoetzScoreHistoryFrame<S::Key>>,
+}
+
+/// A decision recorded in a [`YoetzScoreHistory`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YoetzScoreHistoryFrame<K> {
+    /// The elapsed time when the advisor made the decision.
+    pub time: Duration,
+    /// The key and the score (including the consistency bonus) of the winning suggestion - or
+    /// `None` if the advisor has no active behavior, or kept a behavior that was not suggested.
+    pub winner: Option<(K, f32)>,
+    /// The keys and the scores (including the consistency bonus) of the highest scoring
+    /// suggestions that lost the decision, from the highest score down.
+    pub near_misses: Vec<(K, f32)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzScoreHistory<S> {
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzScoreHistory<S> {
+    /// Create an empty history that keeps the given number of frames, with up to 3 near-misses
+    /// in each.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            near_misses: 3,
+            frames: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Set how many near-misses to keep in each frame.
+    pub fn with_near_misses(mut self, near_misses: usize) -> Self {
+        self.near_misses = near_misses;
+        self
+    }
+
+    /// The recorded frames, from the oldest to the newest.
+    pub fn frames(&self) -> impl '_ + DoubleEndedIterator<Item = &YoetzScoreHistoryFrame<S::Key>> {
+        self.frames.iter()
+    }
+
+    /// The frame of the last decision.
+    pub fn last(&self) -> Option<&YoetzScoreHistoryFrame<S::Key>> {
+        self.frames.back()
+    }
+
+    /// Remove all the frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Check if a suggestion that matches the pattern won or nearly won any of the recorded
+    /// decisions.
+    pub fn considered(&self, pattern: &YoetzKeyPattern<S>) -> bool {
+        self.last_considered(pattern).is_some()
+    }
+
+    /// The newest frame in which a suggestion that matches the pattern won or nearly won, and its
+    /// score in that frame.
+    pub fn last_considered(
+        &self,
+        pattern: &YoetzKeyPattern<S>,
+    ) -> Option<(&YoetzScoreHistoryFrame<S::Key>, f32)> {
+        self.frames.iter().rev().find_map(|frame| {
+            let (_, score) = frame
+                .winner
+                .iter()
+                .chain(frame.near_misses.iter())
+                .find(|(key, _)| pattern.matches(key))?;
+            Some((frame, *score))
+        })
+    }
+
+    /// Check if a suggestion that matches the pattern won any of the recorded decisions.
+    pub fn won(&self, pattern: &YoetzKeyPattern<S>) -> bool {
+        self.frames.iter().any(|frame| {
+            frame
+                .winner
+                .as_ref()
+                .is_some_and(|(key, _)| pattern.matches(key))
+        })
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        time: Duration,
+        mut scores: Vec<(S::Key, f32)>,
+        active_key: Option<&S::Key>,
+    ) {
+        let winner = active_key.and_then(|active_key| {
+            let index = scores.iter().position(|(key, _)| key == active_key)?;
+            Some(scores.swap_remove(index))
+        });
+        scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        scores.truncate(self.near_misses);
+        while self.capacity <= self.frames.len() && !self.frames.is_empty() {
+            self.frames.pop_front();
+        }
+        if 0 < self.capacity {
+            self.frames.push_back(YoetzScoreHistoryFrame {
+                time,
+                winner,
+                near_misses: scores,
+            });
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Building blocks for computing suggestion scores.
+//!
+//! All the scorers are plain functions that return `f32`s - most of them in the `0.0..=1.0` range
+//! - so they can be multiplied together and scaled to build the final score.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Map a value from one range to another, clamping it to the target range.
+///
+/// ```
+/// # use bevy_yoetz::scorers::remap;
+/// assert_eq!(remap(5.0, 0.0, 10.0, 100.0, 200.0), 150.0);
+/// assert_eq!(remap(20.0, 0.0, 10.0, 100.0, 200.0), 200.0);
+/// // Reversed ranges work too.
+/// assert_eq!(remap(2.5, 0.0, 10.0, 1.0, 0.0), 0.75);
+/// ```
+pub fn remap(value: f32, from_start: f32, from_end: f32, to_start: f32, to_end: f32) -> f32 {
+    if from_start == from_end {
+        return if value < from_start { to_start } else { to_end };
+    }
+    let t = ((value - from_start) / (from_end - from_start)).clamp(0.0, 1.0);
+    to_start + t * (to_end - to_start)
+}
+
+/// `1.0` at distance zero, decreasing linearly to `0.0` at `max_distance` and beyond.
+///
+/// ```
+/// # use bevy_yoetz::scorers::linear_falloff;
+/// assert_eq!(linear_falloff(0.0, 10.0), 1.0);
+/// assert_eq!(linear_falloff(2.5, 10.0), 0.75);
+/// assert_eq!(linear_falloff(15.0, 10.0), 0.0);
+/// ```
+pub fn linear_falloff(distance: f32, max_distance: f32) -> f32 {
+    remap(distance, 0.0, max_distance, 1.0, 0.0)
+}
+
+/// `1.0` at distance zero, `0.5` at `half_distance`, and approaching `0.0` as the distance grows
+/// (without ever reaching it).
+///
+/// ```
+/// # use bevy_yoetz::scorers::inverse_square_falloff;
+/// assert_eq!(inverse_square_falloff(0.0, 5.0), 1.0);
+/// assert_eq!(inverse_square_falloff(5.0, 5.0), 0.5);
+/// assert_eq!(inverse_square_falloff(15.0, 5.0), 0.1);
+/// ```
+pub fn inverse_square_falloff(distance: f32, half_distance: f32) -> f32 {
+    let ratio = distance / half_distance;
+    1.0 / (1.0 + ratio * ratio)
+}
+
+/// How much `forward` points toward `direction`: `1.0` when they point the same way, `0.5` when
+/// they are perpendicular, and `0.0` when they point in opposite directions.
+///
+/// Returns `0.0` if either vector is zero.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::scorers::facing;
+/// assert_eq!(facing(Vec3::X, Vec3::X * 5.0), 1.0);
+/// assert_eq!(facing(Vec3::X, Vec3::Y), 0.5);
+/// assert_eq!(facing(Vec3::X, Vec3::NEG_X), 0.0);
+/// ```
+pub fn facing(forward: Vec3, direction: Vec3) -> f32 {
+    let (Some(forward), Some(direction)) = (forward.try_normalize(), direction.try_normalize())
+    else {
+        return 0.0;
+    };
+    (forward.dot(direction).clamp(-1.0, 1.0) + 1.0) * 0.5
+}
+
+/// How much an entity with the transform faces a target position (see [`facing`]).
+pub fn angle_to_facing(transform: &GlobalTransform, target: Vec3) -> f32 {
+    facing(*transform.forward(), target - transform.translation())
+}
+
+/// `current / max`, clamped to `0.0..=1.0`. Useful for things like health fraction.
+///
+/// Returns `0.0` if `max` is not positive.
+///
+/// ```
+/// # use bevy_yoetz::scorers::fraction;
+/// assert_eq!(fraction(30.0, 120.0), 0.25);
+/// assert_eq!(fraction(150.0, 120.0), 1.0);
+/// assert_eq!(fraction(30.0, 0.0), 0.0);
+/// ```
+pub fn fraction(current: f32, max: f32) -> f32 {
+    if max <= 0.0 {
+        return 0.0;
+    }
+    (current / max).clamp(0.0, 1.0)
+}
+
+/// `0.0` right after `since`, increasing linearly to `1.0` once `full_after` has passed.
+///
+/// Both `now` and `since` are times as in [`Time::elapsed`].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_yoetz::scorers::time_since;
+/// let since = Duration::from_secs(10);
+/// let full_after = Duration::from_secs(4);
+/// assert_eq!(time_since(Duration::from_secs(11), since, full_after), 0.25);
+/// assert_eq!(time_since(Duration::from_secs(20), since, full_after), 1.0);
+/// // Times before `since` count as no time at all.
+/// assert_eq!(time_since(Duration::from_secs(5), since, full_after), 0.0);
+/// ```
+pub fn time_since(now: Duration, since: Duration, full_after: Duration) -> f32 {
+    let elapsed = now.saturating_sub(since);
+    if full_after.is_zero() {
+        return 1.0;
+    }
+    (elapsed.as_secs_f32() / full_after.as_secs_f32()).min(1.0)
+}
+
+/// The logistic (S-shaped) curve: `0.5` at the midpoint, approaching `1.0` above it and `0.0`
+/// below it. The steepness controls how sharp the transition is - negative steepness flips the
+/// curve.
+///
+/// ```
+/// # use bevy_yoetz::scorers::logistic;
+/// assert_eq!(logistic(3.0, 3.0, 10.0), 0.5);
+/// assert!(0.99 < logistic(4.0, 3.0, 10.0));
+/// assert!(logistic(2.0, 3.0, 10.0) < 0.01);
+/// ```
+pub fn logistic(value: f32, midpoint: f32, steepness: f32) -> f32 {
+    1.0 / (1.0 + (-steepness * (value - midpoint)).exp())
+}
+
+/// The index of the bucket the value falls in, given the (ascending) thresholds between the
+/// buckets. Values below the first threshold are in bucket `0`, and values at or above the last
+/// threshold are in bucket `thresholds.len()`.
+///
+/// ```
+/// # use bevy_yoetz::scorers::bucket;
+/// let thresholds = [10.0, 20.0, 30.0];
+/// assert_eq!(bucket(5.0, &thresholds), 0);
+/// assert_eq!(bucket(10.0, &thresholds), 1);
+/// assert_eq!(bucket(25.0, &thresholds), 2);
+/// assert_eq!(bucket(100.0, &thresholds), 3);
+/// ```
+pub fn bucket(value: f32, thresholds: &[f32]) -> usize {
+    thresholds.partition_point(|threshold| *threshold <= value)
+}
+
+/// The score of the bucket the value falls in. `buckets` is a list of `(threshold, score)`
+/// pairs sorted by threshold - the score of the last bucket whose threshold is not above the value
+/// is used, and values below all the thresholds get `below_all`.
+///
+/// ```
+/// # use bevy_yoetz::scorers::bucketed;
+/// let buckets = [(0.25, 1.0), (0.5, 3.0), (0.75, 5.0)];
+/// assert_eq!(bucketed(0.1, &buckets, 0.0), 0.0);
+/// assert_eq!(bucketed(0.6, &buckets, 0.0), 3.0);
+/// assert_eq!(bucketed(0.9, &buckets, 0.0), 5.0);
+/// ```
+pub fn bucketed(value: f32, buckets: &[(f32, f32)], below_all: f32) -> f32 {
+    match buckets.partition_point(|(threshold, _)| *threshold <= value) {
+        0 => below_all,
+        index => buckets[index - 1].1,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::advisor::YoetzSuggestion;
+
+/// A fixed chain of behaviors that is suggested as one, and performed in order.
+///
+/// Suggest it with
+/// [`YoetzAdvisor::suggest_sequence`](crate::advisor::YoetzAdvisor::suggest_sequence) every
+/// frame, like any other suggestion. Only the current step is suggested - when the action
+/// systems report that it is done (with
+/// [`YoetzAdvisor::complete`](crate::advisor::YoetzAdvisor::complete)), the advisor advances to
+/// the next step. If another suggestion wins in the middle of the sequence, or if a step
+/// [fails](crate::advisor::YoetzAdvisor::fail), the sequence is abandoned and the next time it is
+/// suggested it starts over.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     GoToCover,
+/// #     Reload,
+/// #     Peek,
+/// # }
+/// fn suggest_reload(mut query: Query<&mut YoetzAdvisor<AiBehavior>>) {
+///     for mut advisor in query.iter_mut() {
+///         advisor.suggest_sequence(
+///             5.0,
+///             YoetzSequence::new()
+///                 .then(AiBehavior::GoToCover)
+///                 .then(AiBehavior::Reload)
+///                 .then(AiBehavior::Peek),
+///         );
+///     }
+/// }
+/// ```
+pub struct YoetzSequence<S: YoetzSuggestion> {
+    pub(crate) steps: Vec<S>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzSequence<S> {
+    fn default() -> Self {
+        Self { steps: Vec::new() }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzSequence<S> {
+    /// Create an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step to the end of the sequence.
+    pub fn then(mut self, step: S) -> Self {
+        self.steps.push(step);
+        self
+    }
+}
@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+
+/// Values that can be compared with some tolerance.
+///
+/// Used by [`YoetzSharedEvaluation`] to decide if two agents have equivalent inputs.
+pub trait YoetzApproxEq {
+    /// Check if the two values are equal, up to the given tolerance.
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool;
+}
+
+impl YoetzApproxEq for f32 {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        (self - other).abs() <= tolerance
+    }
+}
+
+impl YoetzApproxEq for Vec2 {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        self.distance_squared(*other) <= tolerance * tolerance
+    }
+}
+
+impl YoetzApproxEq for Vec3 {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        self.distance_squared(*other) <= tolerance * tolerance
+    }
+}
+
+macro_rules! impl_exact_approx_eq {
+    ($($type:ty),*) => {
+        $(
+            impl YoetzApproxEq for $type {
+                fn approx_eq(&self, other: &Self, _tolerance: f32) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_approx_eq!(
+    (),
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    Entity
+);
+
+impl<T: YoetzApproxEq> YoetzApproxEq for Option<T> {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        match (self, other) {
+            (None, None) => true,
+            (Some(this), Some(other)) => this.approx_eq(other, tolerance),
+            _ => false,
+        }
+    }
+}
+
+impl<A: YoetzApproxEq, B: YoetzApproxEq> YoetzApproxEq for (A, B) {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        self.0.approx_eq(&other.0, tolerance) && self.1.approx_eq(&other.1, tolerance)
+    }
+}
+
+impl<A: YoetzApproxEq, B: YoetzApproxEq, C: YoetzApproxEq> YoetzApproxEq for (A, B, C) {
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        self.0.approx_eq(&other.0, tolerance)
+            && self.1.approx_eq(&other.1, tolerance)
+            && self.2.approx_eq(&other.2, tolerance)
+    }
+}
+
+/// Evaluate suggestions once for a group of agents that share a species and have equivalent
+/// inputs, and reuse the result for all of them.
+///
+/// The species `T` is compared exactly, and the inputs `I` are compared using
+/// [`YoetzApproxEq`] with the [`tolerance`](Self::tolerance). Keep it in a [`Local`] and
+/// [`clear`](Self::clear) it at the beginning of each run of the suggestion system:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion, Clone)]
+/// # enum AiBehavior {
+/// #     Chase {
+/// #         #[yoetz(key)]
+/// #         target: Entity,
+/// #     },
+/// # }
+/// # #[derive(Component, PartialEq, Clone, Copy)] enum Species { Zombie }
+/// # #[derive(Component)] struct Player;
+/// # fn expensive_scoring(_position: Vec3, _player: Entity) -> f32 { 0.0 }
+/// fn suggest_chase(
+///     mut query: Query<(&mut YoetzAdvisor<AiBehavior>, &Species, &GlobalTransform)>,
+///     player_query: Query<Entity, With<Player>>,
+///     mut shared: Local<YoetzSharedEvaluation<Species, Vec3, Vec<(f32, AiBehavior)>>>,
+/// ) {
+///     shared.clear();
+///     shared.tolerance = 0.5;
+///     for (mut advisor, species, transform) in query.iter_mut() {
+///         let suggestions = shared.get_or_evaluate(*species, transform.translation(), |position| {
+///             player_query
+///                 .iter()
+///                 .map(|player| {
+///                     (
+///                         expensive_scoring(*position, player),
+///                         AiBehavior::Chase { target: player },
+///                     )
+///                 })
+///                 .collect()
+///         });
+///         for (score, suggestion) in suggestions.iter() {
+///             advisor.suggest(*score, suggestion.clone());
+///         }
+///     }
+/// }
+/// ```
+pub struct YoetzSharedEvaluation<T: PartialEq, I: YoetzApproxEq, R> {
+    /// Inputs within this tolerance of each other are considered equivalent.
+    pub tolerance: f32,
+    entries: Vec<(T, I, R)>,
+}
+
+impl<T: PartialEq, I: YoetzApproxEq, R> Default for YoetzSharedEvaluation<T, I, R> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<T: PartialEq, I: YoetzApproxEq, R> YoetzSharedEvaluation<T, I, R> {
+    /// Create an empty shared evaluation with the given tolerance.
+    pub fn new(tolerance: f32) -> Self {
+        Self {
+            tolerance,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Get the result evaluated for an equivalent agent, or evaluate it if there was none.
+    pub fn get_or_evaluate(&mut self, species: T, inputs: I, evaluate: impl FnOnce(&I) -> R) -> &R {
+        let tolerance = self.tolerance;
+        let index = if let Some(index) =
+            self.entries
+                .iter()
+                .position(|(entry_species, entry_inputs, _)| {
+                    *entry_species == species && entry_inputs.approx_eq(&inputs, tolerance)
+                }) {
+            index
+        } else {
+            let result = evaluate(&inputs);
+            self.entries.push((species, inputs, result));
+            self.entries.len() - 1
+        };
+        &self.entries[index].2
+    }
+
+    /// Forget all the evaluated results.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The number of evaluations that were actually performed since the last
+    /// [`clear`](Self::clear).
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no evaluations were performed since the last [`clear`](Self::clear).
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::ecs::query::WorldQuery;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// A [`YoetzSuggestion`] implementation that does not require the derive macro, for behaviors
+/// that are only known at runtime (e.g. loaded from data files) or for crates that cannot use
+/// procedural macros.
+///
+/// The key `K` identifies the behavior, and the data `D` is passed to the action systems in the
+/// [`YoetzSimpleBehavior`] component. The data is always updated from the suggestion (like the
+/// input fields of the derive macro), and the component is re-created when the key changes.
+///
+/// All the behaviors are considered to be of the same variant, so per-variant features (e.g.
+/// [`YoetzAdvisor::fail_with_penalty`](crate::advisor::YoetzAdvisor::fail_with_penalty) or
+/// [`YoetzBehaviorCensus`](crate::census::YoetzBehaviorCensus) per variant) treat them as one.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// type DataDrivenBehavior = YoetzSimpleSuggestion<String, f32>;
+///
+/// # let mut app = App::new();
+/// app.add_plugins(YoetzPlugin::<DataDrivenBehavior>::new(FixedUpdate));
+///
+/// fn suggest_from_data(mut query: Query<&mut YoetzAdvisor<DataDrivenBehavior>>) {
+///     for mut advisor in query.iter_mut() {
+///         advisor.suggest(1.0, YoetzSimpleSuggestion::new("patrol".to_owned(), 2.5));
+///     }
+/// }
+///
+/// fn perform_behaviors(query: Query<&YoetzSimpleBehavior<String, f32>>) {
+///     for behavior in query.iter() {
+///         info!("Performing {} with speed {}", behavior.key, behavior.data);
+///     }
+/// }
+/// ```
+pub struct YoetzSimpleSuggestion<K, D = ()> {
+    /// Identifies the behavior.
+    pub key: K,
+    /// The data of the behavior.
+    pub data: D,
+}
+
+impl<K, D> YoetzSimpleSuggestion<K, D> {
+    /// Create a suggestion for the behavior identified by `key`.
+    pub fn new(key: K, data: D) -> Self {
+        Self { key, data }
+    }
+}
+
+/// The behavior component of a [`YoetzSimpleSuggestion`].
+#[derive(Component)]
+pub struct YoetzSimpleBehavior<K: 'static + Send + Sync, D: 'static + Send + Sync = ()> {
+    /// Identifies the behavior.
+    pub key: K,
+    /// The data of the behavior, updated from the suggestions.
+    pub data: D,
+}
+
+impl<K, D> YoetzSuggestion for YoetzSimpleSuggestion<K, D>
+where
+    K: 'static + Send + Sync + Clone + PartialEq,
+    D: 'static + Send + Sync,
+{
+    type Key = K;
+    type OmniQuery = Option<&'static mut YoetzSimpleBehavior<K, D>>;
+
+    const VARIANT_NAMES: &'static [&'static str] = &["YoetzSimpleSuggestion"];
+
+    fn key(&self) -> Self::Key {
+        self.key.clone()
+    }
+
+    fn variant_index(_key: &Self::Key) -> usize {
+        0
+    }
+
+    fn remove_components(_key: &Self::Key, cmd: &mut EntityCommands) {
+        cmd.remove::<YoetzSimpleBehavior<K, D>>();
+    }
+
+    fn add_components(self, cmd: &mut EntityCommands) {
+        cmd.insert(YoetzSimpleBehavior {
+            key: self.key,
+            data: self.data,
+        });
+    }
+
+    fn update_into_components(
+        self,
+        components: &mut <Self::OmniQuery as WorldQuery>::Item<'_>,
+    ) -> Result<(), Self> {
+        if let Some(behavior) = components.as_mut() {
+            behavior.data = self.data;
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    fn replace_into_components(
+        self,
+        components: &mut <Self::OmniQuery as WorldQuery>::Item<'_>,
+    ) -> Result<(), Self> {
+        if let Some(behavior) = components.as_mut() {
+            behavior.key = self.key;
+            behavior.data = self.data;
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
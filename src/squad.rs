@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use crate::advisor::{update_advisor, YoetzAdvisor, YoetzSuggestion};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::score::YoetzScore;
+use crate::{YoetzInternalSystemSet, YoetzPluginSet};
+
+/// Assigns behaviors to the members of a squad, while limiting how many members may perform each
+/// behavior (e.g. at most two attackers and one flanker).
+///
+/// Put this on a squad entity, and have the suggestion systems [`suggest`](Self::suggest) the
+/// behaviors of the members to it instead of to the members' own [`YoetzAdvisor`]s. The
+/// [`YoetzSquadPlugin`] assigns the suggestions from the highest score down - skipping members
+/// that were already assigned and suggestions that would exceed a [limit](Self::with_limit) - and
+/// forwards each assigned suggestion to the advisor of its member. The members' advisors then
+/// insert the strategy components on the members as usual.
+///
+/// The squad advisor, the plugin and the members' advisors share the score type `N` (defaulting
+/// to `f32`).
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// #[derive(YoetzSuggestion)]
+/// enum AiBehavior {
+///     Attack {
+///         #[yoetz(key)]
+///         target: Entity,
+///     },
+///     Flank,
+///     Cover,
+/// }
+///
+/// fn spawn_squad(mut commands: Commands) {
+///     commands.spawn(
+///         YoetzSquadAdvisor::<AiBehavior>::new(1.0)
+///             .with_limit(YoetzKeyPattern::variant::<AiBehaviorAttack>(), 2)
+///             .with_limit(YoetzKeyPattern::variant::<AiBehaviorFlank>(), 1),
+///     );
+/// }
+///
+/// fn suggest_for_squad(
+///     mut squads_query: Query<(&mut YoetzSquadAdvisor<AiBehavior>, &Children)>,
+/// ) {
+///     for (mut squad, members) in squads_query.iter_mut() {
+///         for member in members.iter() {
+///             # let (target, distance) = (Entity::PLACEHOLDER, 0.0);
+///             squad.suggest(*member, 10.0 - distance, AiBehavior::Attack { target });
+///             squad.suggest(*member, 5.0, AiBehavior::Flank);
+///             squad.suggest(*member, 1.0, AiBehavior::Cover);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzSquadAdvisor<S: YoetzSuggestion, N: YoetzScore = f32> {
+    /// Added to the score of a suggestion when its member is already performing it, so that
+    /// members don't swap behaviors between them when their scores are close.
+    pub consistency_bonus: f32,
+    limits: Vec<(YoetzKeyPattern<S>, usize)>,
+    suggestions: Vec<(Entity, N, S)>,
+    assignments: Vec<(Entity, S::Key)>,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzSquadAdvisor<S, N> {
+    /// Create a squad advisor with the specified [`consistency_bonus`](Self::consistency_bonus).
+    pub fn new(consistency_bonus: f32) -> Self {
+        Self {
+            consistency_bonus,
+            limits: Vec::new(),
+            suggestions: Vec::new(),
+            assignments: Vec::new(),
+        }
+    }
+
+    /// Limit the number of members that may be assigned behaviors that match the pattern.
+    pub fn with_limit(mut self, pattern: YoetzKeyPattern<S>, max_members: usize) -> Self {
+        self.set_limit(pattern, max_members);
+        self
+    }
+
+    /// Limit the number of members that may be assigned behaviors that match the pattern.
+    pub fn set_limit(&mut self, pattern: YoetzKeyPattern<S>, max_members: usize) {
+        self.limits.push((pattern, max_members));
+    }
+
+    /// Remove all the limits.
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    /// Suggest a behavior for a member of the squad.
+    ///
+    /// Like [`YoetzAdvisor::suggest`], this should be called every frame as long as the
+    /// suggestion is valid.
+    pub fn suggest(&mut self, member: Entity, score: N, suggestion: S) {
+        if suggestion.is_skipped() {
+            return;
+        }
+        self.suggestions.push((member, score, suggestion));
+    }
+
+    /// The members that were assigned behaviors in the last assignment, and the keys of their
+    /// behaviors.
+    pub fn assignments(&self) -> &[(Entity, S::Key)] {
+        &self.assignments
+    }
+
+    /// The key of the behavior the member was assigned in the last assignment.
+    pub fn assignment(&self, member: Entity) -> Option<&S::Key> {
+        self.assignments
+            .iter()
+            .find(|(assigned, _)| *assigned == member)
+            .map(|(_, key)| key)
+    }
+
+    fn within_limits(&self, key: &S::Key, counts: &[usize]) -> bool {
+        self.limits
+            .iter()
+            .zip(counts)
+            .all(|((pattern, max_members), count)| !pattern.matches(key) || count < max_members)
+    }
+}
+
+/// Assigns the behaviors of the [`YoetzSquadAdvisor`]s of `S` to their members.
+pub struct YoetzSquadPlugin<S: YoetzSuggestion, N: YoetzScore = f32> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn(S, N)>,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzSquadPlugin<S, N> {
+    /// Create a plugin that assigns the behaviors in the given schedule (which should be the same
+    /// schedule the [`YoetzPlugin`](crate::YoetzPlugin) was created with).
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> Plugin for YoetzSquadPlugin<S, N> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            assign_squad_behaviors::<S, N>
+                .in_set(YoetzInternalSystemSet::Think)
+                .in_set(YoetzPluginSet::<S>::default())
+                .before(update_advisor::<S, N>),
+        );
+    }
+}
+
+fn assign_squad_behaviors<S: YoetzSuggestion, N: YoetzScore>(
+    mut squads_query: Query<&mut YoetzSquadAdvisor<S, N>>,
+    mut members_query: Query<&mut YoetzAdvisor<S, N>>,
+) {
+    for mut squad in squads_query.iter_mut() {
+        let mut suggestions = std::mem::take(&mut squad.suggestions)
+            .into_iter()
+            .filter_map(|(member, score, suggestion)| {
+                let advisor = members_query.get(member).ok()?;
+                let key = suggestion.key();
+                let priority = if advisor.active_key().as_ref() == Some(&key) {
+                    score.add_f32(squad.consistency_bonus)
+                } else {
+                    score
+                };
+                Some((priority, member, score, key, suggestion))
+            })
+            .collect::<Vec<_>>();
+        suggestions.sort_by(|(a, ..), (b, ..)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+        let mut counts = vec![0; squad.limits.len()];
+        squad.assignments.clear();
+        for (_, member, score, key, suggestion) in suggestions {
+            if squad.assignment(member).is_some() || !squad.within_limits(&key, &counts) {
+                continue;
+            }
+            for ((pattern, _), count) in squad.limits.iter().zip(counts.iter_mut()) {
+                if pattern.matches(&key) {
+                    *count += 1;
+                }
+            }
+            if let Ok(mut advisor) = members_query.get_mut(member) {
+                advisor.suggest(score, suggestion);
+            }
+            squad.assignments.push((member, key));
+        }
+    }
+}
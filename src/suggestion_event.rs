@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::score::YoetzScore;
+
+/// A suggestion for the [`YoetzAdvisor`] of a specific entity, for suggestion systems that don't
+/// want to access the advisors directly.
+///
+/// Systems that send these events instead of calling [`YoetzAdvisor::suggest`] don't need
+/// `&mut YoetzAdvisor<S>`, so they don't conflict with the other systems that access the
+/// advisors. The [`YoetzPlugin`](crate::YoetzPlugin) delivers the events to the advisors right
+/// before they think. Events for entities that don't have a `YoetzAdvisor<S>` are discarded.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { Flee }
+/// # #[derive(Component)] struct Health(f32);
+/// fn suggest_fleeing(
+///     query: Query<(Entity, &Health)>,
+///     mut suggestions: EventWriter<YoetzSuggestionEvent<AiBehavior>>,
+/// ) {
+///     for (entity, health) in query.iter() {
+///         suggestions.send(YoetzSuggestionEvent {
+///             entity,
+///             score: 10.0 - health.0,
+///             suggestion: AiBehavior::Flee,
+///         });
+///     }
+/// }
+/// ```
+#[derive(Event)]
+pub struct YoetzSuggestionEvent<S: YoetzSuggestion> {
+    /// The entity of the advisor.
+    pub entity: Entity,
+    /// The score of the suggestion (see [`YoetzAdvisor::suggest`]), which is converted to the
+    /// score type of the advisor (see [`YoetzScore::from_f32`]).
+    pub score: f32,
+    /// The suggested behavior.
+    pub suggestion: S,
+}
+
+pub(crate) fn apply_suggestion_events<S: YoetzSuggestion, N: YoetzScore>(
+    mut events: ResMut<Events<YoetzSuggestionEvent<S>>>,
+    mut query: Query<&mut YoetzAdvisor<S, N>>,
+) {
+    for YoetzSuggestionEvent {
+        entity,
+        score,
+        suggestion,
+    } in events.drain()
+    {
+        if let Ok(mut advisor) = query.get_mut(entity) {
+            advisor.suggest(N::from_f32(score), suggestion);
+        }
+    }
+}
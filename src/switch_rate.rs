@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::advisor::YoetzSuggestion;
+
+/// A group of advisors that share a [`YoetzSwitchRateLimit`] - e.g. a squad or a faction.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+pub struct YoetzSwitchGroup(pub u32);
+
+/// Limits how many advisors of each [`YoetzSwitchGroup`] may change their active behavior in a
+/// single tick.
+///
+/// Advisors that would exceed the limit keep their active behavior and defer the switch to a
+/// later tick. This prevents whole groups from snapping to a new behavior in the exact same frame
+/// (which looks robotic and can cause frame spikes). Advisors without a `YoetzSwitchGroup` are
+/// not limited.
+///
+/// This resource is not added by default - insert it to enable the limiting:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// # let mut app = App::new();
+/// app.insert_resource(YoetzSwitchRateLimit::<AiBehavior>::new(3));
+/// ```
+#[derive(Resource)]
+pub struct YoetzSwitchRateLimit<S: YoetzSuggestion> {
+    /// The number of switches allowed per tick in groups that don't have a specific limit.
+    pub default_limit: usize,
+    /// Limits for specific groups.
+    pub group_limits: HashMap<YoetzSwitchGroup, usize>,
+    switches: HashMap<YoetzSwitchGroup, usize>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion> YoetzSwitchRateLimit<S> {
+    /// Allow `default_limit` switches per tick in each group.
+    pub fn new(default_limit: usize) -> Self {
+        Self {
+            default_limit,
+            group_limits: Default::default(),
+            switches: Default::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set a limit for a specific group.
+    pub fn with_group_limit(mut self, group: YoetzSwitchGroup, limit: usize) -> Self {
+        self.group_limits.insert(group, limit);
+        self
+    }
+
+    /// The limit of a group.
+    pub fn limit(&self, group: YoetzSwitchGroup) -> usize {
+        self.group_limits
+            .get(&group)
+            .copied()
+            .unwrap_or(self.default_limit)
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.switches.clear();
+    }
+
+    pub(crate) fn may_switch(&self, group: YoetzSwitchGroup) -> bool {
+        self.switches.get(&group).copied().unwrap_or(0) < self.limit(group)
+    }
+
+    pub(crate) fn count_switch(&mut self, group: YoetzSwitchGroup) {
+        *self.switches.entry(group).or_insert(0) += 1;
+    }
+}
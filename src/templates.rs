@@ -0,0 +1,223 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::advisor::{YoetzAdvisor, YoetzSuggestion};
+use crate::config::{
+    YoetzAggregation, YoetzConsistencyBonusMode, YoetzStarvationPolicy, YoetzTieBreak,
+};
+use crate::key_pattern::YoetzKeyPattern;
+use crate::modifiers::{update_score_modifiers, YoetzScoreModifier, YoetzScoreModifiers};
+use crate::score::YoetzScore;
+
+/// Settings shared by all the advisors created with
+/// [`YoetzAdvisor::from_template`](crate::advisor::YoetzAdvisor::from_template) with the same
+/// name.
+///
+/// Settings that are `None` are not overridden by the template (so they are either taken from the
+/// [`YoetzConfig`](crate::config::YoetzConfig) or set on the advisor itself).
+pub struct YoetzAdvisorTemplate<S: YoetzSuggestion> {
+    /// See [`YoetzAdvisor::consistency_bonus`].
+    pub consistency_bonus: Option<f32>,
+    /// See [`YoetzAdvisor::consistency_bonus_mode`].
+    pub consistency_bonus_mode: Option<YoetzConsistencyBonusMode>,
+    /// See [`YoetzAdvisor::score_threshold`].
+    pub score_threshold: Option<f32>,
+    /// See [`YoetzAdvisor::tie_break`].
+    pub tie_break: Option<YoetzTieBreak>,
+    /// See [`YoetzAdvisor::aggregation`].
+    pub aggregation: Option<YoetzAggregation>,
+    /// See [`YoetzAdvisor::warmup`].
+    pub warmup: Option<Duration>,
+    /// See [`YoetzAdvisor::switch_after_wins`].
+    pub switch_after_wins: Option<u32>,
+    /// See [`YoetzAdvisor::starvation_policy`].
+    pub starvation_policy: Option<YoetzStarvationPolicy>,
+    /// See [`YoetzAdvisor::starvation_frames`].
+    pub starvation_frames: Option<u32>,
+    /// See [`YoetzAdvisor::score_resolution`].
+    pub score_resolution: Option<f32>,
+    /// Score modifiers (weights and disabled variants) applied to the instances, under the
+    /// [`MODIFIER_SOURCE`](Self::MODIFIER_SOURCE) source.
+    pub score_modifiers: Vec<(YoetzKeyPattern<S>, YoetzScoreModifier)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzAdvisorTemplate<S> {
+    fn default() -> Self {
+        Self {
+            consistency_bonus: None,
+            consistency_bonus_mode: None,
+            score_threshold: None,
+            tie_break: None,
+            aggregation: None,
+            warmup: None,
+            switch_after_wins: None,
+            starvation_policy: None,
+            starvation_frames: None,
+            score_resolution: None,
+            score_modifiers: Vec::new(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzAdvisorTemplate<S> {
+    /// The source label of the modifiers templates generate.
+    pub const MODIFIER_SOURCE: &'static str = "template";
+
+    /// Modify the template's settings.
+    pub fn with(mut self, dlg: impl FnOnce(&mut Self)) -> Self {
+        dlg(&mut self);
+        self
+    }
+
+    /// Multiply the scores of the keys that match the pattern.
+    pub fn with_weight(mut self, pattern: YoetzKeyPattern<S>, weight: f32) -> Self {
+        self.score_modifiers
+            .push((pattern, YoetzScoreModifier::Multiply(weight)));
+        self
+    }
+
+    /// Disable the keys that match the pattern.
+    pub fn with_disabled(mut self, pattern: YoetzKeyPattern<S>) -> Self {
+        self.score_modifiers
+            .push((pattern, YoetzScoreModifier::Disable));
+        self
+    }
+
+    fn apply_to<N: YoetzScore>(
+        &self,
+        advisor: &mut YoetzAdvisor<S, N>,
+        modifiers: &mut YoetzScoreModifiers<S>,
+    ) {
+        macro_rules! apply_settings {
+            ($($setting:ident),*) => {
+                $(
+                    if self.$setting.is_some() {
+                        advisor.$setting = self.$setting;
+                    }
+                )*
+            };
+        }
+        apply_settings!(
+            consistency_bonus,
+            consistency_bonus_mode,
+            score_threshold,
+            tie_break,
+            aggregation,
+            warmup,
+            switch_after_wins,
+            starvation_policy,
+            starvation_frames,
+            score_resolution
+        );
+        modifiers.remove_source(Self::MODIFIER_SOURCE);
+        for (pattern, modifier) in self.score_modifiers.iter() {
+            modifiers.insert(Self::MODIFIER_SOURCE, pattern.clone(), *modifier);
+        }
+    }
+}
+
+/// Named [`YoetzAdvisorTemplate`]s for the advisors of `S`.
+///
+/// [`YoetzPlugin`](crate::YoetzPlugin) adds this resource, and applies the templates to advisors
+/// created with [`YoetzAdvisor::from_template`] right before they first think. Changes to a
+/// template are only applied to its existing instances after calling
+/// [`propagate`](Self::propagate).
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Attack,
+/// #     Flee,
+/// # }
+/// fn setup_templates(mut templates: ResMut<YoetzTemplates<AiBehavior>>) {
+///     templates.insert(
+///         "grunt",
+///         YoetzAdvisorTemplate::default()
+///             .with(|template| template.consistency_bonus = Some(2.0))
+///             .with_disabled(YoetzKeyPattern::variant::<AiBehaviorFlee>()),
+///     );
+/// }
+///
+/// fn spawn_grunt(mut commands: Commands) {
+///     commands.spawn(YoetzAdvisor::<AiBehavior>::from_template("grunt"));
+/// }
+/// ```
+#[derive(Resource)]
+pub struct YoetzTemplates<S: YoetzSuggestion> {
+    templates: HashMap<String, (YoetzAdvisorTemplate<S>, u64)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzTemplates<S> {
+    fn default() -> Self {
+        Self {
+            templates: Default::default(),
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzTemplates<S> {
+    /// Add or replace a template.
+    ///
+    /// Replacing a template propagates it to its existing instances.
+    pub fn insert(&mut self, name: impl Into<String>, template: YoetzAdvisorTemplate<S>) {
+        let name = name.into();
+        let generation = self
+            .templates
+            .get(&name)
+            .map_or(0, |(_, generation)| generation + 1);
+        self.templates.insert(name, (template, generation));
+    }
+
+    /// Get a template.
+    pub fn get(&self, name: &str) -> Option<&YoetzAdvisorTemplate<S>> {
+        Some(&self.templates.get(name)?.0)
+    }
+
+    /// Get a template for editing. Call [`propagate`](Self::propagate) to apply the changes to
+    /// the existing instances.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut YoetzAdvisorTemplate<S>> {
+        Some(&mut self.templates.get_mut(name)?.0)
+    }
+
+    /// Re-apply a template to all its existing instances.
+    pub fn propagate(&mut self, name: &str) {
+        if let Some((_, generation)) = self.templates.get_mut(name) {
+            *generation += 1;
+        }
+    }
+
+    fn get_with_generation(&self, name: &str) -> Option<(&YoetzAdvisorTemplate<S>, u64)> {
+        let (template, generation) = self.templates.get(name)?;
+        Some((template, *generation))
+    }
+}
+
+pub fn apply_templates<S: YoetzSuggestion, N: YoetzScore>(
+    mut query: Query<(
+        Entity,
+        &mut YoetzAdvisor<S, N>,
+        Option<&mut YoetzScoreModifiers<S>>,
+    )>,
+    templates: Res<YoetzTemplates<S>>,
+    mut commands: Commands,
+) {
+    for (entity, mut advisor, modifiers) in query.iter_mut() {
+        let Some((name, applied_generation)) = advisor.template() else {
+            continue;
+        };
+        let Some((template, generation)) = templates.get_with_generation(name) else {
+            continue;
+        };
+        if applied_generation == Some(generation) {
+            continue;
+        }
+        update_score_modifiers(entity, modifiers, &mut commands, |modifiers| {
+            template.apply_to(&mut advisor, modifiers);
+        });
+        advisor.set_applied_template_generation(generation);
+    }
+}
@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+use crate::score::YoetzScore;
+
+/// Limits how often the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) of `S` on the same entity
+/// re-decides.
+///
+/// Between the times the timer finishes, the advisor keeps its active behavior (still updating
+/// its input fields when it is suggested) and ignores all other suggestions - unless one of them
+/// reaches the [`urgent_threshold`](Self::urgent_threshold), or a rethink was requested (see
+/// [`YoetzAdvisor::request_rethink`](crate::advisor::YoetzAdvisor::request_rethink)), in which
+/// case it re-decides immediately and the timer restarts. An advisor without an active behavior,
+/// or whose active behavior was dropped, always re-decides.
+///
+/// Use this for background NPCs that don't need to react every frame. Like the advisor, the think
+/// rate has a score type parameter `N` (defaulting to `f32`), which must match the advisor's.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { VariantSoThatItWontBeEmpty }
+/// fn spawn_villager(mut commands: Commands) {
+///     commands.spawn((
+///         YoetzAdvisor::<AiBehavior>::new(1.0),
+///         YoetzThinkRate::<AiBehavior>::new(Duration::from_secs(2)).with_urgent_threshold(50.0),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzThinkRate<S: YoetzSuggestion, N: YoetzScore = f32> {
+    /// The advisor re-decides every time this timer finishes.
+    pub timer: Timer,
+    /// Suggestions with final scores (including the modifiers and the bonuses) of at least this
+    /// much make the advisor re-decide immediately. `None` means there is no urgent threshold.
+    pub urgent_threshold: Option<N>,
+    _phantom: PhantomData<fn(S)>,
+}
+
+impl<S: YoetzSuggestion, N: YoetzScore> YoetzThinkRate<S, N> {
+    /// Make the advisor re-decide once per period, with no urgent threshold.
+    pub fn new(period: Duration) -> Self {
+        Self {
+            timer: Timer::new(period, TimerMode::Repeating),
+            urgent_threshold: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set the [`urgent_threshold`](Self::urgent_threshold).
+    pub fn with_urgent_threshold(mut self, urgent_threshold: N) -> Self {
+        self.urgent_threshold = Some(urgent_threshold);
+        self
+    }
+}
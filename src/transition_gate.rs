@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+
+/// Makes the [`YoetzAdvisor`](crate::advisor::YoetzAdvisor) of `S` wait for the action systems to
+/// acknowledge a switch to another behavior before performing it.
+///
+/// When the advisor decides to switch, it keeps the active behavior (and its components) and marks
+/// the new behavior as [`pending`](Self::pending) instead. The action systems of the active
+/// behavior can then play an exit animation or release resources, and call
+/// [`acknowledge`](Self::acknowledge) when they are done. The next time the advisor thinks, it
+/// switches to the winning suggestion - which is usually the pending one, but may be another one
+/// if the suggestions changed in the meantime. If the active behavior wins again before the switch
+/// is acknowledged, the pending transition is cancelled.
+///
+/// Only switching from one behavior to another waits for the acknowledgment - dropping the active
+/// behavior (when there are no suggestions, or when the advisor is paused) is immediate.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)] enum AiBehavior { Aim, Run }
+/// # #[derive(Component)] struct LowerWeaponAnimation { done: bool }
+/// fn lower_weapon_before_running(
+///     mut query: Query<(
+///         &mut YoetzTransitionGate<AiBehavior>,
+///         &mut LowerWeaponAnimation,
+///         &AiBehaviorAim,
+///     )>,
+/// ) {
+///     for (mut gate, mut animation, _) in query.iter_mut() {
+///         if gate.pending().is_none() {
+///             continue;
+///         }
+///         if animation.done {
+///             gate.acknowledge();
+///         }
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct YoetzTransitionGate<S: YoetzSuggestion> {
+    pending: Option<S::Key>,
+    acknowledged: bool,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzTransitionGate<S> {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            acknowledged: false,
+        }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzTransitionGate<S> {
+    /// The key of the behavior the advisor wants to switch to, if there is a switch waiting for
+    /// acknowledgment.
+    pub fn pending(&self) -> Option<&S::Key> {
+        self.pending.as_ref()
+    }
+
+    /// Allow the advisor to perform the pending switch the next time it thinks.
+    pub fn acknowledge(&mut self) {
+        self.acknowledged = true;
+    }
+
+    /// Check if the switch was acknowledged.
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged
+    }
+
+    /// Returns `true` if the advisor must hold on to the active behavior instead of switching to
+    /// `switch_to`.
+    pub(crate) fn hold(&mut self, switch_to: &S::Key) -> bool {
+        if self.acknowledged {
+            self.cancel();
+            return false;
+        }
+        if self.pending.as_ref() != Some(switch_to) {
+            self.pending = Some(switch_to.clone());
+        }
+        true
+    }
+
+    pub(crate) fn cancel(&mut self) {
+        if self.pending.is_some() || self.acknowledged {
+            self.pending = None;
+            self.acknowledged = false;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::advisor::YoetzSuggestion;
+use crate::key_pattern::YoetzKeyPattern;
+
+enum YoetzTransitionRule {
+    Forbid,
+    AllowOnly,
+    Penalty(f32),
+}
+
+/// A table of constraints on the transitions the advisors of `S` can make from one behavior to
+/// another.
+///
+/// When an advisor has an active behavior, the suggestions it is forbidden to switch to are
+/// disabled, and the suggestions it is penalized for switching to have the penalty subtracted
+/// from their scores - so the best allowed suggestion wins instead. The rules are matched by
+/// [`YoetzKeyPattern`]s, and a suggestion is disabled if any forbidding rule matches it. The rules
+/// do not apply when there is no active behavior, and do not affect the active behavior itself.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_yoetz::prelude::*;
+/// # #[derive(YoetzSuggestion)]
+/// # enum AiBehavior {
+/// #     Idle,
+/// #     Stunned,
+/// #     Recover,
+/// #     Attack,
+/// #     Flee,
+/// # }
+/// # let mut app = App::new();
+/// app.insert_resource(
+///     YoetzTransitions::<AiBehavior>::default()
+///         // A stunned agent must recover before doing anything else.
+///         .allow_only(
+///             YoetzKeyPattern::variant::<AiBehaviorStunned>(),
+///             YoetzKeyPattern::variant::<AiBehaviorRecover>(),
+///         )
+///         // Fleeing right after attacking looks erratic.
+///         .penalize(
+///             YoetzKeyPattern::variant::<AiBehaviorAttack>(),
+///             YoetzKeyPattern::variant::<AiBehaviorFlee>(),
+///             5.0,
+///         ),
+/// );
+/// ```
+///
+/// Forced behaviors (see [`YoetzAdvisor::force`](crate::advisor::YoetzAdvisor::force)) ignore
+/// the table.
+#[derive(Resource)]
+pub struct YoetzTransitions<S: YoetzSuggestion> {
+    rules: Vec<(YoetzKeyPattern<S>, YoetzKeyPattern<S>, YoetzTransitionRule)>,
+}
+
+impl<S: YoetzSuggestion> Default for YoetzTransitions<S> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+impl<S: YoetzSuggestion> YoetzTransitions<S> {
+    /// Forbid switching from behaviors that match `from` to behaviors that match `to`.
+    pub fn forbid(mut self, from: YoetzKeyPattern<S>, to: YoetzKeyPattern<S>) -> Self {
+        self.rules.push((from, to, YoetzTransitionRule::Forbid));
+        self
+    }
+
+    /// Forbid switching from behaviors that match `from` to any behavior that does not match
+    /// `to`.
+    pub fn allow_only(mut self, from: YoetzKeyPattern<S>, to: YoetzKeyPattern<S>) -> Self {
+        self.rules.push((from, to, YoetzTransitionRule::AllowOnly));
+        self
+    }
+
+    /// Subtract `penalty` from the scores of behaviors that match `to` when switching to them
+    /// from behaviors that match `from`. Penalties of multiple matching rules are summed.
+    pub fn penalize(
+        mut self,
+        from: YoetzKeyPattern<S>,
+        to: YoetzKeyPattern<S>,
+        penalty: f32,
+    ) -> Self {
+        self.rules
+            .push((from, to, YoetzTransitionRule::Penalty(penalty)));
+        self
+    }
+
+    /// Check if the table allows switching from `from` to `to`.
+    pub fn is_allowed(&self, from: &S::Key, to: &S::Key) -> bool {
+        self.penalty(from, to).is_some()
+    }
+
+    /// The total penalty for switching from `from` to `to`, or `None` if the switch is forbidden.
+    pub fn penalty(&self, from: &S::Key, to: &S::Key) -> Option<f32> {
+        let mut total = 0.0;
+        for (from_pattern, to_pattern, rule) in self.rules.iter() {
+            if !from_pattern.matches(from) {
+                continue;
+            }
+            match rule {
+                YoetzTransitionRule::Forbid => {
+                    if to_pattern.matches(to) {
+                        return None;
+                    }
+                }
+                YoetzTransitionRule::AllowOnly => {
+                    if !to_pattern.matches(to) {
+                        return None;
+                    }
+                }
+                YoetzTransitionRule::Penalty(penalty) => {
+                    if to_pattern.matches(to) {
+                        total += penalty;
+                    }
+                }
+            }
+        }
+        Some(total)
+    }
+}
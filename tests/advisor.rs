@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use bevy_yoetz::prelude::*;
+
+use self::common::*;
+
+mod common;
+
+#[test]
+fn requested_rethink_bypasses_min_duration() {
+    let mut app = create_app::<f32>();
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(&mut app, vec![(1.0, AiBehavior::Guard)]);
+    app.update();
+    assert!(is_active::<AiBehaviorGuard>(&app, entity));
+
+    set_suggestions::<f32>(
+        &mut app,
+        vec![(1.0, AiBehavior::Guard), (5.0, AiBehavior::Flee)],
+    );
+    app.update();
+    assert!(is_active::<AiBehaviorGuard>(&app, entity));
+
+    advisor::<f32>(&mut app, entity).request_rethink();
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+}
+
+#[test]
+fn min_duration_blocks_switching() {
+    let mut app = create_app::<f32>();
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(&mut app, vec![(1.0, AiBehavior::Guard)]);
+    app.update();
+    assert!(is_active::<AiBehaviorGuard>(&app, entity));
+
+    set_suggestions::<f32>(
+        &mut app,
+        vec![(1.0, AiBehavior::Guard), (5.0, AiBehavior::Flee)],
+    );
+    for _ in 0..5 {
+        app.update();
+        assert!(is_active::<AiBehaviorGuard>(&app, entity));
+        assert!(!is_active::<AiBehaviorFlee>(&app, entity));
+    }
+
+    run_for(&mut app, Duration::from_secs(1));
+    assert!(!is_active::<AiBehaviorGuard>(&app, entity));
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+}
+
+#[test]
+fn timed_suggestion_expires() {
+    let mut app = create_app::<f32>();
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(&mut app, vec![(1.0, AiBehavior::Idle)]);
+    advisor::<f32>(&mut app, entity).suggest_for(5.0, Duration::from_millis(500), AiBehavior::Flee);
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+
+    run_for(&mut app, Duration::from_secs(1));
+    assert!(!is_active::<AiBehaviorFlee>(&app, entity));
+    assert!(is_active::<AiBehaviorIdle>(&app, entity));
+}
+
+#[test]
+fn score_resolution_makes_close_scores_tie() {
+    for (score_resolution, expect_guard) in [(None, false), (Some(1.0), true)] {
+        let mut app = create_app::<f32>();
+        let mut config = YoetzConfig::<AiBehavior>::default()
+            .deterministic()
+            .with_tie_break(YoetzTieBreak::PreferKeyOrder);
+        config.score_resolution = score_resolution;
+        app.insert_resource(config);
+        let entity = spawn_advisor::<f32>(&mut app);
+
+        // Flee is suggested first, but Guard is declared first.
+        set_suggestions::<f32>(
+            &mut app,
+            vec![(2.2, AiBehavior::Flee), (1.9, AiBehavior::Guard)],
+        );
+        app.update();
+        assert_eq!(is_active::<AiBehaviorGuard>(&app, entity), expect_guard);
+        assert_eq!(is_active::<AiBehaviorFlee>(&app, entity), !expect_guard);
+    }
+}
+
+#[test]
+fn inhibition_expires() {
+    let mut app = create_app::<f32>();
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(
+        &mut app,
+        vec![(1.0, AiBehavior::Idle), (5.0, AiBehavior::Flee)],
+    );
+    advisor::<f32>(&mut app, entity).inhibit(
+        YoetzKeyPattern::variant::<AiBehaviorFlee>(),
+        Duration::from_millis(500),
+    );
+    app.update();
+    assert!(is_active::<AiBehaviorIdle>(&app, entity));
+    app.update();
+    assert!(is_active::<AiBehaviorIdle>(&app, entity));
+
+    run_for(&mut app, Duration::from_secs(1));
+    assert!(!is_active::<AiBehaviorIdle>(&app, entity));
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+}
@@ -0,0 +1,92 @@
+//! An app that runs the advisors of a small suggestion `enum` with a fixed time step.
+
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_yoetz::prelude::*;
+
+#[derive(YoetzSuggestion, Clone)]
+pub enum AiBehavior {
+    Idle,
+    #[yoetz(min_duration = 1.0)]
+    Guard,
+    Flee,
+}
+
+/// The suggestions the advisors get every time the schedule runs.
+#[derive(Resource)]
+pub struct Suggestions<N: 'static + Send + Sync>(pub Vec<(N, AiBehavior)>);
+
+fn suggest<N: YoetzScore>(
+    suggestions: Res<Suggestions<N>>,
+    mut query: Query<&mut YoetzAdvisor<AiBehavior, N>>,
+) {
+    for mut advisor in query.iter_mut() {
+        for (score, suggestion) in suggestions.0.iter() {
+            advisor.suggest(*score, suggestion.clone());
+        }
+    }
+}
+
+/// How much time passes on each update (except for the first one, where no time passes).
+pub const STEP: Duration = Duration::from_millis(100);
+
+pub fn create_app<N: YoetzScore>() -> App {
+    create_app_with(YoetzPlugin::<AiBehavior, N>::new(Update))
+}
+
+pub fn create_app_with<N: YoetzScore>(plugin: YoetzPlugin<AiBehavior, N>) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(STEP));
+    app.add_plugins(plugin);
+    app.insert_resource(Suggestions::<N>(Vec::new()));
+    app.add_systems(Update, suggest::<N>.in_set(YoetzSystemSet::Suggest));
+    app
+}
+
+pub fn spawn_advisor<N: YoetzScore>(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn(YoetzAdvisor::<AiBehavior, N>::new(0.0))
+        .id()
+}
+
+pub fn set_suggestions<N: YoetzScore>(app: &mut App, suggestions: Vec<(N, AiBehavior)>) {
+    app.world_mut().resource_mut::<Suggestions<N>>().0 = suggestions;
+}
+
+pub fn advisor<N: YoetzScore>(
+    app: &mut App,
+    entity: Entity,
+) -> Mut<'_, YoetzAdvisor<AiBehavior, N>> {
+    app.world_mut()
+        .get_mut::<YoetzAdvisor<AiBehavior, N>>(entity)
+        .unwrap()
+}
+
+pub fn is_active<T: Component>(app: &App, entity: Entity) -> bool {
+    app.world().entity(entity).contains::<T>()
+}
+
+pub fn run_for(app: &mut App, duration: Duration) {
+    for _ in 0..duration.div_duration_f32(STEP).round() as usize {
+        app.update();
+    }
+}
+
+/// Update the app until the behavior `T` becomes active.
+pub fn update_until_active<T: Component>(app: &mut App, entity: Entity, max_updates: usize) {
+    for _ in 0..max_updates {
+        if is_active::<T>(app, entity) {
+            return;
+        }
+        app.update();
+    }
+    assert!(
+        is_active::<T>(app, entity),
+        "the behavior never became active"
+    );
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use bevy_yoetz::prelude::*;
+
+use self::common::*;
+
+mod common;
+
+#[test]
+fn integer_scores_stay_exact() {
+    let mut app = create_app::<i64>();
+    app.insert_resource(
+        YoetzConfig::<AiBehavior>::default()
+            .deterministic()
+            .with_tie_break(YoetzTieBreak::PreferKeyOrder)
+            .with_score_resolution(100.0),
+    );
+    let entity = spawn_advisor::<i64>(&mut app);
+
+    // Both round to 1_000_000_000 - but not if they go through an `f32`, which cannot represent
+    // them exactly.
+    set_suggestions::<i64>(
+        &mut app,
+        vec![
+            (1_000_000_049, AiBehavior::Flee),
+            (999_999_951, AiBehavior::Guard),
+        ],
+    );
+    app.update();
+    assert!(is_active::<AiBehaviorGuard>(&app, entity));
+
+    set_suggestions::<i64>(&mut app, vec![(1_000_000_051, AiBehavior::Guard)]);
+    app.update();
+    let snapshot = advisor::<i64>(&mut app, entity).snapshot();
+    assert_eq!(snapshot.active_score, Some(1_000_000_051));
+}
+
+#[test]
+fn integer_scores_saturate_instead_of_overflowing() {
+    let mut app = create_app::<i32>();
+    app.insert_resource(YoetzConfig::<AiBehavior>::default().with_score_resolution(10.0));
+    let entity = spawn_advisor::<i32>(&mut app);
+    let mut modifiers = YoetzScoreModifiers::<AiBehavior>::default();
+    for source in ["first", "second"] {
+        modifiers.insert(
+            source,
+            YoetzKeyPattern::variant::<AiBehaviorFlee>(),
+            YoetzScoreModifier::Add(f32::INFINITY),
+        );
+    }
+    app.world_mut().entity_mut(entity).insert(modifiers);
+
+    set_suggestions::<i32>(
+        &mut app,
+        vec![(10, AiBehavior::Idle), (5, AiBehavior::Flee)],
+    );
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+
+    for _ in 0..2 {
+        advisor::<i32>(&mut app, entity).fail_with_penalty(f32::INFINITY, Duration::from_secs(10));
+    }
+    app.update();
+    assert!(is_active::<AiBehaviorIdle>(&app, entity));
+}
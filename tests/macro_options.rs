@@ -0,0 +1,206 @@
+//! Derive suggestion `enum`s with the options of the `YoetzSuggestion` macro, to check that the
+//! generated code compiles and behaves as documented.
+
+use bevy::prelude::*;
+use bevy_yoetz::prelude::*;
+
+#[derive(YoetzSuggestion)]
+enum CfgBehavior {
+    Idle,
+    #[cfg(any())]
+    Teleport,
+    Flee,
+}
+
+#[test]
+fn variants_disabled_by_cfg_are_excluded() {
+    assert_eq!(CfgBehavior::VARIANT_NAMES, ["Idle", "Flee"]);
+    assert_eq!(CfgBehavior::variant_index(&CfgBehavior::Idle.key()), 0);
+    assert_eq!(CfgBehavior::variant_index(&CfgBehavior::Flee.key()), 1);
+}
+
+#[derive(YoetzSuggestion)]
+enum GenericBehavior<T: 'static + Send + Sync + Clone + PartialEq, const N: usize> {
+    Follow {
+        #[yoetz(key)]
+        target: T,
+    },
+    Scan(#[yoetz(key)] usize, #[yoetz(input)] [f32; N]),
+}
+
+#[test]
+fn generic_enums() {
+    let target = Entity::from_raw(1);
+    let key = GenericBehavior::<Entity, 3>::Follow { target }.key();
+    assert!(key == GenericBehaviorKey::Follow { target });
+    let key = GenericBehavior::<Entity, 3>::Scan(2, [0.0; 3]).key();
+    assert!(key == GenericBehaviorKey::Scan(2));
+}
+
+#[derive(YoetzSuggestion)]
+enum TierBehavior {
+    Idle,
+    #[yoetz(tier = 1)]
+    Flee,
+}
+
+#[test]
+fn variant_tiers() {
+    assert_eq!(TierBehavior::tier(&TierBehavior::Idle.key()), None);
+    assert_eq!(TierBehavior::tier(&TierBehavior::Flee.key()), Some(1));
+}
+
+#[derive(YoetzSuggestion)]
+#[yoetz(single_component)]
+enum Mood {
+    Calm,
+    Angry {
+        #[yoetz(key)]
+        at: Entity,
+    },
+}
+
+#[test]
+fn single_component() {
+    assert!(Mood::SINGLE_COMPONENT);
+    assert!(!TierBehavior::SINGLE_COMPONENT);
+    let at = Entity::from_raw(1);
+    assert!(Mood::Calm.key() != Mood::Angry { at }.key());
+    fn _act(_query: Query<&MoodActive>) {}
+}
+
+#[derive(YoetzSuggestion)]
+#[yoetz(key_helpers)]
+enum HelperBehavior {
+    Idle,
+    Attack {
+        #[yoetz(key)]
+        target: Entity,
+    },
+}
+
+#[test]
+fn key_helpers() {
+    let target = Entity::from_raw(1);
+    let key = HelperBehavior::Attack { target }.key();
+    assert!(key.is_attack());
+    assert!(!key.is_idle());
+    assert_eq!(key.as_attack(), Some(&target));
+    assert_eq!(key.discriminant(), HelperBehaviorKind::Attack);
+    assert_eq!(
+        HelperBehavior::Idle.key().discriminant(),
+        HelperBehaviorKind::Idle
+    );
+}
+
+#[derive(YoetzSuggestion)]
+#[yoetz(key_helpers)]
+#[non_exhaustive]
+#[repr(u8)]
+enum ReprBehavior {
+    Idle = 1,
+    Flee(#[yoetz(input)] Vec3) = 7,
+}
+
+#[test]
+fn explicit_discriminants() {
+    assert_eq!(ReprBehavior::Idle.key().discriminant() as u8, 1);
+    assert_eq!(ReprBehavior::Flee(Vec3::ZERO).key().discriminant() as u8, 7);
+    assert_eq!(ReprBehaviorKind::Flee as u8, 7);
+}
+
+#[derive(YoetzSuggestion, Clone)]
+enum SkippingBehavior {
+    Idle,
+    #[yoetz(skip)]
+    Bookkeeping(String),
+}
+
+#[test]
+fn skipped_variants_are_ignored() {
+    assert_eq!(SkippingBehavior::VARIANT_NAMES, ["Idle"]);
+    let suggestion = SkippingBehavior::Bookkeeping("ignored".to_owned());
+    assert!(suggestion.is_skipped());
+    assert!(matches!(&suggestion, SkippingBehavior::Bookkeeping(note) if note == "ignored"));
+    assert!(!SkippingBehavior::Idle.is_skipped());
+
+    let mut advisor = YoetzAdvisor::<SkippingBehavior>::new(0.0);
+    advisor.suggest(10.0, suggestion.clone());
+    advisor.force(suggestion);
+    assert!(advisor.suggestions().is_empty());
+}
+
+#[derive(YoetzSuggestion)]
+#[yoetz(builders)]
+enum BuiltBehavior {
+    Attack {
+        #[yoetz(key)]
+        target: Entity,
+        #[yoetz(input)]
+        position: Vec3,
+        #[yoetz(state)]
+        swings: u32,
+    },
+}
+
+#[test]
+fn builders() {
+    let target = Entity::from_raw(1);
+    let suggestion = BuiltBehavior::attack()
+        .target(target)
+        .position(Vec3::X)
+        .swings(3)
+        .build();
+    let BuiltBehavior::Attack {
+        target: built_target,
+        position,
+        swings,
+    } = suggestion;
+    assert_eq!(built_target, target);
+    assert_eq!(position, Vec3::X);
+    assert_eq!(swings, 3);
+}
+
+#[derive(YoetzSuggestion, Clone)]
+enum FallbackBehavior {
+    #[yoetz(fallback)]
+    Idle,
+    Flee,
+}
+
+#[test]
+fn fallback_variant() {
+    assert!(matches!(
+        FallbackBehavior::fallback(),
+        Some(FallbackBehavior::Idle)
+    ));
+    assert!(TierBehavior::fallback().is_none());
+    assert_eq!(
+        FallbackBehavior::variant_index(&FallbackBehavior::Flee.key()),
+        1
+    );
+}
+
+#[cfg(feature = "serde")]
+#[derive(YoetzSuggestion)]
+#[yoetz(key_enum(serde))]
+enum SerdeBehavior {
+    Idle,
+    Attack {
+        #[yoetz(key)]
+        target: Entity,
+    },
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn key_enum_serde() {
+    fn assert_serde<T: serde::Serialize + for<'de> serde::Deserialize<'de>>() {}
+    assert_serde::<SerdeBehaviorKey>();
+    let _ = [
+        SerdeBehavior::Idle,
+        SerdeBehavior::Attack {
+            target: Entity::PLACEHOLDER,
+        },
+    ];
+}
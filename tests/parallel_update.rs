@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_yoetz::prelude::*;
+
+use self::common::*;
+
+mod common;
+
+#[derive(Component)]
+struct Index(usize);
+
+/// Suggestions that differ between the advisors and change over time, so that advisors keep
+/// switching behaviors.
+fn suggest_by_index(
+    mut frame: Local<usize>,
+    mut query: Query<(&Index, &mut YoetzAdvisor<AiBehavior>)>,
+) {
+    *frame += 1;
+    for (Index(index), mut advisor) in query.iter_mut() {
+        advisor.suggest(1.0, AiBehavior::Idle);
+        advisor.suggest((index % 7) as f32, AiBehavior::Guard);
+        advisor.suggest(((index + *frame) % 9) as f32, AiBehavior::Flee);
+    }
+}
+
+/// The name of the active behavior of each advisor after each update, and whether each advisor
+/// has each of the strategy components.
+fn record_run(config: YoetzConfig<AiBehavior>) -> Vec<Vec<(Option<&'static str>, [bool; 3])>> {
+    let mut app = create_app::<f32>();
+    app.insert_resource(config);
+    app.add_systems(Update, suggest_by_index.in_set(YoetzSystemSet::Suggest));
+    let entities = (0..200)
+        .map(|index| {
+            app.world_mut()
+                .spawn((YoetzAdvisor::<AiBehavior>::new(0.5), Index(index)))
+                .id()
+        })
+        .collect::<Vec<_>>();
+
+    (0..30)
+        .map(|_| {
+            app.update();
+            entities
+                .iter()
+                .map(|&entity| {
+                    let active = advisor::<f32>(&mut app, entity)
+                        .active_key()
+                        .as_ref()
+                        .map(|key| AiBehavior::VARIANT_NAMES[AiBehavior::variant_index(key)]);
+                    let components = [
+                        is_active::<AiBehaviorIdle>(&app, entity),
+                        is_active::<AiBehaviorGuard>(&app, entity),
+                        is_active::<AiBehaviorFlee>(&app, entity),
+                    ];
+                    (active, components)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn parallel_update_matches_deterministic_update() {
+    let deterministic = record_run(YoetzConfig::default().deterministic());
+    let parallel = record_run(YoetzConfig::default());
+    assert_eq!(deterministic, parallel);
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_yoetz::prelude::*;
+
+use self::common::*;
+
+mod common;
+
+#[test]
+fn advisors_only_switch_when_the_cadence_timer_finishes() {
+    let mut app = create_app_with(
+        YoetzPlugin::<AiBehavior>::new(Update).think_every(Duration::from_millis(300)),
+    );
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(&mut app, vec![(1.0, AiBehavior::Idle)]);
+    // The advisor first thinks when the timer first finishes.
+    update_until_active::<AiBehaviorIdle>(&mut app, entity, 5);
+
+    set_suggestions::<f32>(&mut app, vec![(5.0, AiBehavior::Flee)]);
+    for _ in 0..2 {
+        app.update();
+        assert!(is_active::<AiBehaviorIdle>(&app, entity));
+    }
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+}
+
+#[test]
+fn requested_rethink_skips_the_cadence() {
+    let mut app = create_app_with(
+        YoetzPlugin::<AiBehavior>::new(Update).think_every(Duration::from_millis(300)),
+    );
+    let entity = spawn_advisor::<f32>(&mut app);
+
+    set_suggestions::<f32>(&mut app, vec![(1.0, AiBehavior::Idle)]);
+    update_until_active::<AiBehaviorIdle>(&mut app, entity, 5);
+
+    set_suggestions::<f32>(&mut app, vec![(5.0, AiBehavior::Flee)]);
+    app.update();
+    assert!(is_active::<AiBehaviorIdle>(&app, entity));
+    advisor::<f32>(&mut app, entity).request_rethink();
+    app.update();
+    assert!(is_active::<AiBehaviorFlee>(&app, entity));
+}